@@ -58,4 +58,114 @@ pub enum ErrorCode {
     InvalidInvalidationType,
     #[msg("Invalid reverse entry for name entry")]
     InvalidReverseEntryForNameEntry,
+    #[msg("Account required by namespace is missing")]
+    MissingRequiredAccount,
+    #[msg("Invalid mint counter")]
+    InvalidMintCounter,
+    #[msg("Invalid time invalidator")]
+    InvalidTimeInvalidator,
+    #[msg("Invalid payment manager")]
+    InvalidPaymentManager,
+    #[msg("Payment manager program ID is invalid")]
+    InvalidPaymentManagerProgramId,
+    #[msg("Invalid fee collector token account")]
+    InvalidFeeCollectorTokenAccount,
+    #[msg("Invalid payer token account")]
+    InvalidPayerTokenAccount,
+    #[msg("Invalid namespace token account")]
+    InvalidNamespaceTokenAccount,
+    #[msg("Namespace collection already initialized")]
+    CollectionAlreadyInitialized,
+    #[msg("Invalid namespace collection")]
+    InvalidNamespaceCollection,
+    #[msg("Invalid metadata configuration")]
+    InvalidMetadataConfig,
+    #[msg("Invalid royalty configuration")]
+    InvalidRoyaltyConfig,
+    #[msg("Invalid referral basis points")]
+    InvalidReferralBasisPoints,
+    #[msg("Invalid referrer")]
+    InvalidReferrer,
+    #[msg("Invalid referrer token account")]
+    InvalidReferrerTokenAccount,
+    #[msg("Invalid beneficiaries")]
+    InvalidBeneficiaries,
+    #[msg("Invalid beneficiary token account")]
+    InvalidBeneficiaryTokenAccount,
+    #[msg("Namespace funds must be distributed to its beneficiaries")]
+    NamespaceHasBeneficiaries,
+    #[msg("Invalid fee basis points")]
+    InvalidFeeBasisPoints,
+    #[msg("Invalid namespace fee override")]
+    InvalidNamespaceFeeOverride,
+    #[msg("Namespace has accrued protocol fees that must be collected first")]
+    NamespaceHasAccruedFees,
+    #[msg("Claim request has expired")]
+    ClaimRequestExpired,
+    #[msg("Claim request has not expired")]
+    ClaimRequestNotExpired,
+    #[msg("Invalid payer")]
+    InvalidPayer,
+    #[msg("Invalid approval signature")]
+    InvalidApprovalSignature,
+    #[msg("Invalid allowlist proof")]
+    InvalidAllowlistProof,
+    #[msg("Invalid claim request")]
+    InvalidClaimRequest,
+    #[msg("Invalid gate")]
+    InvalidGate,
+    #[msg("Requestor does not hold the tokens required by the namespace gate")]
+    GateNotSatisfied,
+    #[msg("Namespace is token gated, use claim_name_entry_v2")]
+    NamespaceGated,
+    #[msg("Invalid wallet entry counter")]
+    InvalidWalletEntryCounter,
+    #[msg("Wallet reached the namespace per wallet limit")]
+    WalletReachedLimit,
+    #[msg("Namespace has a per wallet limit, use claim_name_entry_v2")]
+    NamespaceWalletLimited,
+    #[msg("Namespace entries are not transferable")]
+    EntriesNotTransferable,
+    #[msg("Invalid listing")]
+    InvalidListing,
+    #[msg("Invalid creator token account")]
+    InvalidCreatorTokenAccount,
+    #[msg("Invalid offer")]
+    InvalidOffer,
+    #[msg("Offer has expired")]
+    OfferExpired,
+    #[msg("Offer has not expired")]
+    OfferNotExpired,
+    #[msg("Invalid sublease")]
+    InvalidSublease,
+    #[msg("Name is currently subleased")]
+    SubleaseActive,
+    #[msg("Sublease has not expired")]
+    SubleaseNotExpired,
+    #[msg("Invalid name policy")]
+    InvalidNamePolicy,
+    #[msg("Name is not in canonical form")]
+    NonCanonicalName,
+    #[msg("Name mixes scripts")]
+    MixedScriptName,
+    #[msg("Name is confusable with a latin name")]
+    ConfusableName,
+    #[msg("Name is too long")]
+    NameTooLong,
+    #[msg("Name is too short")]
+    NameTooShort,
+    #[msg("Invalid payment tiers")]
+    InvalidPaymentTiers,
+    #[msg("Invalid emoji allowlist")]
+    InvalidEmojiAllowlist,
+    #[msg("Emoji is not allowed in this namespace")]
+    EmojiNotAllowed,
+    #[msg("Invalid name length limits")]
+    InvalidNameLengthLimits,
+    #[msg("Name entry is not live")]
+    NameEntryNotLive,
+    #[msg("Listing price does not cover fees and royalties")]
+    InvalidListingPrice,
+    #[msg("Collect amount exceeds the namespace balance")]
+    InvalidCollectAmount,
 }
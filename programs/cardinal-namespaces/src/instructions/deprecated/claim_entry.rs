@@ -10,7 +10,7 @@ use cardinal_certificate::{
     state::CertificateKind,
 };
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
 };
 
@@ -23,11 +23,14 @@ pub struct ClaimEntryIx {
 #[derive(Accounts)]
 #[instruction(ix: ClaimEntryIx)]
 pub struct ClaimEntry<'info> {
-    #[account(mut)]
+    #[account(mut,
+        constraint = namespace.gate.is_none() @ ErrorCode::NamespaceGated,
+        constraint = namespace.max_entries_per_wallet.is_none() @ ErrorCode::NamespaceWalletLimited,
+    )]
     namespace: Box<Account<'info, Namespace>>,
     #[account(
         mut,
-        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), entry.name.as_bytes()],
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&entry.name).as_ref()],
         bump = entry.bump,
     )]
     entry: Box<Account<'info, Entry>>,
@@ -43,7 +46,8 @@ pub struct ClaimEntry<'info> {
         && claim_request.entry_name == entry.name
         && claim_request.requestor == user.key()
         && claim_request.counter == entry.claim_request_counter
-        @ ErrorCode::ClaimNotAllowed
+        @ ErrorCode::ClaimNotAllowed,
+        constraint = !claim_request_expired(&claim_request) @ ErrorCode::ClaimRequestExpired
     )]
     claim_request: Box<Account<'info, ClaimRequest>>,
 
@@ -144,7 +148,7 @@ pub fn handler(ctx: Context<ClaimEntry>, ix: ClaimEntryIx) -> Result<()> {
     };
     let mut payment_amount: Option<u64> = None;
     if ix.duration.is_some() {
-        payment_amount = Some((ctx.accounts.namespace.payment_amount_daily as f64 * (ix.duration.unwrap() as f64) / (60 * 60 * 24) as f64) as u64);
+        payment_amount = Some((payment_amount_daily(&ctx.accounts.namespace, &ctx.accounts.entry.name) as f64 * (ix.duration.unwrap() as f64) / (60 * 60 * 24) as f64) as u64);
     }
     let create_certificate_ix = IssueCertificateIx {
         // basic
@@ -1,7 +1,7 @@
 use mpl_token_metadata::instruction::create_metadata_accounts_v3;
 
 use {
-    crate::state::*,
+    crate::{state::*, utils::*},
     anchor_lang::{prelude::*, solana_program::program::invoke_signed},
     anchor_spl::{
         associated_token::{self, AssociatedToken},
@@ -26,8 +26,8 @@ pub struct InitEntry<'info> {
     #[account(
         init,
         payer = payer,
-        space = ENTRY_SIZE,
-        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), ix.name.as_bytes()],
+        space = entry_size(&ix.name),
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&ix.name).as_ref()],
         bump,
     )]
     entry: Account<'info, Entry>,
@@ -59,6 +59,8 @@ pub struct InitEntry<'info> {
 }
 
 pub fn handler(ctx: Context<InitEntry>, ix: InitEntryIx) -> Result<()> {
+    assert_name(&ctx.accounts.namespace, &ix.name)?;
+    assert_emoji_allowlist(&ctx.accounts.namespace, &ctx.accounts.namespace.key(), &ix.name, ctx.remaining_accounts.first())?;
     let entry = &mut ctx.accounts.entry;
     entry.namespace = ctx.accounts.namespace.key();
     entry.name = ix.name.clone();
@@ -11,7 +11,7 @@ pub struct SetEntryData<'info> {
     namespace: Box<Account<'info, Namespace>>,
     #[account(
         mut,
-        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), entry.name.as_bytes()],
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&entry.name).as_ref()],
         bump = entry.bump,
     )]
     entry: Box<Account<'info, Entry>>,
@@ -11,14 +11,14 @@ pub struct SetReverseEntryCtx<'info> {
     namespace: Box<Account<'info, Namespace>>,
     #[account(
         mut,
-        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), entry.name.as_bytes()],
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&entry.name).as_ref()],
         bump = entry.bump,
     )]
     entry: Box<Account<'info, Entry>>,
     #[account(
         init_if_needed,
         payer = payer,
-        space = REVERSE_ENTRY_SIZE,
+        space = reverse_entry_size(&entry.name),
         seeds = [REVERSE_ENTRY_SEED.as_bytes(), user.key().as_ref()],
         bump,
     )]
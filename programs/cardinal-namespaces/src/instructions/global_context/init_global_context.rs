@@ -1,4 +1,7 @@
-use {crate::state::*, anchor_lang::prelude::*};
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitGlobalContextIx {
@@ -23,6 +26,9 @@ pub struct InitGlobalContextCtx<'info> {
 }
 
 pub fn handler(ctx: Context<InitGlobalContextCtx>, ix: InitGlobalContextIx) -> Result<()> {
+    if ix.fee_basis_points > BASIS_POINTS_DIVISOR.into() {
+        return Err(error!(ErrorCode::InvalidFeeBasisPoints));
+    }
     let global_context = &mut ctx.accounts.global_context;
     global_context.bump = *ctx.bumps.get("global_context").unwrap();
     global_context.update_authority = ctx.accounts.authority.key();
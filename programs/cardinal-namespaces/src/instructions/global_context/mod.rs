@@ -1,3 +1,5 @@
 pub mod collect_global_context_funds;
 pub mod init_global_context;
+pub mod remove_namespace_fee_override;
+pub mod set_namespace_fee_override;
 pub mod update_global_context;
@@ -0,0 +1,23 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct RemoveNamespaceFeeOverrideCtx<'info> {
+    #[account(seeds = [GLOBAL_CONTEXT_PREFIX.as_bytes()], bump = global_context.bump)]
+    pub global_context: Account<'info, GlobalContext>,
+    #[account(
+        mut,
+        close = update_authority,
+        seeds = [FEE_OVERRIDE_SEED.as_bytes(), namespace_fee_override.namespace.as_ref()],
+        bump = namespace_fee_override.bump,
+    )]
+    pub namespace_fee_override: Account<'info, NamespaceFeeOverride>,
+    #[account(mut, constraint = update_authority.key() == global_context.update_authority @ ErrorCode::InvalidAuthority)]
+    pub update_authority: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<RemoveNamespaceFeeOverrideCtx>) -> Result<()> {
+    Ok(())
+}
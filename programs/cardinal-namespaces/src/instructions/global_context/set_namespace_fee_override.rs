@@ -0,0 +1,35 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SetNamespaceFeeOverrideCtx<'info> {
+    #[account(seeds = [GLOBAL_CONTEXT_PREFIX.as_bytes()], bump = global_context.bump)]
+    pub global_context: Account<'info, GlobalContext>,
+    pub namespace: Account<'info, Namespace>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = NAMESPACE_FEE_OVERRIDE_SIZE,
+        seeds = [FEE_OVERRIDE_SEED.as_bytes(), namespace.key().as_ref()],
+        bump,
+    )]
+    pub namespace_fee_override: Account<'info, NamespaceFeeOverride>,
+    #[account(constraint = update_authority.key() == global_context.update_authority @ ErrorCode::InvalidAuthority)]
+    pub update_authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetNamespaceFeeOverrideCtx>, fee_basis_points: u64) -> Result<()> {
+    if fee_basis_points > BASIS_POINTS_DIVISOR.into() {
+        return Err(error!(ErrorCode::InvalidFeeBasisPoints));
+    }
+    let namespace_fee_override = &mut ctx.accounts.namespace_fee_override;
+    namespace_fee_override.bump = *ctx.bumps.get("namespace_fee_override").unwrap();
+    namespace_fee_override.namespace = ctx.accounts.namespace.key();
+    namespace_fee_override.fee_basis_points = fee_basis_points;
+    Ok(())
+}
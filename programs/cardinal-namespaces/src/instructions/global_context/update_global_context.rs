@@ -24,6 +24,9 @@ pub struct UpdateGlobalContextCtx<'info> {
 
 pub fn handler(ctx: Context<UpdateGlobalContextCtx>, ix: UpdateGlobalContextIx) -> Result<()> {
     let global_context = &mut ctx.accounts.global_context;
+    if matches!(ix.fee_basis_points, Some(fee_basis_points) if fee_basis_points > BASIS_POINTS_DIVISOR.into()) {
+        return Err(error!(ErrorCode::InvalidFeeBasisPoints));
+    }
     global_context.fee_basis_points = ix.fee_basis_points.unwrap_or(global_context.fee_basis_points);
     global_context.update_authority = ix.update_authority.unwrap_or(global_context.update_authority);
     global_context.rent_authority = ix.rent_authority.unwrap_or(global_context.rent_authority);
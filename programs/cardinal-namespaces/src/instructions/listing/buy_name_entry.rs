@@ -0,0 +1,143 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[derive(Accounts)]
+pub struct BuyNameEntryCtx<'info> {
+    #[account(mut, constraint = namespace.key() == listing.namespace @ ErrorCode::InvalidListing)]
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        mut,
+        constraint = name_entry.key() == listing.name_entry && name_entry.is_claimed && name_entry.mint == listing.mint @ ErrorCode::InvalidListing,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        mut,
+        close = seller,
+        seeds = [LISTING_SEED.as_bytes(), listing.mint.as_ref()],
+        bump = listing.bump,
+    )]
+    listing: Box<Account<'info, Listing>>,
+    #[account(mut, constraint = listing_token_account.mint == listing.mint && listing_token_account.owner == listing.key() @ ErrorCode::InvalidListing)]
+    listing_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = mint.key() == listing.mint @ ErrorCode::InvalidListing)]
+    mint: Box<Account<'info, Mint>>,
+    /// CHECK: This is not dangerous because we check the address
+    #[account(mut, address = listing.seller @ ErrorCode::InvalidListing)]
+    seller: UncheckedAccount<'info>,
+    #[account(mut, constraint = seller_payment_token_account.mint == listing.payment_mint && seller_payment_token_account.owner == listing.seller @ ErrorCode::InvalidPaymentMint)]
+    seller_payment_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because we check inside the handler, pass the namespaces program id when the entry has no reverse entry
+    reverse_entry: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    time_invalidator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    buyer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    buyer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = buyer_payment_token_account.mint == listing.payment_mint && buyer_payment_token_account.owner == buyer.key() @ ErrorCode::InvalidPayerTokenAccount)]
+    buyer_payment_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = get_associated_token_address(&namespace.key(), &namespace.payment_mint) @ ErrorCode::InvalidPaymentMint)]
+    namespace_payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(seeds = [GLOBAL_CONTEXT_PREFIX.as_bytes()], bump = global_context.bump)]
+    global_context: Box<Account<'info, GlobalContext>>,
+    /// CHECK: This is not dangerous because we check the address inside the handler
+    namespace_fee_override: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    rent: Sysvar<'info, Rent>,
+    system_program: Program<'info, System>,
+    // remaining accounts are payment token accounts for each namespace metadata creator with a share, the namespace uses its payment account
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, BuyNameEntryCtx<'info>>) -> Result<()> {
+    // an expired or invalidated rental cannot be sold
+    assert_name_entry_live(
+        &ctx.accounts.namespace.key(),
+        &ctx.accounts.name_entry,
+        &ctx.accounts.token_manager,
+        &ctx.accounts.time_invalidator,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let price = ctx.accounts.listing.price;
+    let payment_mint = ctx.accounts.listing.payment_mint;
+
+    // namespace fee is owed to the protocol, account for earlier payments before depositing it
+    let fee_basis_points = namespace_fee_basis_points(&ctx.accounts.global_context, &ctx.accounts.namespace_fee_override, &ctx.accounts.namespace.key())?;
+    let namespace_fee = accrue_sale_fee(&mut ctx.accounts.namespace, price, ctx.accounts.namespace_payment_token_account.amount, fee_basis_points);
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.buyer_payment_token_account.to_account_info(),
+        to: ctx.accounts.namespace_payment_token_account.to_account_info(),
+        authority: ctx.accounts.buyer.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_context, namespace_fee)?;
+
+    // royalties follow the namespace metadata configuration
+    let royalties_paid = pay_royalties(
+        &ctx.accounts.namespace,
+        price,
+        &payment_mint,
+        &ctx.accounts.buyer_payment_token_account.to_account_info(),
+        &ctx.accounts.buyer.to_account_info(),
+        &[],
+        &ctx.accounts.token_program.to_account_info(),
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    // the namespace fee or royalties may have been raised since listing
+    let seller_amount = price
+        .checked_sub(namespace_fee)
+        .and_then(|amount| amount.checked_sub(royalties_paid))
+        .ok_or_else(|| error!(ErrorCode::InvalidListingPrice))?;
+
+    // pay seller
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.buyer_payment_token_account.to_account_info(),
+        to: ctx.accounts.seller_payment_token_account.to_account_info(),
+        authority: ctx.accounts.buyer.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_context, seller_amount)?;
+
+    // transfer the name to the buyer and close escrow
+    let listing_seeds = &[LISTING_SEED.as_bytes(), ctx.accounts.listing.mint.as_ref(), &[ctx.accounts.listing.bump]];
+    let listing_signer = &[&listing_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.listing_token_account.to_account_info(),
+        to: ctx.accounts.buyer_token_account.to_account_info(),
+        authority: ctx.accounts.listing.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(listing_signer);
+    token::transfer(cpi_context, ctx.accounts.listing_token_account.amount)?;
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.listing_token_account.to_account_info(),
+        destination: ctx.accounts.seller.to_account_info(),
+        authority: ctx.accounts.listing.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(listing_signer);
+    token::close_account(cpi_context)?;
+
+    // clear the seller reverse entry
+    reassign_name_entry(&mut ctx.accounts.name_entry, &ctx.accounts.reverse_entry, &ctx.accounts.seller, &ctx.accounts.buyer.key())?;
+    Ok(())
+}
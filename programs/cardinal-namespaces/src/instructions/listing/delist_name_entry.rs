@@ -0,0 +1,48 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct DelistNameEntryCtx<'info> {
+    #[account(
+        mut,
+        close = seller,
+        constraint = listing.seller == seller.key() @ ErrorCode::InvalidListing,
+        seeds = [LISTING_SEED.as_bytes(), listing.mint.as_ref()],
+        bump = listing.bump,
+    )]
+    listing: Box<Account<'info, Listing>>,
+    #[account(mut, constraint = listing_token_account.mint == listing.mint && listing_token_account.owner == listing.key() @ ErrorCode::InvalidListing)]
+    listing_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    seller: Signer<'info>,
+    #[account(mut, constraint = seller_token_account.mint == listing.mint && seller_token_account.owner == seller.key() @ ErrorCode::InvalidOwnerMint)]
+    seller_token_account: Box<Account<'info, TokenAccount>>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<DelistNameEntryCtx>) -> Result<()> {
+    let listing_seeds = &[LISTING_SEED.as_bytes(), ctx.accounts.listing.mint.as_ref(), &[ctx.accounts.listing.bump]];
+    let listing_signer = &[&listing_seeds[..]];
+
+    // return the name
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.listing_token_account.to_account_info(),
+        to: ctx.accounts.seller_token_account.to_account_info(),
+        authority: ctx.accounts.listing.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(listing_signer);
+    token::transfer(cpi_context, ctx.accounts.listing_token_account.amount)?;
+
+    // close escrow
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.listing_token_account.to_account_info(),
+        destination: ctx.accounts.seller.to_account_info(),
+        authority: ctx.accounts.listing.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(listing_signer);
+    token::close_account(cpi_context)?;
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{self, Mint, Token, TokenAccount, Transfer},
+    },
+};
+
+#[derive(Accounts)]
+pub struct ListNameEntryCtx<'info> {
+    #[account(constraint = namespace.transferable_entries @ ErrorCode::EntriesNotTransferable)]
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        constraint = name_entry.is_claimed && name_entry.mint == mint.key() @ ErrorCode::InvalidEntryMint,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        init,
+        payer = seller,
+        space = LISTING_SIZE,
+        seeds = [LISTING_SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    listing: Box<Account<'info, Listing>>,
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+    )]
+    listing_token_account: Box<Account<'info, TokenAccount>>,
+    mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    seller: Signer<'info>,
+    #[account(mut, constraint =
+        seller_token_account.mint == mint.key()
+        && seller_token_account.owner == seller.key()
+        && seller_token_account.amount == 1
+        @ ErrorCode::InvalidOwnerMint
+    )]
+    seller_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(seeds = [GLOBAL_CONTEXT_PREFIX.as_bytes()], bump = global_context.bump)]
+    global_context: Box<Account<'info, GlobalContext>>,
+    /// CHECK: This is not dangerous because we check the address inside the handler
+    namespace_fee_override: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    rent: Sysvar<'info, Rent>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ListNameEntryCtx>, price: u64) -> Result<()> {
+    let fee_basis_points = namespace_fee_basis_points(&ctx.accounts.global_context, &ctx.accounts.namespace_fee_override, &ctx.accounts.namespace.key())?;
+    assert_sale_price(&ctx.accounts.namespace, price, fee_basis_points)?;
+
+    let listing = &mut ctx.accounts.listing;
+    listing.bump = *ctx.bumps.get("listing").unwrap();
+    listing.namespace = ctx.accounts.namespace.key();
+    listing.name_entry = ctx.accounts.name_entry.key();
+    listing.mint = ctx.accounts.mint.key();
+    listing.seller = ctx.accounts.seller.key();
+    // listings are priced in the namespace payment mint so royalties can be paid to the namespace
+    listing.payment_mint = ctx.accounts.namespace.payment_mint;
+    listing.price = price;
+
+    // escrow the name
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.seller_token_account.to_account_info(),
+        to: ctx.accounts.listing_token_account.to_account_info(),
+        authority: ctx.accounts.seller.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_context, 1)?;
+    Ok(())
+}
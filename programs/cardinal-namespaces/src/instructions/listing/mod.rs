@@ -0,0 +1,3 @@
+pub mod buy_name_entry;
+pub mod delist_name_entry;
+pub mod list_name_entry;
@@ -11,11 +11,20 @@ pub use deprecated::set_reverse_entry::*;
 pub mod global_context;
 pub use global_context::collect_global_context_funds::*;
 pub use global_context::init_global_context::*;
+pub use global_context::remove_namespace_fee_override::*;
+pub use global_context::set_namespace_fee_override::*;
 pub use global_context::update_global_context::*;
 
+// listing
+pub mod listing;
+pub use listing::buy_name_entry::*;
+pub use listing::delist_name_entry::*;
+pub use listing::list_name_entry::*;
+
 // name_entry
 pub mod name_entry;
 pub use name_entry::claim_name_entry::*;
+pub use name_entry::claim_name_entry_v2::*;
 pub use name_entry::close_name_entry::*;
 pub use name_entry::init_name_entry::*;
 pub use name_entry::init_name_entry_mint::*;
@@ -24,19 +33,46 @@ pub use name_entry::invalidate_transferable_name_entry::*;
 pub use name_entry::migrate_name_entry_mint::*;
 pub use name_entry::revoke_name_entry::*;
 pub use name_entry::set_name_entry_data::*;
+pub use name_entry::sync_name_entry_mint_metadata::*;
 pub use name_entry::update_name_entry_mint_metadata::*;
 
 // namespace
 pub mod namespace;
 pub use namespace::collect_namespace_funds::*;
 pub use namespace::create_namespace::*;
+pub use namespace::distribute_namespace_funds::*;
+pub use namespace::init_namespace_collection::*;
+pub use namespace::set_emoji_allowlist::*;
 pub use namespace::update_namespace::*;
 
+// offer
+pub mod offer;
+pub use offer::accept_name_entry_offer::*;
+pub use offer::cancel_name_entry_offer::*;
+pub use offer::make_name_entry_offer::*;
+
+// primary_name
+pub mod primary_name;
+pub use primary_name::close_primary_name::*;
+pub use primary_name::resolve_primary_name::*;
+pub use primary_name::set_primary_name::*;
+
+// referrer
+pub mod referrer;
+pub use referrer::init_referrer::*;
+
 // requests
 pub mod requests;
 pub use requests::approve_claim_request::*;
+pub use requests::approve_claim_request_with_proof::*;
+pub use requests::approve_claim_request_with_signature::*;
+pub use requests::approve_claim_requests::*;
+pub use requests::close_expired_claim_request::*;
 pub use requests::create_claim_request::*;
+pub use requests::migrate_claim_request::*;
+pub use requests::reject_claim_request::*;
 pub use requests::update_claim_request::*;
+pub use requests::withdraw_claim_request::*;
 
 // reverse_name_entry
 pub mod reverse_name_entry;
@@ -45,3 +81,11 @@ pub use reverse_name_entry::invalidate_transferable_reverse_name_entry::*;
 pub use reverse_name_entry::revoke_reverse_name_entry::*;
 pub use reverse_name_entry::set_global_reverse_entry::*;
 pub use reverse_name_entry::set_namespace_reverse_name_entry::*;
+
+// sublease
+pub mod sublease;
+pub use sublease::close_sublease::*;
+pub use sublease::end_sublease::*;
+pub use sublease::init_sublease::*;
+pub use sublease::rent_sublease::*;
+pub use sublease::set_sublease_reverse_name_entry::*;
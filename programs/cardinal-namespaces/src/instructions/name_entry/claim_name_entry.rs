@@ -1,18 +1,11 @@
-use anchor_spl::token::Mint;
-
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
     anchor_spl::{
-        associated_token::{self, AssociatedToken},
-        token::Token,
-    },
-    cardinal_time_invalidator::{self},
-    cardinal_token_manager::{
-        self,
-        program::CardinalTokenManager,
-        state::{InvalidationType, TokenManagerKind},
+        associated_token::AssociatedToken,
+        token::{Token, TokenAccount},
     },
+    cardinal_token_manager::program::CardinalTokenManager,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -23,9 +16,17 @@ pub struct ClaimNameEntryIx {
 #[derive(Accounts)]
 #[instruction(ix: ClaimNameEntryIx)]
 pub struct ClaimNameEntryCtx<'info> {
-    #[account(mut)]
+    #[account(mut,
+        constraint = namespace.gate.is_none() @ ErrorCode::NamespaceGated,
+        constraint = namespace.max_entries_per_wallet.is_none() @ ErrorCode::NamespaceWalletLimited,
+    )]
     namespace: Box<Account<'info, Namespace>>,
-    #[account(mut, constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace)]
+    #[account(
+        mut,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
     name_entry: Box<Account<'info, Entry>>,
     #[account(mut)]
     requestor: Signer<'info>,
@@ -40,22 +41,26 @@ pub struct ClaimNameEntryCtx<'info> {
         && claim_request.entry_name == name_entry.name
         && claim_request.requestor == requestor.key()
         && claim_request.counter == name_entry.claim_request_counter
-        @ ErrorCode::ClaimNotAllowed
+        @ ErrorCode::ClaimNotAllowed,
+        constraint = !claim_request_expired(&claim_request) @ ErrorCode::ClaimRequestExpired
     )]
     claim_request: Box<Account<'info, ClaimRequest>>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(mut, constraint = mint.key() == name_entry.mint @ ErrorCode::InvalidEntryMint)]
     mint: UncheckedAccount<'info>,
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    #[account(mut)]
-    namespace_token_account: UncheckedAccount<'info>,
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut, constraint =
+        namespace_token_account.mint == name_entry.mint
+        && namespace_token_account.owner == namespace.key()
+        @ ErrorCode::InvalidNamespaceTokenAccount
+    )]
+    namespace_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because we check the derivation inside the handler
     #[account(mut)]
     token_manager: UncheckedAccount<'info>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(mut)]
     token_manager_token_account: UncheckedAccount<'info>,
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: This is not dangerous because we check the derivation inside the handler
     #[account(mut)]
     mint_counter: UncheckedAccount<'info>,
     /// CHECK: This is not dangerous because we don't read or write from this account
@@ -71,6 +76,9 @@ pub struct ClaimNameEntryCtx<'info> {
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ClaimNameEntryCtx<'info>>, ix: ClaimNameEntryIx) -> Result<()> {
+    assert_token_manager(&ctx.accounts.token_manager, &ctx.accounts.name_entry.mint)?;
+    assert_mint_counter(&ctx.accounts.mint_counter, &ctx.accounts.name_entry.mint)?;
+
     let remaining_accs = &mut ctx.remaining_accounts.iter();
     let name_entry = &mut ctx.accounts.name_entry;
     let namespace = &mut ctx.accounts.namespace;
@@ -95,175 +103,65 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         return Err(error!(ErrorCode::NamespaceRequiresDuration));
     }
 
-    let namespace_seeds = &[NAMESPACE_PREFIX.as_bytes(), ctx.accounts.namespace.name.as_bytes(), &[ctx.accounts.namespace.bump]];
-    let namespace_signer = &[&namespace_seeds[..]];
-
-    if ctx.accounts.token_manager_token_account.data_is_empty() {
-        // create associated token account for certificate mint
-        let cpi_accounts = associated_token::Create {
-            payer: ctx.accounts.payer.to_account_info(),
-            associated_token: ctx.accounts.token_manager_token_account.to_account_info(),
-            authority: ctx.accounts.token_manager.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-            rent: ctx.accounts.rent.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-        associated_token::create(cpi_context)?;
-    }
-
-    // token manager init
-    let init_ix = cardinal_token_manager::instructions::InitIx {
-        amount: 1,
-        kind: if ctx.accounts.namespace.transferable_entries {
-            TokenManagerKind::Unmanaged as u8
-        } else {
-            TokenManagerKind::Edition as u8
-        },
-        // for old versions of namespace, field invalidation_type didn't exist and defaults to zero
-        invalidation_type: if ctx.accounts.namespace.invalidation_type == 0 {
-            if ctx.accounts.namespace.transferable_entries {
-                InvalidationType::Invalidate as u8
-            } else {
-                InvalidationType::Return as u8
-            }
-        } else {
-            ctx.accounts.namespace.invalidation_type
-        },
-        num_invalidators: if ctx.accounts.namespace.payment_amount_daily > 0 || ctx.accounts.namespace.max_expiration.is_some() {
-            2
-        } else {
-            1
-        },
-    };
-    let cpi_accounts = cardinal_token_manager::cpi::accounts::InitCtx {
-        token_manager: ctx.accounts.token_manager.to_account_info(),
-        mint_counter: ctx.accounts.mint_counter.to_account_info(),
-        issuer: ctx.accounts.namespace.to_account_info(),
-        payer: ctx.accounts.payer.to_account_info(),
-        issuer_token_account: ctx.accounts.namespace_token_account.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_manager_program.to_account_info(), cpi_accounts).with_signer(namespace_signer);
-    cardinal_token_manager::cpi::init(cpi_ctx, init_ix)?;
-
-    // add invalidator
-    let cpi_accounts = cardinal_token_manager::cpi::accounts::AddInvalidatorCtx {
-        token_manager: ctx.accounts.token_manager.to_account_info(),
-        issuer: ctx.accounts.namespace.to_account_info(),
+    // length tiered daily price
+    let daily_payment_amount = payment_amount_daily(&ctx.accounts.namespace, &ctx.accounts.name_entry.name);
+    let has_time_invalidator = daily_payment_amount > 0 || ctx.accounts.namespace.max_expiration.is_some();
+    let time_invalidator = if has_time_invalidator {
+        Some(ClaimTimeInvalidatorAccounts {
+            payment_mint: next_account_info(remaining_accs)?.to_account_info(),
+            payment_manager: next_account_info(remaining_accs)?.to_account_info(),
+            time_invalidator: next_account_info(remaining_accs)?.to_account_info(),
+            time_invalidator_program: next_account_info(remaining_accs)?.to_account_info(),
+        })
+    } else {
+        None
     };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_manager_program.to_account_info(), cpi_accounts).with_signer(namespace_signer);
-    cardinal_token_manager::cpi::add_invalidator(cpi_ctx, ctx.accounts.namespace.key())?;
-
-    let mut payment_manager_account_info: Option<&AccountInfo> = None;
-    let mut time_invalidator_account_info: Option<&AccountInfo> = None;
-    let mut time_invalidator_program: Option<&AccountInfo> = None;
-    if ctx.accounts.namespace.payment_amount_daily > 0 || ctx.accounts.namespace.max_expiration.is_some() {
-        // payment_mint
-        let payment_mint_account_info = next_account_info(remaining_accs)?;
-        let payment_mint = Account::<Mint>::try_from(payment_mint_account_info)?;
-        if payment_mint.key() != ctx.accounts.namespace.payment_mint {
-            return Err(error!(ErrorCode::InvalidPaymentMint));
-        }
-        payment_manager_account_info = Some(next_account_info(remaining_accs)?);
-        time_invalidator_account_info = Some(next_account_info(remaining_accs)?);
-        time_invalidator_program = Some(next_account_info(remaining_accs)?);
-        if time_invalidator_program.expect("Expected time_invalidator_program").key() != cardinal_time_invalidator::id() {
-            return Err(error!(ErrorCode::InvalidTimeInvalidatorProgramId));
-        }
-
-        // init time invalidator
-        let init_ix = cardinal_time_invalidator::instructions::InitIx {
-            collector: ctx.accounts.namespace.key(),
-            payment_manager: payment_manager_account_info.expect("Expected payment_manager").key(),
-            duration_seconds: if ctx.accounts.namespace.payment_amount_daily > 0 { Some(0) } else { None },
-            extension_payment_amount: if ctx.accounts.namespace.payment_amount_daily > 0 {
-                Some(ctx.accounts.namespace.payment_amount_daily)
-            } else {
-                None
-            },
-            extension_duration_seconds: if ctx.accounts.namespace.payment_amount_daily > 0 { Some(86400) } else { None },
-            extension_payment_mint: if ctx.accounts.namespace.payment_amount_daily > 0 { Some(payment_mint.key()) } else { None },
-            max_expiration: ctx.accounts.namespace.max_expiration,
-            disable_partial_extension: None,
-        };
-        let cpi_accounts = cardinal_time_invalidator::cpi::accounts::InitCtx {
-            token_manager: ctx.accounts.token_manager.to_account_info(),
-            issuer: ctx.accounts.namespace.to_account_info(),
-            payer: ctx.accounts.payer.to_account_info(),
-            time_invalidator: time_invalidator_account_info.expect("Expected time_invalidator_account_info").to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(time_invalidator_program.unwrap().to_account_info(), cpi_accounts).with_signer(namespace_signer);
-        cardinal_time_invalidator::cpi::init(cpi_ctx, init_ix)?;
-
-        // add time invalidator
-        let cpi_accounts = cardinal_token_manager::cpi::accounts::AddInvalidatorCtx {
-            token_manager: ctx.accounts.token_manager.to_account_info(),
-            issuer: ctx.accounts.namespace.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(ctx.accounts.token_manager_program.to_account_info(), cpi_accounts).with_signer(namespace_signer);
-        cardinal_token_manager::cpi::add_invalidator(cpi_ctx, time_invalidator_account_info.expect("Expected time_invalidator_account_info").to_account_info().key())?;
-    }
-
-    // token manager issue
-    let cpi_accounts = cardinal_token_manager::cpi::accounts::IssueCtx {
-        token_manager: ctx.accounts.token_manager.to_account_info(),
-        token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
-        issuer: ctx.accounts.namespace.to_account_info(),
-        issuer_token_account: ctx.accounts.namespace_token_account.to_account_info(),
-        payer: ctx.accounts.payer.to_account_info(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
+    let mint_edition = if ctx.accounts.namespace.transferable_entries {
+        None
+    } else {
+        Some((next_account_info(remaining_accs)?.to_account_info(), next_account_info(remaining_accs)?.to_account_info()))
     };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_manager_program.to_account_info(), cpi_accounts).with_signer(namespace_signer);
-    cardinal_token_manager::cpi::issue(cpi_ctx)?;
-
-    // token manager claim
-    let cpi_accounts = cardinal_token_manager::cpi::accounts::ClaimCtx {
+    let issue_accounts = IssueNameEntryAccounts {
+        mint: ctx.accounts.mint.to_account_info(),
+        namespace_token_account: ctx.accounts.namespace_token_account.to_account_info(),
         token_manager: ctx.accounts.token_manager.to_account_info(),
         token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
+        mint_counter: ctx.accounts.mint_counter.to_account_info(),
         recipient: ctx.accounts.recipient.to_account_info(),
         recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        token_manager_program: ctx.accounts.token_manager_program.to_account_info(),
         token_program: ctx.accounts.token_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
         system_program: ctx.accounts.system_program.to_account_info(),
+        mint_edition,
+        time_invalidator,
     };
-    if ctx.accounts.namespace.transferable_entries {
-        let cpi_ctx = CpiContext::new(ctx.accounts.token_manager_program.to_account_info(), cpi_accounts);
-        cardinal_token_manager::cpi::claim(cpi_ctx)?;
-    } else {
-        let edition_info = next_account_info(remaining_accs)?;
-        let metadata_program_info = next_account_info(remaining_accs)?;
-        let cpi_ctx = CpiContext::new(ctx.accounts.token_manager_program.to_account_info(), cpi_accounts)
-            .with_remaining_accounts([edition_info.to_account_info(), metadata_program_info.to_account_info()].to_vec());
-        cardinal_token_manager::cpi::claim(cpi_ctx)?;
-    }
+    issue_name_entry(&ctx.accounts.namespace, &issue_accounts, daily_payment_amount)?;
 
-    if ctx.accounts.namespace.payment_amount_daily > 0 && ix.duration.expect("Duration required") > 0 {
-        let payer_token_account_info = next_account_info(remaining_accs)?;
+    if daily_payment_amount > 0 && ix.duration.expect("Duration required") > 0 {
+        let payer_token_account = next_account_info(remaining_accs)?;
         let payment_token_account = next_account_info(remaining_accs)?;
         let fee_collector_token_account = next_account_info(remaining_accs)?;
         let payment_manager_program = next_account_info(remaining_accs)?;
-
-        let cpi_accounts = cardinal_time_invalidator::cpi::accounts::ExtendExpirationCtx {
-            token_manager: ctx.accounts.token_manager.to_account_info(),
-            time_invalidator: time_invalidator_account_info.expect("Expected time_invalidator").to_account_info(),
-            payer: ctx.accounts.payer.to_account_info(),
-            payment_manager: payment_manager_account_info.expect("Expected payment_manager").to_account_info(),
-            payment_token_account: payment_token_account.to_account_info(),
-            fee_collector_token_account: fee_collector_token_account.to_account_info(),
-            payer_token_account: payer_token_account_info.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-            cardinal_payment_manager: payment_manager_program.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(time_invalidator_program.expect("Expected time_invalidator_program").to_account_info(), cpi_accounts)
-            .with_signer(namespace_signer)
-            .with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>());
-        cardinal_time_invalidator::cpi::extend_expiration(cpi_ctx, ix.duration.expect("Duration required").try_into().expect("Duration invalid"))?;
+        let time_invalidator = issue_accounts.time_invalidator.as_ref().expect("Expected time invalidator");
+        extend_name_entry(
+            &ctx.accounts.namespace,
+            &ExtendNameEntryAccounts {
+                token_manager: ctx.accounts.token_manager.to_account_info(),
+                time_invalidator: time_invalidator.time_invalidator.clone(),
+                payer: ctx.accounts.payer.to_account_info(),
+                payment_manager: time_invalidator.payment_manager.clone(),
+                payer_token_account: payer_token_account.to_account_info(),
+                payment_token_account: payment_token_account.to_account_info(),
+                fee_collector_token_account: fee_collector_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                payment_manager_program: payment_manager_program.to_account_info(),
+                time_invalidator_program: time_invalidator.time_invalidator_program.clone(),
+            },
+            ix.duration.expect("Duration required"),
+            remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>(),
+        )?;
     }
 
     Ok(())
@@ -0,0 +1,296 @@
+use super::claim_name_entry::ClaimNameEntryIx;
+
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{self, Token, TokenAccount, Transfer},
+    },
+    cardinal_payment_manager::program::CardinalPaymentManager,
+    cardinal_time_invalidator::program::CardinalTimeInvalidator,
+    cardinal_token_manager::program::CardinalTokenManager,
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[derive(Accounts)]
+#[instruction(ix: ClaimNameEntryIx)]
+pub struct ClaimNameEntryV2Ctx<'info> {
+    #[account(mut)]
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        mut,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(mut)]
+    requestor: Signer<'info>,
+    #[account(mut)]
+    recipient: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(mut,
+        close = namespace,
+        constraint = claim_request.is_approved
+        && claim_request.namespace == namespace.key()
+        && claim_request.entry_name == name_entry.name
+        && claim_request.requestor == requestor.key()
+        && claim_request.counter == name_entry.claim_request_counter
+        @ ErrorCode::ClaimNotAllowed,
+        constraint = !claim_request_expired(&claim_request) @ ErrorCode::ClaimRequestExpired
+    )]
+    claim_request: Box<Account<'info, ClaimRequest>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut, constraint = mint.key() == name_entry.mint @ ErrorCode::InvalidEntryMint)]
+    mint: UncheckedAccount<'info>,
+    #[account(mut, constraint =
+        namespace_token_account.mint == name_entry.mint
+        && namespace_token_account.owner == namespace.key()
+        @ ErrorCode::InvalidNamespaceTokenAccount
+    )]
+    namespace_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because we check the derivation inside the handler
+    #[account(mut)]
+    token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    token_manager_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check the derivation inside the handler
+    #[account(mut)]
+    mint_counter: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    recipient_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    mint_edition: UncheckedAccount<'info>,
+
+    // optional accounts, pass the namespaces program id for accounts that do not apply to this namespace
+    // the program id is never writable so writable accounts are checked in the handler once they are used
+    /// CHECK: This is not dangerous because we check inside the handler
+    payment_mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    payment_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    time_invalidator: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    payer_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    payment_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    fee_collector_token_account: UncheckedAccount<'info>,
+    #[account(seeds = [GLOBAL_CONTEXT_PREFIX.as_bytes()], bump = global_context.bump)]
+    global_context: Box<Account<'info, GlobalContext>>,
+    /// CHECK: This is not dangerous because we check the derivation inside the handler
+    namespace_fee_override: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    referrer: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    referrer_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    gate_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    gate_metadata: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    wallet_entry_counter: UncheckedAccount<'info>,
+
+    // programs
+    token_manager_program: Program<'info, CardinalTokenManager>,
+    time_invalidator_program: Program<'info, CardinalTimeInvalidator>,
+    payment_manager_program: Program<'info, CardinalPaymentManager>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = mpl_token_metadata::id())]
+    token_metadata_program: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    associated_token: Program<'info, AssociatedToken>,
+    rent: Sysvar<'info, Rent>,
+    system_program: Program<'info, System>,
+}
+
+/// Optional accounts are passed as the namespaces program id when they do not apply
+fn optional_account<'a, 'info>(account: &'a UncheckedAccount<'info>) -> Option<&'a AccountInfo<'info>> {
+    if account.key() == crate::id() {
+        None
+    } else {
+        Some(account)
+    }
+}
+
+fn required_account<'a, 'info>(account: &'a UncheckedAccount<'info>) -> Result<&'a AccountInfo<'info>> {
+    optional_account(account).ok_or_else(|| error!(ErrorCode::MissingRequiredAccount))
+}
+
+fn required_account_mut<'a, 'info>(account: &'a UncheckedAccount<'info>) -> Result<&'a AccountInfo<'info>> {
+    let account = required_account(account)?;
+    if !account.is_writable {
+        return Err(error!(anchor_lang::error::ErrorCode::ConstraintMut));
+    }
+    Ok(account)
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ClaimNameEntryV2Ctx<'info>>, ix: ClaimNameEntryIx) -> Result<()> {
+    assert_token_manager(&ctx.accounts.token_manager, &ctx.accounts.name_entry.mint)?;
+    assert_mint_counter(&ctx.accounts.mint_counter, &ctx.accounts.name_entry.mint)?;
+    if let Some(gate) = &ctx.accounts.namespace.gate {
+        assert_gate(
+            gate,
+            &ctx.accounts.requestor.key(),
+            optional_account(&ctx.accounts.gate_token_account),
+            optional_account(&ctx.accounts.gate_metadata),
+        )?;
+    }
+
+    let name_entry = &mut ctx.accounts.name_entry;
+    let namespace = &mut ctx.accounts.namespace;
+    name_entry.data = Some(ctx.accounts.recipient.key());
+    name_entry.claim_request_counter = name_entry.claim_request_counter.checked_add(1).expect("Add error");
+    name_entry.is_claimed = true;
+    namespace.count = namespace.count.checked_add(1).expect("Add error");
+
+    if ctx.accounts.namespace.limit.is_some() && ctx.accounts.namespace.count > ctx.accounts.namespace.limit.unwrap() {
+        return Err(error!(ErrorCode::NamespaceReachedLimit));
+    }
+
+    // per wallet limit, entries created before the claimer field may need more space
+    if ctx.accounts.namespace.max_entries_per_wallet.is_some() {
+        increment_wallet_entry_counter(
+            &ctx.accounts.namespace,
+            &ctx.accounts.namespace.key(),
+            &ctx.accounts.requestor.key(),
+            required_account_mut(&ctx.accounts.wallet_entry_counter)?,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        ctx.accounts.name_entry.claimer = Some(ctx.accounts.requestor.key());
+        resize_account(
+            &ctx.accounts.name_entry.to_account_info(),
+            entry_size(&ctx.accounts.name_entry.name),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    // duration checks
+    if let Some(duration) = ix.duration {
+        if duration <= ctx.accounts.namespace.min_rental_seconds {
+            return Err(error!(ErrorCode::RentalDurationTooSmall));
+        }
+        if ctx.accounts.namespace.max_rental_seconds.is_some() && duration >= ctx.accounts.namespace.max_rental_seconds.unwrap() {
+            return Err(error!(ErrorCode::RentalDurationTooLarge));
+        }
+    } else if ctx.accounts.namespace.max_rental_seconds.is_some() {
+        return Err(error!(ErrorCode::NamespaceRequiresDuration));
+    }
+
+    // length tiered daily price
+    let daily_payment_amount = payment_amount_daily(&ctx.accounts.namespace, &ctx.accounts.name_entry.name);
+    let has_time_invalidator = daily_payment_amount > 0 || ctx.accounts.namespace.max_expiration.is_some();
+    let issue_accounts = IssueNameEntryAccounts {
+        mint: ctx.accounts.mint.to_account_info(),
+        namespace_token_account: ctx.accounts.namespace_token_account.to_account_info(),
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
+        mint_counter: ctx.accounts.mint_counter.to_account_info(),
+        recipient: ctx.accounts.recipient.to_account_info(),
+        recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        token_manager_program: ctx.accounts.token_manager_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        mint_edition: Some((ctx.accounts.mint_edition.to_account_info(), ctx.accounts.token_metadata_program.to_account_info())),
+        time_invalidator: if has_time_invalidator {
+            Some(ClaimTimeInvalidatorAccounts {
+                payment_mint: required_account(&ctx.accounts.payment_mint)?.to_account_info(),
+                payment_manager: required_account_mut(&ctx.accounts.payment_manager)?.to_account_info(),
+                time_invalidator: required_account_mut(&ctx.accounts.time_invalidator)?.to_account_info(),
+                time_invalidator_program: ctx.accounts.time_invalidator_program.to_account_info(),
+            })
+        } else {
+            None
+        },
+    };
+    issue_name_entry(&ctx.accounts.namespace, &issue_accounts, daily_payment_amount)?;
+
+    if daily_payment_amount > 0 && ix.duration.expect("Duration required") > 0 {
+        let payment_mint = ctx.accounts.namespace.payment_mint;
+        let payment_token_account = required_account_mut(&ctx.accounts.payment_token_account)?;
+        if payment_token_account.key() != get_associated_token_address(&ctx.accounts.namespace.key(), &payment_mint) {
+            return Err(error!(ErrorCode::InvalidNamespacePaymentAccount));
+        }
+        let payment_balance_before = assert_token_account(payment_token_account, &payment_mint, &ctx.accounts.namespace.key(), ErrorCode::InvalidNamespacePaymentAccount)?.amount;
+        extend_name_entry(
+            &ctx.accounts.namespace,
+            &ExtendNameEntryAccounts {
+                token_manager: ctx.accounts.token_manager.to_account_info(),
+                time_invalidator: required_account_mut(&ctx.accounts.time_invalidator)?.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                payment_manager: required_account_mut(&ctx.accounts.payment_manager)?.to_account_info(),
+                payer_token_account: required_account_mut(&ctx.accounts.payer_token_account)?.to_account_info(),
+                payment_token_account: payment_token_account.to_account_info(),
+                fee_collector_token_account: required_account_mut(&ctx.accounts.fee_collector_token_account)?.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                payment_manager_program: ctx.accounts.payment_manager_program.to_account_info(),
+                time_invalidator_program: ctx.accounts.time_invalidator_program.to_account_info(),
+            },
+            ix.duration.expect("Duration required"),
+            ctx.remaining_accounts.to_vec(),
+        )?;
+
+        // protocol fee and referral share are both taken from what the namespace received after payment manager fees
+        let fee_basis_points = namespace_fee_basis_points(&ctx.accounts.global_context, &ctx.accounts.namespace_fee_override, &ctx.accounts.namespace.key())?;
+        let payment_balance_after = Account::<TokenAccount>::try_from(payment_token_account)?.amount;
+        let received_amount = payment_balance_after.checked_sub(payment_balance_before).expect("Sub error");
+        let protocol_fee = received_amount
+            .checked_mul(fee_basis_points)
+            .expect("Multiplication error")
+            .checked_div(BASIS_POINTS_DIVISOR.into())
+            .expect("Division error");
+        let mut referral_amount = 0;
+        if let Some(referrer_info) = optional_account(&ctx.accounts.referrer) {
+            if !referrer_info.is_writable {
+                return Err(error!(anchor_lang::error::ErrorCode::ConstraintMut));
+            }
+            let mut referrer = Account::<Referrer>::try_from(referrer_info).map_err(|_| error!(ErrorCode::InvalidReferrer))?;
+            assert_derivation(&crate::id(), referrer_info, &[REFERRER_SEED.as_bytes(), referrer.authority.as_ref()], ErrorCode::InvalidReferrer)?;
+            if referrer.authority == ctx.accounts.payer.key() || referrer.authority == ctx.accounts.recipient.key() {
+                return Err(error!(ErrorCode::InvalidReferrer));
+            }
+            let referrer_token_account = assert_token_account(
+                required_account_mut(&ctx.accounts.referrer_token_account)?,
+                &payment_mint,
+                &referrer.authority,
+                ErrorCode::InvalidReferrerTokenAccount,
+            )?;
+
+            // referral share never eats into the protocol fee
+            referral_amount = received_amount
+                .checked_mul(ctx.accounts.namespace.referral_basis_points.into())
+                .expect("Multiplication error")
+                .checked_div(BASIS_POINTS_DIVISOR.into())
+                .expect("Division error")
+                .min(received_amount.checked_sub(protocol_fee).expect("Sub error"));
+            if referral_amount > 0 {
+                let namespace_seeds = &[NAMESPACE_PREFIX.as_bytes(), ctx.accounts.namespace.name.as_bytes(), &[ctx.accounts.namespace.bump]];
+                let namespace_signer = &[&namespace_seeds[..]];
+                let cpi_accounts = Transfer {
+                    from: payment_token_account.to_account_info(),
+                    to: referrer_token_account.to_account_info(),
+                    authority: ctx.accounts.namespace.to_account_info(),
+                };
+                let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(namespace_signer);
+                token::transfer(cpi_context, referral_amount)?;
+            }
+            referrer.referral_count = referrer.referral_count.checked_add(1).expect("Add error");
+            referrer.exit(&crate::id())?;
+        }
+
+        // accrue protocol fees at the source, the namespace keeps the rest
+        let namespace = &mut ctx.accounts.namespace;
+        accrue_protocol_fees(namespace, payment_balance_after, fee_basis_points);
+        namespace.accounted_balance = payment_balance_after.checked_sub(referral_amount).expect("Sub error");
+    }
+
+    Ok(())
+}
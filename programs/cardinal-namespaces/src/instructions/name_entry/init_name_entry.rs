@@ -1,4 +1,7 @@
-use {crate::state::*, anchor_lang::prelude::*};
+use {
+    crate::{state::*, utils::*},
+    anchor_lang::prelude::*,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitNameEntryIx {
@@ -12,8 +15,8 @@ pub struct InitNameEntryCtx<'info> {
     #[account(
         init,
         payer = payer,
-        space = ENTRY_SIZE,
-        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), ix.name.as_bytes()],
+        space = entry_size(&ix.name),
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&ix.name).as_ref()],
         bump,
     )]
     name_entry: Account<'info, Entry>,
@@ -23,6 +26,9 @@ pub struct InitNameEntryCtx<'info> {
 }
 
 pub fn handler(ctx: Context<InitNameEntryCtx>, ix: InitNameEntryIx) -> Result<()> {
+    assert_name(&ctx.accounts.namespace, &ix.name)?;
+    // remaining accounts are [emoji_allowlist] for emoji names in namespaces with a name policy
+    assert_emoji_allowlist(&ctx.accounts.namespace, &ctx.accounts.namespace.key(), &ix.name, ctx.remaining_accounts.first())?;
     let name_entry = &mut ctx.accounts.name_entry;
     name_entry.bump = *ctx.bumps.get("name_entry").unwrap();
     name_entry.namespace = ctx.accounts.namespace.key();
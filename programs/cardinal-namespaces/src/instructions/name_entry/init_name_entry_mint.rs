@@ -1,28 +1,27 @@
 use anchor_lang::solana_program::program::invoke;
-use mpl_token_metadata::instruction::{create_master_edition_v3, create_metadata_accounts_v3};
+use mpl_token_metadata::instruction::{create_master_edition_v3, create_metadata_accounts_v3, verify_collection};
 
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::{prelude::*, solana_program::program::invoke_signed, solana_program::program_pack::Pack},
     anchor_spl::{
         associated_token::{self, AssociatedToken},
         token::{self, Token},
     },
-    mpl_token_metadata::state::Creator as MCreator,
+    mpl_token_metadata::state::Collection,
     spl_token::solana_program::system_instruction,
-    urlencoding::encode,
 };
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
-pub struct Creator {
-    pub address: Pubkey,
-    pub verified: bool,
-    pub share: u8,
-}
 
 #[derive(Accounts)]
 pub struct InitNameEntryMintCtx<'info> {
     namespace: Box<Account<'info, Namespace>>,
-    #[account(mut, constraint = name_entry.mint == Pubkey::default() @ ErrorCode::MintAlreadyInitialized)]
+    #[account(
+        mut,
+        constraint = name_entry.mint == Pubkey::default() @ ErrorCode::MintAlreadyInitialized,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
     name_entry: Account<'info, Entry>,
     #[account(mut)]
     payer: Signer<'info>,
@@ -49,9 +48,8 @@ pub struct InitNameEntryMintCtx<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitNameEntryMintCtx>) -> Result<()> {
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InitNameEntryMintCtx<'info>>) -> Result<()> {
     let name_entry = &mut ctx.accounts.name_entry;
-    name_entry.namespace = ctx.accounts.namespace.key();
     name_entry.mint = ctx.accounts.mint.key();
 
     let namespace_seeds = &[NAMESPACE_PREFIX.as_bytes(), ctx.accounts.namespace.name.as_bytes(), &[ctx.accounts.namespace.bump]];
@@ -87,19 +85,18 @@ pub fn handler(ctx: Context<InitNameEntryMintCtx>) -> Result<()> {
             ctx.accounts.namespace.key(),
             *ctx.accounts.payer.key,
             ctx.accounts.namespace.key(),
-            ctx.accounts.namespace.name.clone(),
-            "NAME".to_string(),
+            metadata_name(&ctx.accounts.namespace, &ctx.accounts.name_entry.name),
+            metadata_symbol(&ctx.accounts.namespace),
             // generative URL which will inclde image of the name with expiration data
-            "https://nft.cardinal.so/metadata/".to_string() + &ctx.accounts.mint.key().to_string() + "?name=" + &encode(ctx.accounts.name_entry.name.as_str()),
-            Some(vec![MCreator {
-                address: ctx.accounts.namespace.key(),
-                verified: true,
-                share: 100,
-            }]),
-            0,
+            metadata_uri(&ctx.accounts.namespace, &ctx.accounts.mint.key(), &ctx.accounts.name_entry.name),
+            Some(metadata_creators(&ctx.accounts.namespace.key(), &ctx.accounts.namespace)),
+            ctx.accounts.namespace.seller_fee_basis_points,
             true,
             true,
-            None,
+            ctx.accounts.namespace.collection_mint.map(|collection_mint| Collection {
+                verified: false,
+                key: collection_mint,
+            }),
             None,
             None,
         ),
@@ -165,5 +162,36 @@ pub fn handler(ctx: Context<InitNameEntryMintCtx>) -> Result<()> {
         namespace_signer,
     )?;
 
+    // verify collection
+    if let Some(collection_mint) = ctx.accounts.namespace.collection_mint {
+        let remaining_accs = &mut ctx.remaining_accounts.iter();
+        let collection_mint_info = next_account_info(remaining_accs)?;
+        let collection_metadata_info = next_account_info(remaining_accs)?;
+        let collection_master_edition_info = next_account_info(remaining_accs)?;
+        assert_namespace_collection(&collection_mint, collection_mint_info, collection_metadata_info, collection_master_edition_info)?;
+
+        invoke_signed(
+            &verify_collection(
+                *ctx.accounts.token_metadata_program.key,
+                *ctx.accounts.mint_metadata.key,
+                ctx.accounts.namespace.key(),
+                ctx.accounts.payer.key(),
+                collection_mint,
+                collection_metadata_info.key(),
+                collection_master_edition_info.key(),
+                None,
+            ),
+            &[
+                ctx.accounts.mint_metadata.to_account_info(),
+                ctx.accounts.namespace.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                collection_mint_info.to_account_info(),
+                collection_metadata_info.to_account_info(),
+                collection_master_edition_info.to_account_info(),
+            ],
+            namespace_signer,
+        )?;
+    }
+
     Ok(())
 }
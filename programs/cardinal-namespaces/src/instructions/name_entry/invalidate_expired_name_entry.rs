@@ -1,7 +1,7 @@
 use anchor_lang::AccountsClose;
 use anchor_spl::token::TokenAccount;
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
 };
 
@@ -10,7 +10,12 @@ pub struct InvalidateExpiredNameEntryCtx<'info> {
     #[account(mut)]
     pub namespace: Account<'info, Namespace>,
     // Must invalidate reverse entry first
-    #[account(mut, constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidEntry)]
+    #[account(
+        mut,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidEntry,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
     pub name_entry: Account<'info, Entry>,
     #[account(mut, constraint =
         namespace_token_account.mint == name_entry.mint
@@ -29,9 +34,12 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
     name_entry.data = None;
     name_entry.is_claimed = false;
 
+    // remaining accounts are [wallet_entry_counter] for counted entries followed by [reverse_entry] when set
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    decrement_wallet_entry_counter(name_entry, remaining_accs)?;
+
     // check reverse entry
     if name_entry.reverse_entry.is_some() {
-        let remaining_accs = &mut ctx.remaining_accounts.iter();
         let reverse_entry_info = next_account_info(remaining_accs)?;
 
         if !reverse_entry_info.data_is_empty() {
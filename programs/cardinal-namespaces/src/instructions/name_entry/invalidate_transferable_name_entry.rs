@@ -1,7 +1,5 @@
-use crate::instructions::assert_derivation;
-
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
     cardinal_token_manager::state::{TokenManager, TokenManagerState},
 };
@@ -10,7 +8,12 @@ use {
 pub struct InvalidateTransferableNameEntryCtx<'info> {
     #[account(mut)]
     namespace: Account<'info, Namespace>,
-    #[account(mut, constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidEntry)]
+    #[account(
+        mut,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidEntry,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
     name_entry: Account<'info, Entry>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     token_manager: UncheckedAccount<'info>,
@@ -19,7 +22,7 @@ pub struct InvalidateTransferableNameEntryCtx<'info> {
     invalidator: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<InvalidateTransferableNameEntryCtx>) -> Result<()> {
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateTransferableNameEntryCtx<'info>>) -> Result<()> {
     let name_entry = &mut ctx.accounts.name_entry;
     let namespace = &mut ctx.accounts.namespace;
 
@@ -28,6 +31,7 @@ pub fn handler(ctx: Context<InvalidateTransferableNameEntryCtx>) -> Result<()> {
         &cardinal_token_manager::id(),
         &ctx.accounts.token_manager.to_account_info(),
         &[cardinal_token_manager::state::TOKEN_MANAGER_SEED.as_bytes(), name_entry.mint.as_ref()],
+        ErrorCode::InvalidEntry,
     )?;
     if !ctx.accounts.token_manager.data_is_empty() {
         let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
@@ -39,6 +43,8 @@ pub fn handler(ctx: Context<InvalidateTransferableNameEntryCtx>) -> Result<()> {
     name_entry.data = None;
     name_entry.is_claimed = false;
     name_entry.mint = Pubkey::default();
+    // remaining accounts are [wallet_entry_counter] for entries counted against a per wallet limit
+    decrement_wallet_entry_counter(name_entry, &mut ctx.remaining_accounts.iter())?;
 
     namespace.count = namespace.count.checked_sub(1).expect("Sub error");
     Ok(())
@@ -1,4 +1,5 @@
 pub mod claim_name_entry;
+pub mod claim_name_entry_v2;
 pub mod close_name_entry;
 pub mod init_name_entry;
 pub mod init_name_entry_mint;
@@ -7,4 +8,5 @@ pub mod invalidate_transferable_name_entry;
 pub mod migrate_name_entry_mint;
 pub mod revoke_name_entry;
 pub mod set_name_entry_data;
+pub mod sync_name_entry_mint_metadata;
 pub mod update_name_entry_mint_metadata;
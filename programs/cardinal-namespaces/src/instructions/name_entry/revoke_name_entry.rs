@@ -1,5 +1,5 @@
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
     anchor_spl::token::Token,
     cardinal_token_manager::program::CardinalTokenManager,
@@ -9,7 +9,12 @@ use {
 pub struct RevokeNameEntryCtx<'info> {
     #[account(mut, constraint = namespace.approve_authority.is_some())]
     pub namespace: Account<'info, Namespace>,
-    #[account(mut, constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace)]
+    #[account(
+        mut,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
     pub name_entry: Box<Account<'info, Entry>>,
     #[account(mut,
         constraint =
@@ -17,7 +22,8 @@ pub struct RevokeNameEntryCtx<'info> {
         && claim_request.namespace == namespace.key()
         && claim_request.entry_name == name_entry.name
         && claim_request.counter == name_entry.claim_request_counter
-        @ ErrorCode::ClaimNotAllowed
+        @ ErrorCode::ClaimNotAllowed,
+        constraint = !claim_request_expired(&claim_request) @ ErrorCode::ClaimRequestExpired
     )]
     pub claim_request: Box<Account<'info, ClaimRequest>>,
     pub invalidator: Signer<'info>,
@@ -50,6 +56,11 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         name_entry.mint = Pubkey::default();
     }
 
+    // the wallet entry counter comes first for counted entries, the rest are forwarded to the token manager
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    decrement_wallet_entry_counter(name_entry, remaining_accs)?;
+    let remaining_accounts = remaining_accs.as_slice();
+
     let namespace = &mut ctx.accounts.namespace;
     namespace.count = namespace.count.checked_sub(1).expect("Sub error");
 
@@ -67,7 +78,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         rent: ctx.accounts.rent.to_account_info(),
     };
     let cpi_ctx = CpiContext::new(ctx.accounts.token_manager_program.to_account_info(), cpi_accounts)
-        .with_remaining_accounts(ctx.remaining_accounts.to_vec())
+        .with_remaining_accounts(remaining_accounts.to_vec())
         .with_signer(namespace_signer);
     cardinal_token_manager::cpi::invalidate(cpi_ctx)?;
 
@@ -11,7 +11,12 @@ use {
 #[derive(Accounts)]
 pub struct SetEntryDataV2Ctx<'info> {
     namespace: Box<Account<'info, Namespace>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
     name_entry: Box<Account<'info, Entry>>,
 
     #[account(constraint =
@@ -0,0 +1,67 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::{prelude::*, solana_program::program::invoke_signed},
+    mpl_token_metadata::{
+        instruction::update_metadata_accounts_v2,
+        state::{Collection, DataV2, Metadata, TokenMetadataAccount},
+    },
+};
+
+#[derive(Accounts)]
+pub struct SyncNameEntryMintMetadataCtx<'info> {
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(mut)]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    mint_metadata: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = mpl_token_metadata::id())]
+    token_metadata_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SyncNameEntryMintMetadataCtx>) -> Result<()> {
+    let namespace_seeds = &[NAMESPACE_PREFIX.as_bytes(), ctx.accounts.namespace.name.as_bytes(), &[ctx.accounts.namespace.bump]];
+    let namespace_signer = &[&namespace_seeds[..]];
+
+    assert_derivation(
+        &mpl_token_metadata::id(),
+        &ctx.accounts.mint_metadata,
+        &[mpl_token_metadata::state::PREFIX.as_bytes(), mpl_token_metadata::id().as_ref(), ctx.accounts.name_entry.mint.as_ref()],
+        ErrorCode::InvalidEntry,
+    )?;
+
+    // collection is left unverified here, it can be verified through update_name_entry_mint_metadata
+    let mint_metadata: Metadata = Metadata::from_account_info(&ctx.accounts.mint_metadata)?;
+    let collection = ctx.accounts.namespace.collection_mint.map(|collection_mint| Collection {
+        verified: mint_metadata.collection.map(|c| c.verified && c.key == collection_mint).unwrap_or(false),
+        key: collection_mint,
+    });
+
+    invoke_signed(
+        &update_metadata_accounts_v2(
+            *ctx.accounts.token_metadata_program.key,
+            *ctx.accounts.mint_metadata.key,
+            ctx.accounts.namespace.key(),
+            None,
+            Some(DataV2 {
+                name: metadata_name(&ctx.accounts.namespace, &ctx.accounts.name_entry.name),
+                symbol: metadata_symbol(&ctx.accounts.namespace),
+                uri: metadata_uri(&ctx.accounts.namespace, &ctx.accounts.name_entry.mint, &ctx.accounts.name_entry.name),
+                creators: Some(metadata_creators(&ctx.accounts.namespace.key(), &ctx.accounts.namespace)),
+                seller_fee_basis_points: ctx.accounts.namespace.seller_fee_basis_points,
+                collection,
+                uses: None,
+            }),
+            None,
+            None,
+        ),
+        &[ctx.accounts.mint_metadata.to_account_info(), ctx.accounts.namespace.to_account_info()],
+        namespace_signer,
+    )?;
+    Ok(())
+}
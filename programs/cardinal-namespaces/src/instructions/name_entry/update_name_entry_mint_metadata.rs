@@ -1,13 +1,10 @@
-use super::init_name_entry_mint::Creator;
-
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::{prelude::*, solana_program::program::invoke_signed},
     mpl_token_metadata::{
-        instruction::update_metadata_accounts_v2,
-        state::{Creator as MCreator, DataV2},
+        instruction::{update_metadata_accounts_v2, verify_collection},
+        state::{Collection, Creator as MCreator, DataV2, Metadata, TokenMetadataAccount},
     },
-    urlencoding::encode,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -22,9 +19,13 @@ pub struct UpdateNameEntryMintMetadataIx {
 #[derive(Accounts)]
 pub struct UpdateNameEntryMintMetadataCtx<'info> {
     namespace: Box<Account<'info, Namespace>>,
-    #[account(constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace)]
+    #[account(
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
     name_entry: Box<Account<'info, Entry>>,
-    #[account(constraint = update_authority.key() == namespace.update_authority @ ErrorCode::InvalidAuthority)]
+    #[account(mut, constraint = update_authority.key() == namespace.update_authority @ ErrorCode::InvalidAuthority)]
     update_authority: Signer<'info>,
     #[account(mut)]
     /// CHECK: This is not dangerous because we don't read or write from this account
@@ -33,7 +34,7 @@ pub struct UpdateNameEntryMintMetadataCtx<'info> {
     token_metadata_program: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<UpdateNameEntryMintMetadataCtx>, ix: UpdateNameEntryMintMetadataIx) -> Result<()> {
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, UpdateNameEntryMintMetadataCtx<'info>>, ix: UpdateNameEntryMintMetadataIx) -> Result<()> {
     let namespace_seeds = &[NAMESPACE_PREFIX.as_bytes(), ctx.accounts.namespace.name.as_bytes(), &[ctx.accounts.namespace.bump]];
     let namespace_signer = &[&namespace_seeds[..]];
 
@@ -41,8 +42,21 @@ pub fn handler(ctx: Context<UpdateNameEntryMintMetadataCtx>, ix: UpdateNameEntry
         &mpl_token_metadata::id(),
         &ctx.accounts.mint_metadata.to_account_info(),
         &[mpl_token_metadata::state::PREFIX.as_bytes(), mpl_token_metadata::id().as_ref(), ctx.accounts.name_entry.mint.as_ref()],
+        ErrorCode::InvalidEntry,
     )?;
 
+    // collection stays verified if it was already verified for the namespace collection
+    let collection = match ctx.accounts.namespace.collection_mint {
+        Some(collection_mint) => {
+            let mint_metadata: Metadata = Metadata::from_account_info(&ctx.accounts.mint_metadata)?;
+            Some(Collection {
+                verified: mint_metadata.collection.map(|c| c.verified && c.key == collection_mint).unwrap_or(false),
+                key: collection_mint,
+            })
+        }
+        None => None,
+    };
+
     // update metadata
     invoke_signed(
         &update_metadata_accounts_v2(
@@ -51,9 +65,9 @@ pub fn handler(ctx: Context<UpdateNameEntryMintMetadataCtx>, ix: UpdateNameEntry
             ctx.accounts.namespace.key(),
             Some(ctx.accounts.namespace.key()),
             Some(DataV2 {
-                name: ctx.accounts.name_entry.name.clone() + "." + &ctx.accounts.namespace.name.to_string(),
-                symbol: "NAME".to_string(),
-                uri: "https://nft.cardinal.so/metadata/".to_string() + &ctx.accounts.name_entry.mint.to_string() + &"?name=".to_string() + &encode(ctx.accounts.name_entry.name.as_str()).into_owned(),
+                name: metadata_name(&ctx.accounts.namespace, &ctx.accounts.name_entry.name),
+                symbol: metadata_symbol(&ctx.accounts.namespace),
+                uri: metadata_uri(&ctx.accounts.namespace, &ctx.accounts.name_entry.mint, &ctx.accounts.name_entry.name),
                 creators: Some(
                     [
                         vec![Creator {
@@ -73,7 +87,7 @@ pub fn handler(ctx: Context<UpdateNameEntryMintMetadataCtx>, ix: UpdateNameEntry
                     .collect(),
                 ),
                 seller_fee_basis_points: ix.seller_fee_basis_points,
-                collection: None,
+                collection: collection.clone(),
                 uses: None,
             }),
             ix.primary_sale_happened,
@@ -82,13 +96,38 @@ pub fn handler(ctx: Context<UpdateNameEntryMintMetadataCtx>, ix: UpdateNameEntry
         &[ctx.accounts.mint_metadata.to_account_info(), ctx.accounts.namespace.to_account_info()],
         namespace_signer,
     )?;
-    Ok(())
-}
 
-pub fn assert_derivation(program_id: &Pubkey, account: &AccountInfo, path: &[&[u8]]) -> Result<u8> {
-    let (key, bump) = Pubkey::find_program_address(path, program_id);
-    if key != *account.key {
-        return Err(ErrorCode::InvalidEntry.into());
+    // verify collection
+    if let Some(collection) = collection {
+        if !collection.verified {
+            let remaining_accs = &mut ctx.remaining_accounts.iter();
+            let collection_mint_info = next_account_info(remaining_accs)?;
+            let collection_metadata_info = next_account_info(remaining_accs)?;
+            let collection_master_edition_info = next_account_info(remaining_accs)?;
+            assert_namespace_collection(&collection.key, collection_mint_info, collection_metadata_info, collection_master_edition_info)?;
+
+            invoke_signed(
+                &verify_collection(
+                    *ctx.accounts.token_metadata_program.key,
+                    *ctx.accounts.mint_metadata.key,
+                    ctx.accounts.namespace.key(),
+                    ctx.accounts.update_authority.key(),
+                    collection.key,
+                    collection_metadata_info.key(),
+                    collection_master_edition_info.key(),
+                    None,
+                ),
+                &[
+                    ctx.accounts.mint_metadata.to_account_info(),
+                    ctx.accounts.namespace.to_account_info(),
+                    ctx.accounts.update_authority.to_account_info(),
+                    collection_mint_info.to_account_info(),
+                    collection_metadata_info.to_account_info(),
+                    collection_master_edition_info.to_account_info(),
+                ],
+                namespace_signer,
+            )?;
+        }
     }
-    Ok(bump)
+    Ok(())
 }
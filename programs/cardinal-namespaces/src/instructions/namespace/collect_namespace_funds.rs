@@ -1,7 +1,8 @@
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
+    spl_associated_token_account::get_associated_token_address,
 };
 
 #[derive(Accounts)]
@@ -11,9 +12,12 @@ pub struct CollectNamespaceFundsCtx<'info> {
 
     #[account(mut, constraint = global_context_payment_account.owner == global_context.key() && global_context_payment_account.mint == namespace.payment_mint @ ErrorCode::InvalidGlobalContextPaymentAccount)]
     pub global_context_payment_account: Account<'info, TokenAccount>,
+    /// CHECK: This is not dangerous because we check the derivation inside the handler
+    pub namespace_fee_override: UncheckedAccount<'info>,
+    #[account(mut, constraint = namespace.beneficiaries.is_none() @ ErrorCode::NamespaceHasBeneficiaries)]
     pub namespace: Account<'info, Namespace>,
 
-    #[account(mut, constraint = namespace_payment_account.owner == namespace.key() && namespace_payment_account.mint == namespace.payment_mint @ ErrorCode::InvalidNamespacePaymentAccount)]
+    #[account(mut, constraint = namespace_payment_account.key() == get_associated_token_address(&namespace.key(), &namespace.payment_mint) @ ErrorCode::InvalidNamespacePaymentAccount)]
     pub namespace_payment_account: Account<'info, TokenAccount>,
     #[account(constraint = namespace.rent_authority == rent_authority.key())]
     pub rent_authority: Signer<'info>,
@@ -23,17 +27,26 @@ pub struct CollectNamespaceFundsCtx<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<CollectNamespaceFundsCtx>, amount: u64) -> Result<()> {
+/// Withdraws `amount` from the namespace payment account, or everything when `amount` is None
+/// Accrued protocol fees are paid to the global context out of the withdrawn amount first
+pub fn handler(ctx: Context<CollectNamespaceFundsCtx>, amount: Option<u64>) -> Result<()> {
+    let fee_basis_points = namespace_fee_basis_points(&ctx.accounts.global_context, &ctx.accounts.namespace_fee_override, &ctx.accounts.namespace.key())?;
+    let payment_balance = ctx.accounts.namespace_payment_account.amount;
+    let namespace = &mut ctx.accounts.namespace;
+    accrue_protocol_fees(namespace, payment_balance, fee_basis_points);
+    let amount = amount.unwrap_or(payment_balance);
+    if amount > payment_balance {
+        return Err(error!(ErrorCode::InvalidCollectAmount));
+    }
+    let global_context_payment = std::cmp::min(namespace.accrued_protocol_fees, amount);
+    let authority_payment = amount.checked_sub(global_context_payment).expect("Sub error");
+    namespace.accrued_protocol_fees = namespace.accrued_protocol_fees.checked_sub(global_context_payment).expect("Sub error");
+    namespace.accounted_balance = payment_balance.checked_sub(amount).expect("Sub error");
+
     // get PDA seeds to sign with
     let namespace_seeds = &[NAMESPACE_PREFIX.as_bytes(), ctx.accounts.namespace.name.as_bytes(), &[ctx.accounts.namespace.bump]];
     let namespace_signer = &[&namespace_seeds[..]];
 
-    let global_context_payment = amount
-        .checked_mul(ctx.accounts.global_context.fee_basis_points)
-        .expect("Multiplication error")
-        .checked_div(BASIS_POINTS_DIVISOR.into())
-        .expect("Division error");
-
     // transfer amount to authority
     let cpi_accounts = Transfer {
         from: ctx.accounts.namespace_payment_account.to_account_info(),
@@ -42,7 +55,7 @@ pub fn handler(ctx: Context<CollectNamespaceFundsCtx>, amount: u64) -> Result<()
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(namespace_signer);
-    token::transfer(cpi_context, amount.checked_sub(global_context_payment).expect("Sub error"))?;
+    token::transfer(cpi_context, authority_payment)?;
 
     // transfer amount to global namespace
     let cpi_accounts = Transfer {
@@ -58,6 +58,23 @@ pub fn handler(ctx: Context<CreateNamespace>, ix: CreateNamespaceIx) -> Result<(
     namespace.max_expiration = ix.max_expiration;
     namespace.invalidation_type = ix.invalidation_type;
     namespace.count = 0;
+    namespace.collection_mint = None;
+    namespace.metadata_uri_template = None;
+    namespace.metadata_symbol = None;
+    namespace.metadata_name_format = None;
+    namespace.seller_fee_basis_points = 0;
+    namespace.creators = None;
+    namespace.referral_basis_points = 0;
+    namespace.beneficiaries = None;
+    namespace.accrued_protocol_fees = 0;
+    namespace.accounted_balance = 0;
+    namespace.allowlist_root = None;
+    namespace.gate = None;
+    namespace.max_entries_per_wallet = None;
+    namespace.name_policy = NamePolicy::Unrestricted as u8;
+    namespace.min_name_length = None;
+    namespace.max_name_length = None;
+    namespace.payment_tiers = None;
 
     if ix.invalidation_type != InvalidationType::Return as u8
         && ix.invalidation_type != InvalidationType::Invalidate as u8
@@ -0,0 +1,84 @@
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[derive(Accounts)]
+pub struct DistributeNamespaceFundsCtx<'info> {
+    #[account(seeds = [GLOBAL_CONTEXT_PREFIX.as_bytes()], bump = global_context.bump)]
+    pub global_context: Account<'info, GlobalContext>,
+
+    #[account(mut, constraint = global_context_payment_account.owner == global_context.key() && global_context_payment_account.mint == namespace.payment_mint @ ErrorCode::InvalidGlobalContextPaymentAccount)]
+    pub global_context_payment_account: Account<'info, TokenAccount>,
+    /// CHECK: This is not dangerous because we check the derivation inside the handler
+    pub namespace_fee_override: UncheckedAccount<'info>,
+    #[account(mut, constraint = namespace.beneficiaries.is_some() @ ErrorCode::InvalidBeneficiaries)]
+    pub namespace: Account<'info, Namespace>,
+
+    #[account(mut, constraint = namespace_payment_account.key() == get_associated_token_address(&namespace.key(), &namespace.payment_mint) @ ErrorCode::InvalidNamespacePaymentAccount)]
+    pub namespace_payment_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining accounts are the beneficiary token accounts in the order of namespace.beneficiaries
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, DistributeNamespaceFundsCtx<'info>>) -> Result<()> {
+    let fee_basis_points = namespace_fee_basis_points(&ctx.accounts.global_context, &ctx.accounts.namespace_fee_override, &ctx.accounts.namespace.key())?;
+    let amount = ctx.accounts.namespace_payment_account.amount;
+    let namespace = &mut ctx.accounts.namespace;
+    accrue_protocol_fees(namespace, amount, fee_basis_points);
+    let global_context_payment = namespace.accrued_protocol_fees;
+    namespace.accrued_protocol_fees = 0;
+    namespace.accounted_balance = 0;
+
+    // get PDA seeds to sign with
+    let namespace_seeds = &[NAMESPACE_PREFIX.as_bytes(), ctx.accounts.namespace.name.as_bytes(), &[ctx.accounts.namespace.bump]];
+    let namespace_signer = &[&namespace_seeds[..]];
+
+    // transfer amount to global namespace
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.namespace_payment_account.to_account_info(),
+        to: ctx.accounts.global_context_payment_account.to_account_info(),
+        authority: ctx.accounts.namespace.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(namespace_signer);
+    token::transfer(cpi_context, global_context_payment)?;
+
+    // split the rest between beneficiaries, rounding remainder goes to the last beneficiary
+    let beneficiaries = ctx.accounts.namespace.beneficiaries.clone().expect("No beneficiaries");
+    let distributable_amount = amount.checked_sub(global_context_payment).expect("Sub error");
+    let mut remaining_amount = distributable_amount;
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    for (i, beneficiary) in beneficiaries.iter().enumerate() {
+        let beneficiary_token_account_info = next_account_info(remaining_accs)?;
+        assert_token_account(
+            beneficiary_token_account_info,
+            &ctx.accounts.namespace.payment_mint,
+            &beneficiary.address,
+            ErrorCode::InvalidBeneficiaryTokenAccount,
+        )?;
+        let beneficiary_amount = if i == beneficiaries.len() - 1 {
+            remaining_amount
+        } else {
+            distributable_amount
+                .checked_mul(beneficiary.basis_points.into())
+                .expect("Multiplication error")
+                .checked_div(BASIS_POINTS_DIVISOR.into())
+                .expect("Division error")
+        };
+        remaining_amount = remaining_amount.checked_sub(beneficiary_amount).expect("Sub error");
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.namespace_payment_account.to_account_info(),
+            to: beneficiary_token_account_info.to_account_info(),
+            authority: ctx.accounts.namespace.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(namespace_signer);
+        token::transfer(cpi_context, beneficiary_amount)?;
+    }
+    Ok(())
+}
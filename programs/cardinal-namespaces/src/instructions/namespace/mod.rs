@@ -1,3 +1,6 @@
 pub mod collect_namespace_funds;
 pub mod create_namespace;
+pub mod distribute_namespace_funds;
+pub mod init_namespace_collection;
+pub mod set_emoji_allowlist;
 pub mod update_namespace;
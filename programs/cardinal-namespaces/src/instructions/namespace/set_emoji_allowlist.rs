@@ -0,0 +1,41 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(sequences: Vec<String>)]
+pub struct SetEmojiAllowlistCtx<'info> {
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = emoji_allowlist_size(&sequences),
+        seeds = [EMOJI_ALLOWLIST_SEED.as_bytes(), namespace.key().as_ref()],
+        bump,
+    )]
+    emoji_allowlist: Box<Account<'info, EmojiAllowlist>>,
+    #[account(constraint = namespace.update_authority == update_authority.key() @ ErrorCode::InvalidAuthority)]
+    update_authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetEmojiAllowlistCtx>, sequences: Vec<String>) -> Result<()> {
+    if sequences.iter().any(|s| name_length(s) != 1 || !is_emoji_grapheme(s)) {
+        return Err(error!(ErrorCode::InvalidEmojiAllowlist));
+    }
+    resize_account(
+        &ctx.accounts.emoji_allowlist.to_account_info(),
+        emoji_allowlist_size(&sequences),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let emoji_allowlist = &mut ctx.accounts.emoji_allowlist;
+    emoji_allowlist.bump = *ctx.bumps.get("emoji_allowlist").unwrap();
+    emoji_allowlist.namespace = ctx.accounts.namespace.key();
+    emoji_allowlist.sequences = sequences;
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use {crate::state::*, anchor_lang::prelude::*};
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateNamespaceIx {
@@ -16,6 +19,29 @@ pub struct UpdateNamespaceIx {
     pub limit: Option<u32>,
     pub max_expiration: Option<i64>,
     pub invalidation_type: u8,
+    // metadata
+    pub metadata_uri_template: Option<String>,
+    pub metadata_symbol: Option<String>,
+    pub metadata_name_format: Option<String>,
+    // royalties
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<Creator>>,
+    // referrals
+    pub referral_basis_points: u16,
+    // revenue splits
+    pub beneficiaries: Option<Vec<Beneficiary>>,
+    // allowlist
+    pub allowlist_root: Option<[u8; 32]>,
+    // token gate
+    pub gate: Option<Gate>,
+    // per wallet limit
+    pub max_entries_per_wallet: Option<u32>,
+    // name normalization
+    pub name_policy: u8,
+    // name length in grapheme clusters
+    pub min_name_length: Option<u32>,
+    pub max_name_length: Option<u32>,
+    pub payment_tiers: Option<Vec<PaymentTier>>,
 }
 
 #[derive(Accounts)]
@@ -28,15 +54,33 @@ pub struct UpdateNamepsace<'info> {
     pub namespace: Account<'info, Namespace>,
     #[account(constraint = namespace.update_authority == update_authority.key())]
     pub update_authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<UpdateNamepsace>, ix: UpdateNamespaceIx) -> Result<()> {
+    // namespaces created before the latest fields were appended need more space
+    resize_account(
+        &ctx.accounts.namespace.to_account_info(),
+        NAMESPACE_SIZE,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
     let namespace = &mut ctx.accounts.namespace;
     namespace.update_authority = ix.update_authority;
     namespace.rent_authority = ix.rent_authority;
     namespace.approve_authority = ix.approve_authority;
     namespace.schema = ix.schema;
     // payment
+    if ix.payment_mint != namespace.payment_mint {
+        // fee accounting is tracked against the payment mint account
+        if namespace.accrued_protocol_fees > 0 {
+            return Err(error!(ErrorCode::NamespaceHasAccruedFees));
+        }
+        namespace.accounted_balance = 0;
+    }
     namespace.payment_amount_daily = ix.payment_amount_daily;
     namespace.payment_mint = ix.payment_mint;
     // validators
@@ -46,6 +90,60 @@ pub fn handler(ctx: Context<UpdateNamepsace>, ix: UpdateNamespaceIx) -> Result<(
     namespace.limit = ix.limit;
     namespace.max_expiration = ix.max_expiration;
     namespace.invalidation_type = ix.invalidation_type;
+    // metadata
+    if matches!(&ix.metadata_uri_template, Some(t) if t.len() > MAX_METADATA_URI_TEMPLATE_LENGTH)
+        || matches!(&ix.metadata_symbol, Some(s) if s.len() > MAX_METADATA_SYMBOL_LENGTH)
+        || matches!(&ix.metadata_name_format, Some(f) if f.len() > MAX_METADATA_NAME_FORMAT_LENGTH)
+    {
+        return Err(error!(ErrorCode::InvalidMetadataConfig));
+    }
+    namespace.metadata_uri_template = ix.metadata_uri_template;
+    namespace.metadata_symbol = ix.metadata_symbol;
+    namespace.metadata_name_format = ix.metadata_name_format;
+    // royalties
+    assert_royalty_config(&namespace.key(), ix.seller_fee_basis_points, &ix.creators)?;
+    namespace.seller_fee_basis_points = ix.seller_fee_basis_points;
+    namespace.creators = ix.creators;
+    // referrals
+    if ix.referral_basis_points > BASIS_POINTS_DIVISOR {
+        return Err(error!(ErrorCode::InvalidReferralBasisPoints));
+    }
+    namespace.referral_basis_points = ix.referral_basis_points;
+    // revenue splits
+    if let Some(beneficiaries) = &ix.beneficiaries {
+        if beneficiaries.is_empty() || beneficiaries.len() > MAX_NAMESPACE_BENEFICIARIES || beneficiaries.iter().map(|b| b.basis_points as u64).sum::<u64>() != BASIS_POINTS_DIVISOR as u64 {
+            return Err(error!(ErrorCode::InvalidBeneficiaries));
+        }
+    }
+    namespace.beneficiaries = ix.beneficiaries;
+    // allowlist
+    namespace.allowlist_root = ix.allowlist_root;
+    // token gate
+    if let Some(gate) = &ix.gate {
+        if gate.gate_type != GateType::Collection as u8 && gate.gate_type != GateType::Mint as u8 {
+            return Err(error!(ErrorCode::InvalidGate));
+        }
+    }
+    namespace.gate = ix.gate;
+    // per wallet limit
+    namespace.max_entries_per_wallet = ix.max_entries_per_wallet;
+    // name normalization, only applies to names created from now on
+    if ix.name_policy > NamePolicy::Strict as u8 {
+        return Err(error!(ErrorCode::InvalidNamePolicy));
+    }
+    namespace.name_policy = ix.name_policy;
+    // name length
+    if matches!((ix.min_name_length, ix.max_name_length), (Some(min), Some(max)) if min > max) {
+        return Err(error!(ErrorCode::InvalidNameLengthLimits));
+    }
+    namespace.min_name_length = ix.min_name_length;
+    namespace.max_name_length = ix.max_name_length;
+    if let Some(payment_tiers) = &ix.payment_tiers {
+        if payment_tiers.is_empty() || payment_tiers.len() > MAX_PAYMENT_TIERS || payment_tiers.windows(2).any(|w| w[0].max_name_length >= w[1].max_name_length) {
+            return Err(error!(ErrorCode::InvalidPaymentTiers));
+        }
+    }
+    namespace.payment_tiers = ix.payment_tiers;
 
     Ok(())
 }
@@ -0,0 +1,156 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[derive(Accounts)]
+pub struct AcceptNameEntryOfferCtx<'info> {
+    #[account(mut, constraint = namespace.transferable_entries @ ErrorCode::EntriesNotTransferable)]
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        mut,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        mut,
+        close = buyer,
+        constraint = offer.name_entry == name_entry.key() && offer.payment_mint == namespace.payment_mint @ ErrorCode::InvalidOffer,
+        constraint = !offer_expired(&offer) @ ErrorCode::OfferExpired,
+        seeds = [OFFER_SEED.as_bytes(), name_entry.key().as_ref(), offer.buyer.as_ref()],
+        bump = offer.bump,
+    )]
+    offer: Box<Account<'info, Offer>>,
+    #[account(mut, constraint = offer_token_account.mint == offer.payment_mint && offer_token_account.owner == offer.key() @ ErrorCode::InvalidOffer)]
+    offer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(address = name_entry.mint @ ErrorCode::InvalidEntryMint)]
+    mint: Box<Account<'info, Mint>>,
+    /// CHECK: This is not dangerous because we check the address
+    #[account(mut, address = offer.buyer @ ErrorCode::InvalidOffer)]
+    buyer: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+    )]
+    buyer_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint =
+        holder_token_account.mint == name_entry.mint
+        && holder_token_account.owner == holder.key()
+        && holder_token_account.amount > 0
+        @ ErrorCode::InvalidOwnerMint
+    )]
+    holder_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    time_invalidator: UncheckedAccount<'info>,
+    #[account(mut)]
+    holder: Signer<'info>,
+    #[account(mut, constraint = holder_payment_token_account.mint == offer.payment_mint && holder_payment_token_account.owner == holder.key() @ ErrorCode::InvalidPaymentMint)]
+    holder_payment_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because we check inside the handler, pass the namespaces program id when the entry has no reverse entry
+    reverse_entry: UncheckedAccount<'info>,
+
+    #[account(mut, address = get_associated_token_address(&namespace.key(), &namespace.payment_mint) @ ErrorCode::InvalidPaymentMint)]
+    namespace_payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(seeds = [GLOBAL_CONTEXT_PREFIX.as_bytes()], bump = global_context.bump)]
+    global_context: Box<Account<'info, GlobalContext>>,
+    /// CHECK: This is not dangerous because we check the address inside the handler
+    namespace_fee_override: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    rent: Sysvar<'info, Rent>,
+    system_program: Program<'info, System>,
+    // remaining accounts are payment token accounts for each namespace metadata creator with a share, the namespace uses its payment account
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, AcceptNameEntryOfferCtx<'info>>) -> Result<()> {
+    // offers can only be accepted while the holder's rental is still running
+    assert_name_entry_live(
+        &ctx.accounts.namespace.key(),
+        &ctx.accounts.name_entry,
+        &ctx.accounts.token_manager,
+        &ctx.accounts.time_invalidator,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let amount = ctx.accounts.offer.amount;
+    // the holder receives the whole escrow balance so tokens sent to the escrow do not block closing it
+    let escrow_balance = ctx.accounts.offer_token_account.amount;
+    let payment_mint = ctx.accounts.offer.payment_mint;
+    let offer_seeds = &[
+        OFFER_SEED.as_bytes(),
+        ctx.accounts.offer.name_entry.as_ref(),
+        ctx.accounts.offer.buyer.as_ref(),
+        &[ctx.accounts.offer.bump],
+    ];
+    let offer_signer = &[&offer_seeds[..]];
+
+    // namespace fee is owed to the protocol, account for earlier payments before depositing it
+    let fee_basis_points = namespace_fee_basis_points(&ctx.accounts.global_context, &ctx.accounts.namespace_fee_override, &ctx.accounts.namespace.key())?;
+    let namespace_fee = accrue_sale_fee(&mut ctx.accounts.namespace, amount, ctx.accounts.namespace_payment_token_account.amount, fee_basis_points);
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.offer_token_account.to_account_info(),
+        to: ctx.accounts.namespace_payment_token_account.to_account_info(),
+        authority: ctx.accounts.offer.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(offer_signer);
+    token::transfer(cpi_context, namespace_fee)?;
+
+    // royalties follow the namespace metadata configuration
+    let royalties_paid = pay_royalties(
+        &ctx.accounts.namespace,
+        amount,
+        &payment_mint,
+        &ctx.accounts.offer_token_account.to_account_info(),
+        &ctx.accounts.offer.to_account_info(),
+        offer_signer,
+        &ctx.accounts.token_program.to_account_info(),
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    // pay holder
+    let holder_amount = escrow_balance
+        .checked_sub(namespace_fee)
+        .and_then(|amount| amount.checked_sub(royalties_paid))
+        .ok_or_else(|| error!(ErrorCode::InvalidOffer))?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.offer_token_account.to_account_info(),
+        to: ctx.accounts.holder_payment_token_account.to_account_info(),
+        authority: ctx.accounts.offer.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(offer_signer);
+    token::transfer(cpi_context, holder_amount)?;
+
+    // close escrow
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.offer_token_account.to_account_info(),
+        destination: ctx.accounts.buyer.to_account_info(),
+        authority: ctx.accounts.offer.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(offer_signer);
+    token::close_account(cpi_context)?;
+
+    // transfer the name to the buyer
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.holder_token_account.to_account_info(),
+        to: ctx.accounts.buyer_token_account.to_account_info(),
+        authority: ctx.accounts.holder.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_context, ctx.accounts.holder_token_account.amount)?;
+
+    reassign_name_entry(&mut ctx.accounts.name_entry, &ctx.accounts.reverse_entry, &ctx.accounts.holder, &ctx.accounts.buyer.key())?;
+    Ok(())
+}
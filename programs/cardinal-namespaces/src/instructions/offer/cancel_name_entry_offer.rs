@@ -0,0 +1,57 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct CancelNameEntryOfferCtx<'info> {
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [OFFER_SEED.as_bytes(), offer.name_entry.as_ref(), offer.buyer.as_ref()],
+        bump = offer.bump,
+    )]
+    offer: Box<Account<'info, Offer>>,
+    #[account(mut, constraint = offer_token_account.mint == offer.payment_mint && offer_token_account.owner == offer.key() @ ErrorCode::InvalidOffer)]
+    offer_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because we check the address
+    #[account(mut, address = offer.buyer @ ErrorCode::InvalidOffer)]
+    buyer: UncheckedAccount<'info>,
+    #[account(mut, constraint = buyer_payment_token_account.mint == offer.payment_mint && buyer_payment_token_account.owner == offer.buyer @ ErrorCode::InvalidPayerTokenAccount)]
+    buyer_payment_token_account: Box<Account<'info, TokenAccount>>,
+
+    // the buyer can cancel at any time, anyone can refund an expired offer
+    #[account(constraint = user.key() == offer.buyer || offer_expired(&offer) @ ErrorCode::OfferNotExpired)]
+    user: Signer<'info>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CancelNameEntryOfferCtx>) -> Result<()> {
+    let offer_seeds = &[
+        OFFER_SEED.as_bytes(),
+        ctx.accounts.offer.name_entry.as_ref(),
+        ctx.accounts.offer.buyer.as_ref(),
+        &[ctx.accounts.offer.bump],
+    ];
+    let offer_signer = &[&offer_seeds[..]];
+
+    // refund the buyer
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.offer_token_account.to_account_info(),
+        to: ctx.accounts.buyer_payment_token_account.to_account_info(),
+        authority: ctx.accounts.offer.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(offer_signer);
+    token::transfer(cpi_context, ctx.accounts.offer_token_account.amount)?;
+
+    // close escrow
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.offer_token_account.to_account_info(),
+        destination: ctx.accounts.buyer.to_account_info(),
+        authority: ctx.accounts.offer.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(offer_signer);
+    token::close_account(cpi_context)?;
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{self, Mint, Token, TokenAccount, Transfer},
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MakeNameEntryOfferIx {
+    pub amount: u64,
+    pub expires_at: i64,
+}
+
+#[derive(Accounts)]
+pub struct MakeNameEntryOfferCtx<'info> {
+    #[account(constraint = namespace.transferable_entries @ ErrorCode::EntriesNotTransferable)]
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        constraint = name_entry.is_claimed @ ErrorCode::InvalidOffer,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        init,
+        payer = buyer,
+        space = OFFER_SIZE,
+        seeds = [OFFER_SEED.as_bytes(), name_entry.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    offer: Box<Account<'info, Offer>>,
+    #[account(
+        init,
+        payer = buyer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = offer,
+    )]
+    offer_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(address = namespace.payment_mint @ ErrorCode::InvalidPaymentMint)]
+    payment_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    buyer: Signer<'info>,
+    #[account(mut, constraint = buyer_payment_token_account.mint == payment_mint.key() && buyer_payment_token_account.owner == buyer.key() @ ErrorCode::InvalidPayerTokenAccount)]
+    buyer_payment_token_account: Box<Account<'info, TokenAccount>>,
+
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    rent: Sysvar<'info, Rent>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MakeNameEntryOfferCtx>, ix: MakeNameEntryOfferIx) -> Result<()> {
+    if ix.expires_at <= Clock::get()?.unix_timestamp {
+        return Err(error!(ErrorCode::OfferExpired));
+    }
+
+    let offer = &mut ctx.accounts.offer;
+    offer.bump = *ctx.bumps.get("offer").unwrap();
+    offer.namespace = ctx.accounts.namespace.key();
+    offer.name_entry = ctx.accounts.name_entry.key();
+    offer.buyer = ctx.accounts.buyer.key();
+    offer.payment_mint = ctx.accounts.payment_mint.key();
+    offer.amount = ix.amount;
+    offer.expires_at = ix.expires_at;
+
+    // escrow the offer
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.buyer_payment_token_account.to_account_info(),
+        to: ctx.accounts.offer_token_account.to_account_info(),
+        authority: ctx.accounts.buyer.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_context, ix.amount)?;
+    Ok(())
+}
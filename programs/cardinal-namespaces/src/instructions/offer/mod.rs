@@ -0,0 +1,3 @@
+pub mod accept_name_entry_offer;
+pub mod cancel_name_entry_offer;
+pub mod make_name_entry_offer;
@@ -0,0 +1,18 @@
+use {crate::state::*, anchor_lang::prelude::*};
+
+#[derive(Accounts)]
+pub struct ClosePrimaryNameCtx<'info> {
+    #[account(
+        mut,
+        close = user,
+        seeds = [PRIMARY_NAME_SEED.as_bytes(), user.key().as_ref()],
+        bump = primary_name.bump,
+    )]
+    primary_name: Box<Account<'info, PrimaryName>>,
+    #[account(mut)]
+    user: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<ClosePrimaryNameCtx>) -> Result<()> {
+    Ok(())
+}
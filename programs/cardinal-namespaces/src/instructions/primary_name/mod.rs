@@ -0,0 +1,3 @@
+pub mod close_primary_name;
+pub mod resolve_primary_name;
+pub mod set_primary_name;
@@ -0,0 +1,59 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::{prelude::*, solana_program::program::set_return_data},
+};
+
+/// Resolved primary name, returned as borsh encoded return data
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PrimaryNameResolution {
+    pub wallet: Pubkey,
+    pub name_entry: Pubkey,
+    pub namespace_name: String,
+    pub entry_name: String,
+}
+
+#[derive(Accounts)]
+pub struct ResolvePrimaryNameCtx<'info> {
+    #[account(
+        seeds = [PRIMARY_NAME_SEED.as_bytes(), primary_name.wallet.as_ref()],
+        bump = primary_name.bump,
+    )]
+    primary_name: Box<Account<'info, PrimaryName>>,
+    #[account(address = primary_name.namespace @ ErrorCode::InvalidNamespace)]
+    namespace: Box<Account<'info, Namespace>>,
+    // the entry must still point at the wallet and its reverse entry, a sold or invalidated entry no longer resolves
+    #[account(
+        address = primary_name.name_entry @ ErrorCode::InvalidEntry,
+        constraint = name_entry.data == Some(primary_name.wallet) @ ErrorCode::InvalidEntry,
+        constraint = name_entry.reverse_entry == Some(reverse_entry.key()) @ ErrorCode::InvalidReverseEntryForNameEntry,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        address = primary_name.reverse_entry @ ErrorCode::InvalidReverseEntry,
+        constraint = reverse_entry.entry_name == name_entry.name @ ErrorCode::InvalidReverseEntry,
+    )]
+    reverse_entry: Box<Account<'info, ReverseEntry>>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    time_invalidator: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ResolvePrimaryNameCtx>) -> Result<()> {
+    assert_name_entry_live(
+        &ctx.accounts.namespace.key(),
+        &ctx.accounts.name_entry,
+        &ctx.accounts.token_manager,
+        &ctx.accounts.time_invalidator,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let resolution = PrimaryNameResolution {
+        wallet: ctx.accounts.primary_name.wallet,
+        name_entry: ctx.accounts.name_entry.key(),
+        namespace_name: ctx.accounts.namespace.name.clone(),
+        entry_name: ctx.accounts.name_entry.name.clone(),
+    };
+    set_return_data(&resolution.try_to_vec()?);
+    Ok(())
+}
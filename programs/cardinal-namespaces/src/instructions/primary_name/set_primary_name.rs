@@ -0,0 +1,58 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SetPrimaryNameCtx<'info> {
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        constraint = name_entry.data == Some(user.key()) @ ErrorCode::InvalidEntry,
+        constraint = name_entry.reverse_entry == Some(reverse_entry.key()) @ ErrorCode::InvalidReverseEntryForNameEntry,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        constraint = reverse_entry.entry_name == name_entry.name @ ErrorCode::InvalidReverseEntry,
+        seeds = [REVERSE_ENTRY_SEED.as_bytes(), namespace.key().as_ref(), user.key().as_ref()],
+        bump = reverse_entry.bump,
+    )]
+    reverse_entry: Box<Account<'info, ReverseEntry>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PRIMARY_NAME_SIZE,
+        seeds = [PRIMARY_NAME_SEED.as_bytes(), user.key().as_ref()],
+        bump,
+    )]
+    primary_name: Box<Account<'info, PrimaryName>>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    time_invalidator: UncheckedAccount<'info>,
+
+    user: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetPrimaryNameCtx>) -> Result<()> {
+    assert_name_entry_live(
+        &ctx.accounts.namespace.key(),
+        &ctx.accounts.name_entry,
+        &ctx.accounts.token_manager,
+        &ctx.accounts.time_invalidator,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let primary_name = &mut ctx.accounts.primary_name;
+    primary_name.bump = *ctx.bumps.get("primary_name").unwrap();
+    primary_name.wallet = ctx.accounts.user.key();
+    primary_name.namespace = ctx.accounts.namespace.key();
+    primary_name.name_entry = ctx.accounts.name_entry.key();
+    primary_name.reverse_entry = ctx.accounts.reverse_entry.key();
+    Ok(())
+}
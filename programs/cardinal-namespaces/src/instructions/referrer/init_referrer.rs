@@ -0,0 +1,25 @@
+use {crate::state::*, anchor_lang::prelude::*};
+
+#[derive(Accounts)]
+pub struct InitReferrerCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = REFERRER_SIZE,
+        seeds = [REFERRER_SEED.as_bytes(), authority.key().as_ref()],
+        bump,
+    )]
+    referrer: Account<'info, Referrer>,
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitReferrerCtx>) -> Result<()> {
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.bump = *ctx.bumps.get("referrer").unwrap();
+    referrer.authority = ctx.accounts.authority.key();
+    referrer.referral_count = 0;
+    Ok(())
+}
@@ -1,10 +1,10 @@
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
 };
 
 #[derive(Accounts)]
-#[instruction(entry_name: String, user: Pubkey)]
+#[instruction(entry_name: String, user: Pubkey, expires_at: Option<i64>)]
 pub struct ApproveClaimRequestCtx<'info> {
     namespace: Account<'info, Namespace>,
     #[account(mut)]
@@ -12,12 +12,12 @@ pub struct ApproveClaimRequestCtx<'info> {
     #[account(
         init_if_needed,
         payer = payer,
-        space = CLAIM_REQUEST_SIZE,
-        seeds = [CLAIM_REQUEST_SEED.as_bytes(), namespace.key().as_ref(), entry_name.as_bytes(), user.as_ref()],
+        space = claim_request_size(&entry_name),
+        seeds = [CLAIM_REQUEST_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&entry_name).as_ref(), user.as_ref()],
         bump,
     )]
     claim_request: Account<'info, ClaimRequest>,
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    /// CHECK: This is not dangerous because we check inside the handler
     #[account(mut)]
     name_entry: UncheckedAccount<'info>,
 
@@ -27,19 +27,19 @@ pub struct ApproveClaimRequestCtx<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<ApproveClaimRequestCtx>, entry_name: String, user: Pubkey) -> Result<()> {
-    let claim_request = &mut ctx.accounts.claim_request;
-    claim_request.bump = *ctx.bumps.get("claim_request").unwrap();
-    claim_request.namespace = ctx.accounts.namespace.key();
-    claim_request.entry_name = entry_name;
-    claim_request.is_approved = true;
-    claim_request.requestor = user;
-    claim_request.counter = 0;
-
-    if !ctx.accounts.name_entry.data_is_empty() {
-        let name_entry = Account::<Entry>::try_from(&ctx.accounts.name_entry)?;
-        claim_request.counter = name_entry.claim_request_counter;
-    }
-
+pub fn handler(ctx: Context<ApproveClaimRequestCtx>, entry_name: String, user: Pubkey, expires_at: Option<i64>) -> Result<()> {
+    assert_name(&ctx.accounts.namespace, &entry_name)?;
+    let counter = name_entry_claim_request_counter(ctx.program_id, &ctx.accounts.namespace.key(), &entry_name, &ctx.accounts.name_entry)?;
+    fill_approved_claim_request(
+        &mut ctx.accounts.claim_request,
+        *ctx.bumps.get("claim_request").unwrap(),
+        &ctx.accounts.namespace.key(),
+        entry_name,
+        user,
+        counter,
+        expires_at,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
     Ok(())
 }
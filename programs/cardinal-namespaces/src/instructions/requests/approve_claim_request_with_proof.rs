@@ -0,0 +1,60 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(entry_name: String, user: Pubkey)]
+pub struct ApproveClaimRequestWithProofCtx<'info> {
+    #[account(constraint = namespace.allowlist_root.is_some() @ ErrorCode::InvalidAllowlistProof)]
+    namespace: Account<'info, Namespace>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = claim_request_size(&entry_name),
+        seeds = [CLAIM_REQUEST_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&entry_name).as_ref(), user.as_ref()],
+        bump,
+    )]
+    claim_request: Account<'info, ClaimRequest>,
+    // fails if the leaf was already used
+    #[account(
+        init,
+        payer = payer,
+        space = ALLOWLIST_CLAIM_SIZE,
+        seeds = [ALLOWLIST_CLAIM_SEED.as_bytes(), namespace.key().as_ref(), allowlist_leaf(&entry_name, &user).as_ref()],
+        bump,
+    )]
+    allowlist_claim: Account<'info, AllowlistClaim>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    name_entry: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ApproveClaimRequestWithProofCtx>, entry_name: String, user: Pubkey, proof: Vec<[u8; 32]>) -> Result<()> {
+    assert_name(&ctx.accounts.namespace, &entry_name)?;
+    let leaf = allowlist_leaf(&entry_name, &user);
+    assert_merkle_proof(&proof, &ctx.accounts.namespace.allowlist_root.expect("No allowlist root"), leaf)?;
+
+    let allowlist_claim = &mut ctx.accounts.allowlist_claim;
+    allowlist_claim.bump = *ctx.bumps.get("allowlist_claim").unwrap();
+    allowlist_claim.namespace = ctx.accounts.namespace.key();
+    allowlist_claim.leaf = leaf;
+
+    let counter = name_entry_claim_request_counter(ctx.program_id, &ctx.accounts.namespace.key(), &entry_name, &ctx.accounts.name_entry)?;
+    // the allowlist does not expire approvals, keep any expiration already set on the request
+    let expires_at = ctx.accounts.claim_request.expires_at;
+    fill_approved_claim_request(
+        &mut ctx.accounts.claim_request,
+        *ctx.bumps.get("claim_request").unwrap(),
+        &ctx.accounts.namespace.key(),
+        entry_name,
+        user,
+        counter,
+        expires_at,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::{prelude::*, solana_program::sysvar},
+};
+
+/// Message signed by the namespace approve authority, serialized with borsh
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimApproval {
+    pub namespace: Pubkey,
+    pub entry_name: String,
+    pub requestor: Pubkey,
+    pub counter: u32,
+    pub expires_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(entry_name: String, user: Pubkey)]
+pub struct ApproveClaimRequestWithSignatureCtx<'info> {
+    namespace: Account<'info, Namespace>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = claim_request_size(&entry_name),
+        seeds = [CLAIM_REQUEST_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&entry_name).as_ref(), user.as_ref()],
+        bump,
+    )]
+    claim_request: Account<'info, ClaimRequest>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    name_entry: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check the address
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ApproveClaimRequestWithSignatureCtx>, entry_name: String, user: Pubkey, expires_at: i64) -> Result<()> {
+    if Clock::get()?.unix_timestamp >= expires_at {
+        return Err(error!(ErrorCode::ClaimRequestExpired));
+    }
+    assert_name(&ctx.accounts.namespace, &entry_name)?;
+    let approve_authority = ctx.accounts.namespace.approve_authority.ok_or_else(|| error!(ErrorCode::InvalidApproveAuthority))?;
+
+    let counter = name_entry_claim_request_counter(ctx.program_id, &ctx.accounts.namespace.key(), &entry_name, &ctx.accounts.name_entry)?;
+
+    let approval = ClaimApproval {
+        namespace: ctx.accounts.namespace.key(),
+        entry_name: entry_name.clone(),
+        requestor: user,
+        counter,
+        expires_at,
+    };
+    assert_ed25519_signature(&ctx.accounts.instructions, &approve_authority, &approval.try_to_vec()?)?;
+
+    fill_approved_claim_request(
+        &mut ctx.accounts.claim_request,
+        *ctx.bumps.get("claim_request").unwrap(),
+        &ctx.accounts.namespace.key(),
+        entry_name,
+        user,
+        counter,
+        Some(expires_at),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimRequestApproval {
+    pub entry_name: String,
+    pub user: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ApproveClaimRequestsIx {
+    pub approvals: Vec<ClaimRequestApproval>,
+    pub expires_at: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveClaimRequestsCtx<'info> {
+    namespace: Account<'info, Namespace>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(constraint = approve_authority.key() == namespace.approve_authority.unwrap() @ ErrorCode::InvalidApproveAuthority)]
+    approve_authority: Signer<'info>,
+    system_program: Program<'info, System>,
+    // remaining accounts are [claim_request, name_entry] for each approval
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ApproveClaimRequestsCtx<'info>>, ix: ApproveClaimRequestsIx) -> Result<()> {
+    let namespace_key = ctx.accounts.namespace.key();
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    for approval in ix.approvals {
+        assert_name(&ctx.accounts.namespace, &approval.entry_name)?;
+        let claim_request_info = next_account_info(remaining_accs)?;
+        let name_entry_info = next_account_info(remaining_accs)?;
+
+        let entry_name_seed = name_seed(&approval.entry_name);
+        let claim_request_seeds = &[CLAIM_REQUEST_SEED.as_bytes(), namespace_key.as_ref(), entry_name_seed.as_ref(), approval.user.as_ref()];
+        let (claim_request_key, claim_request_bump) = Pubkey::find_program_address(claim_request_seeds, ctx.program_id);
+        if claim_request_key != claim_request_info.key() {
+            return Err(error!(ErrorCode::InvalidClaimRequest));
+        }
+        let counter = name_entry_claim_request_counter(ctx.program_id, &namespace_key, &approval.entry_name, name_entry_info)?;
+
+        // create claim request if needed
+        let mut claim_request = if claim_request_info.data_is_empty() {
+            create_pda_account(
+                claim_request_info,
+                claim_request_size(&approval.entry_name),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &[
+                    CLAIM_REQUEST_SEED.as_bytes(),
+                    namespace_key.as_ref(),
+                    entry_name_seed.as_ref(),
+                    approval.user.as_ref(),
+                    &[claim_request_bump],
+                ],
+            )?;
+            Account::<ClaimRequest>::try_from_unchecked(claim_request_info)?
+        } else {
+            // legacy requests are too small to deserialize until they are grown
+            resize_account(
+                claim_request_info,
+                claim_request_size(&approval.entry_name),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+            Account::<ClaimRequest>::try_from(claim_request_info)?
+        };
+        fill_approved_claim_request(
+            &mut claim_request,
+            claim_request_bump,
+            &namespace_key,
+            approval.entry_name,
+            approval.user,
+            counter,
+            ix.expires_at,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        claim_request.exit(ctx.program_id)?;
+    }
+    Ok(())
+}
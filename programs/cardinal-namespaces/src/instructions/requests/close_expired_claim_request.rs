@@ -0,0 +1,17 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CloseExpiredClaimRequestCtx<'info> {
+    #[account(mut, close = payer, constraint = claim_request_expired(&claim_request) @ ErrorCode::ClaimRequestNotExpired)]
+    claim_request: Account<'info, ClaimRequest>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut, address = claim_request_payer(&claim_request) @ ErrorCode::InvalidPayer)]
+    payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<CloseExpiredClaimRequestCtx>) -> Result<()> {
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use {crate::state::*, anchor_lang::prelude::*};
+use {
+    crate::{state::*, utils::*},
+    anchor_lang::prelude::*,
+};
 
 #[derive(Accounts)]
 #[instruction(entry_name: String, claim_request_bump: u8, user: Pubkey)]
@@ -9,8 +12,8 @@ pub struct CreateClaimRequestCtx<'info> {
     #[account(
         init,
         payer = payer,
-        space = CLAIM_REQUEST_SIZE,
-        seeds = [CLAIM_REQUEST_SEED.as_bytes(), namespace.key().as_ref(), entry_name.as_bytes(), user.as_ref()],
+        space = claim_request_size(&entry_name),
+        seeds = [CLAIM_REQUEST_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&entry_name).as_ref(), user.as_ref()],
         bump,
     )]
     claim_request: Account<'info, ClaimRequest>,
@@ -18,11 +21,18 @@ pub struct CreateClaimRequestCtx<'info> {
 }
 
 pub fn handler(ctx: Context<CreateClaimRequestCtx>, entry_name: String, _claim_request_bump: u8, user: Pubkey) -> Result<()> {
+    assert_name(&ctx.accounts.namespace, &entry_name)?;
+    // remaining accounts are [gate_token_account, gate_metadata] for gated namespaces
+    if let Some(gate) = &ctx.accounts.namespace.gate {
+        assert_gate(gate, &user, ctx.remaining_accounts.first(), ctx.remaining_accounts.get(1))?;
+    }
     let claim_request = &mut ctx.accounts.claim_request;
     claim_request.bump = *ctx.bumps.get("claim_request").unwrap();
     claim_request.requestor = user;
     claim_request.namespace = ctx.accounts.namespace.key();
     claim_request.entry_name = entry_name;
+    claim_request.payer = ctx.accounts.payer.key();
+    claim_request.expires_at = None;
     if ctx.accounts.namespace.approve_authority.is_some() {
         claim_request.is_approved = false;
     } else {
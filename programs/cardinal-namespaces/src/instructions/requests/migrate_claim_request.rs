@@ -0,0 +1,57 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::{prelude::*, Discriminator},
+    std::io::Cursor,
+};
+
+// claim requests were allocated at a fixed size before the payer and expiration were stored
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyClaimRequest {
+    bump: u8,
+    requestor: Pubkey,
+    is_approved: bool,
+    namespace: Pubkey,
+    entry_name: String,
+    counter: u32,
+}
+
+#[derive(Accounts)]
+pub struct MigrateClaimRequestCtx<'info> {
+    /// CHECK: This is not dangerous because we check the discriminator inside the handler
+    #[account(mut, owner = crate::id() @ ErrorCode::InvalidClaimRequest)]
+    claim_request: UncheckedAccount<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+/// Grows a legacy claim request to the current layout so it can be deserialized, migrated requests keep the payer unset so rent returns to the requestor
+pub fn handler(ctx: Context<MigrateClaimRequestCtx>) -> Result<()> {
+    let claim_request_info = ctx.accounts.claim_request.to_account_info();
+    let legacy_claim_request = {
+        let data = claim_request_info.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != ClaimRequest::discriminator() {
+            return Err(error!(ErrorCode::InvalidClaimRequest));
+        }
+        LegacyClaimRequest::deserialize(&mut &data[8..]).map_err(|_| error!(ErrorCode::InvalidClaimRequest))?
+    };
+    let size = claim_request_size(&legacy_claim_request.entry_name);
+    if claim_request_info.data_len() >= size {
+        return Ok(());
+    }
+
+    resize_account(&claim_request_info, size, &ctx.accounts.payer.to_account_info(), &ctx.accounts.system_program.to_account_info())?;
+    let claim_request = ClaimRequest {
+        bump: legacy_claim_request.bump,
+        requestor: legacy_claim_request.requestor,
+        is_approved: legacy_claim_request.is_approved,
+        namespace: legacy_claim_request.namespace,
+        entry_name: legacy_claim_request.entry_name,
+        counter: legacy_claim_request.counter,
+        payer: Pubkey::default(),
+        expires_at: None,
+    };
+    let mut data = claim_request_info.try_borrow_mut_data()?;
+    claim_request.try_serialize(&mut Cursor::new(&mut data[..]))?;
+    Ok(())
+}
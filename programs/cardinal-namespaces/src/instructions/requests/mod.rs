@@ -1,3 +1,10 @@
 pub mod approve_claim_request;
+pub mod approve_claim_request_with_proof;
+pub mod approve_claim_request_with_signature;
+pub mod approve_claim_requests;
+pub mod close_expired_claim_request;
 pub mod create_claim_request;
+pub mod migrate_claim_request;
+pub mod reject_claim_request;
 pub mod update_claim_request;
+pub mod withdraw_claim_request;
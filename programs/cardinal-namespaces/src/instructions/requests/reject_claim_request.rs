@@ -0,0 +1,20 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct RejectClaimRequestCtx<'info> {
+    namespace: Account<'info, Namespace>,
+    #[account(constraint = namespace.approve_authority == Some(approve_authority.key()) @ ErrorCode::InvalidApproveAuthority)]
+    approve_authority: Signer<'info>,
+    #[account(mut, close = payer, constraint = claim_request.namespace == namespace.key() @ ErrorCode::InvalidNamespace)]
+    claim_request: Account<'info, ClaimRequest>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut, address = claim_request_payer(&claim_request) @ ErrorCode::InvalidPayer)]
+    payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<RejectClaimRequestCtx>) -> Result<()> {
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use {
-    crate::{errors::ErrorCode, instructions::assert_derivation, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
 };
 
@@ -22,7 +22,8 @@ pub fn handler(ctx: Context<UpdateClaimRequestCtx>, is_approved: bool) -> Result
     assert_derivation(
         &ctx.program_id,
         &ctx.accounts.name_entry.to_account_info(),
-        &[ENTRY_SEED.as_bytes(), ctx.accounts.namespace.key().as_ref(), rent_request.entry_name.as_bytes()],
+        &[ENTRY_SEED.as_bytes(), ctx.accounts.namespace.key().as_ref(), name_seed(&rent_request.entry_name).as_ref()],
+        ErrorCode::InvalidEntry,
     )?;
 
     if !ctx.accounts.name_entry.data_is_empty() {
@@ -0,0 +1,18 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawClaimRequestCtx<'info> {
+    #[account(mut, close = payer, constraint = claim_request.requestor == requestor.key() @ ErrorCode::InvalidRequestor)]
+    claim_request: Account<'info, ClaimRequest>,
+    requestor: Signer<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut, address = claim_request_payer(&claim_request) @ ErrorCode::InvalidPayer)]
+    payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<WithdrawClaimRequestCtx>) -> Result<()> {
+    Ok(())
+}
@@ -9,8 +9,9 @@ pub struct InvalidateExpiredReverseNameEntryCtx<'info> {
     pub namespace: Account<'info, Namespace>,
     #[account(
         mut,
-        constraint = name_entry.namespace == namespace.key()
-        @ ErrorCode::InvalidEntry
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidEntry,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
     )]
     pub name_entry: Account<'info, Entry>,
     #[account(
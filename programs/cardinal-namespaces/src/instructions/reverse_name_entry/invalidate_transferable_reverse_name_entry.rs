@@ -1,7 +1,5 @@
-use crate::instructions::assert_derivation;
-
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
     cardinal_token_manager::state::{TokenManager, TokenManagerState},
 };
@@ -11,8 +9,9 @@ pub struct InvalidateTransferableReverseNameEntryCtx<'info> {
     namespace: Account<'info, Namespace>,
     #[account(
         mut,
-        constraint = name_entry.namespace == namespace.key()
-        @ ErrorCode::InvalidEntry
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidEntry,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
     )]
     name_entry: Account<'info, Entry>,
     #[account(
@@ -37,6 +36,7 @@ pub fn handler(ctx: Context<InvalidateTransferableReverseNameEntryCtx>) -> Resul
         &cardinal_token_manager::id(),
         &ctx.accounts.token_manager.to_account_info(),
         &[cardinal_token_manager::state::TOKEN_MANAGER_SEED.as_bytes(), name_entry.mint.as_ref()],
+        ErrorCode::InvalidEntry,
     )?;
     if !ctx.accounts.token_manager.data_is_empty() {
         let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
@@ -1,12 +1,16 @@
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
 };
 
 #[derive(Accounts)]
 pub struct RevokeReverseNameEntryCtx<'info> {
     namespace: Box<Account<'info, Namespace>>,
-    #[account(constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidEntry)]
+    #[account(
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidEntry,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
     name_entry: Box<Account<'info, Entry>>,
     #[account(
         mut,
@@ -20,7 +24,8 @@ pub struct RevokeReverseNameEntryCtx<'info> {
         && claim_request.namespace == namespace.key()
         && claim_request.entry_name == name_entry.name
         && claim_request.counter == name_entry.claim_request_counter
-        @ ErrorCode::ClaimNotAllowed
+        @ ErrorCode::ClaimNotAllowed,
+        constraint = !claim_request_expired(&claim_request) @ ErrorCode::ClaimRequestExpired
     )]
     claim_request: Box<Account<'info, ClaimRequest>>,
     invalidator: Signer<'info>,
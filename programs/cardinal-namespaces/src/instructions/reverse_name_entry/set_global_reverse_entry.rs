@@ -1,7 +1,7 @@
 use anchor_spl::token::TokenAccount;
 use cardinal_token_manager::state::{TokenManager, TokenManagerState};
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
 };
 
@@ -10,14 +10,15 @@ pub struct SetGlobalReverseEntryCtx<'info> {
     namespace: Box<Account<'info, Namespace>>,
     #[account(
         mut,
-        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_entry.name.as_bytes()],
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
         bump = name_entry.bump,
     )]
     name_entry: Box<Account<'info, Entry>>,
     #[account(
         init_if_needed,
         payer = payer,
-        space = REVERSE_ENTRY_SIZE,
+        space = reverse_entry_size(&name_entry.name),
         seeds = [REVERSE_ENTRY_SEED.as_bytes(), user.key().as_ref()],
         bump,
     )]
@@ -42,6 +43,13 @@ pub struct SetGlobalReverseEntryCtx<'info> {
 }
 
 pub fn handler(ctx: Context<SetGlobalReverseEntryCtx>) -> Result<()> {
+    // reverse entries set before a long name was assigned need room for it
+    resize_account(
+        &ctx.accounts.reverse_name_entry.to_account_info(),
+        reverse_entry_size(&ctx.accounts.name_entry.name),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
     let namespace = &mut ctx.accounts.namespace;
     let entry = &mut ctx.accounts.name_entry;
     entry.reverse_entry = Some(ctx.accounts.reverse_name_entry.key());
@@ -1,6 +1,6 @@
 use anchor_spl::token::TokenAccount;
 use {
-    crate::{errors::ErrorCode, state::*},
+    crate::{errors::ErrorCode, state::*, utils::*},
     anchor_lang::prelude::*,
     cardinal_token_manager::state::{TokenManager, TokenManagerState},
 };
@@ -8,12 +8,17 @@ use {
 #[derive(Accounts)]
 pub struct SetNamespaceReverseNameEntryCtx<'info> {
     namespace: Box<Account<'info, Namespace>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
     name_entry: Box<Account<'info, Entry>>,
     #[account(
         init_if_needed,
         payer = payer,
-        space = REVERSE_ENTRY_SIZE,
+        space = reverse_entry_size(&name_entry.name),
         seeds = [REVERSE_ENTRY_SEED.as_bytes(), namespace.key().as_ref(), user.key().as_ref()],
         bump,
     )]
@@ -42,6 +47,13 @@ pub struct SetNamespaceReverseNameEntryCtx<'info> {
 }
 
 pub fn handler(ctx: Context<SetNamespaceReverseNameEntryCtx>) -> Result<()> {
+    // reverse entries set before a long name was assigned need room for it
+    resize_account(
+        &ctx.accounts.reverse_entry.to_account_info(),
+        reverse_entry_size(&ctx.accounts.name_entry.name),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
     let name_entry = &mut ctx.accounts.name_entry;
     name_entry.reverse_entry = Some(ctx.accounts.reverse_entry.key());
 
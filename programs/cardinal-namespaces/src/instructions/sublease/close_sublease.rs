@@ -0,0 +1,49 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct CloseSubleaseCtx<'info> {
+    #[account(
+        mut,
+        close = holder,
+        constraint = sublease.holder == holder.key() @ ErrorCode::InvalidSublease,
+        constraint = sublease.lessee.is_none() @ ErrorCode::SubleaseActive,
+        seeds = [SUBLEASE_SEED.as_bytes(), sublease.mint.as_ref()],
+        bump = sublease.bump,
+    )]
+    sublease: Box<Account<'info, Sublease>>,
+    #[account(mut, constraint = sublease_token_account.mint == sublease.mint && sublease_token_account.owner == sublease.key() @ ErrorCode::InvalidSublease)]
+    sublease_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    holder: Signer<'info>,
+    #[account(mut, constraint = holder_token_account.mint == sublease.mint && holder_token_account.owner == holder.key() @ ErrorCode::InvalidOwnerMint)]
+    holder_token_account: Box<Account<'info, TokenAccount>>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CloseSubleaseCtx>) -> Result<()> {
+    let sublease_seeds = &[SUBLEASE_SEED.as_bytes(), ctx.accounts.sublease.mint.as_ref(), &[ctx.accounts.sublease.bump]];
+    let sublease_signer = &[&sublease_seeds[..]];
+
+    // return the name
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.sublease_token_account.to_account_info(),
+        to: ctx.accounts.holder_token_account.to_account_info(),
+        authority: ctx.accounts.sublease.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(sublease_signer);
+    token::transfer(cpi_context, ctx.accounts.sublease_token_account.amount)?;
+
+    // close escrow
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.sublease_token_account.to_account_info(),
+        destination: ctx.accounts.holder.to_account_info(),
+        authority: ctx.accounts.sublease.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(sublease_signer);
+    token::close_account(cpi_context)?;
+    Ok(())
+}
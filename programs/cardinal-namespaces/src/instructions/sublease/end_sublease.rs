@@ -0,0 +1,42 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct EndSubleaseCtx<'info> {
+    #[account(
+        mut,
+        constraint = name_entry.key() == sublease.name_entry @ ErrorCode::InvalidSublease,
+        seeds = [ENTRY_SEED.as_bytes(), sublease.namespace.as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        mut,
+        constraint = sublease.lessee == Some(lessee.key()) @ ErrorCode::InvalidSublease,
+        seeds = [SUBLEASE_SEED.as_bytes(), sublease.mint.as_ref()],
+        bump = sublease.bump,
+    )]
+    sublease: Box<Account<'info, Sublease>>,
+    /// CHECK: This is not dangerous because we check it is the sublease lessee
+    #[account(mut)]
+    lessee: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler, pass the namespaces program id when the entry has no reverse entry
+    reverse_entry: UncheckedAccount<'info>,
+
+    // the lessee can end early, anyone can revert an expired lease
+    #[account(constraint = user.key() == lessee.key() || !sublease_active(&sublease) @ ErrorCode::SubleaseNotExpired)]
+    user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<EndSubleaseCtx>) -> Result<()> {
+    let sublease = &mut ctx.accounts.sublease;
+    sublease.lessee = None;
+    sublease.lease_expires_at = None;
+
+    // the lessee's reverse entry is released and the entry reverts to the holder
+    let holder = sublease.holder;
+    reassign_name_entry(&mut ctx.accounts.name_entry, &ctx.accounts.reverse_entry, &ctx.accounts.lessee, &holder)?;
+    Ok(())
+}
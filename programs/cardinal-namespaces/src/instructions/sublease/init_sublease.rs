@@ -0,0 +1,94 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{self, Mint, Token, TokenAccount, Transfer},
+    },
+    cardinal_token_manager::state::{TokenManager, TokenManagerState},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitSubleaseIx {
+    pub price: u64,
+    pub duration: i64,
+}
+
+#[derive(Accounts)]
+pub struct InitSubleaseCtx<'info> {
+    #[account(constraint = namespace.transferable_entries @ ErrorCode::EntriesNotTransferable)]
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        constraint = name_entry.is_claimed && name_entry.mint == mint.key() @ ErrorCode::InvalidEntryMint,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        init,
+        payer = holder,
+        space = SUBLEASE_SIZE,
+        seeds = [SUBLEASE_SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+    )]
+    sublease: Box<Account<'info, Sublease>>,
+    #[account(
+        init,
+        payer = holder,
+        associated_token::mint = mint,
+        associated_token::authority = sublease,
+    )]
+    sublease_token_account: Box<Account<'info, TokenAccount>>,
+    mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, constraint =
+        holder_token_account.mint == name_entry.mint
+        && holder_token_account.owner == holder.key()
+        && holder_token_account.amount > 0
+        @ ErrorCode::InvalidOwnerMint
+    )]
+    holder_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(constraint =
+        token_manager.mint == name_entry.mint
+        && token_manager.issuer == namespace.key()
+        && token_manager.state != TokenManagerState::Invalidated as u8
+        @ ErrorCode::InvalidTokenManager
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+    #[account(mut)]
+    holder: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    rent: Sysvar<'info, Rent>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitSubleaseCtx>, ix: InitSubleaseIx) -> Result<()> {
+    if ix.duration <= 0 {
+        return Err(error!(ErrorCode::InvalidSublease));
+    }
+
+    let sublease = &mut ctx.accounts.sublease;
+    sublease.bump = *ctx.bumps.get("sublease").unwrap();
+    sublease.namespace = ctx.accounts.namespace.key();
+    sublease.name_entry = ctx.accounts.name_entry.key();
+    sublease.mint = ctx.accounts.mint.key();
+    sublease.holder = ctx.accounts.holder.key();
+    sublease.payment_mint = ctx.accounts.namespace.payment_mint;
+    sublease.price = ix.price;
+    sublease.duration = ix.duration;
+    sublease.lessee = None;
+    sublease.lease_expires_at = None;
+
+    // escrow the name, the token manager and its namespace rental stay with the holder's token
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.holder_token_account.to_account_info(),
+        to: ctx.accounts.sublease_token_account.to_account_info(),
+        authority: ctx.accounts.holder.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_context, ctx.accounts.holder_token_account.amount)?;
+    Ok(())
+}
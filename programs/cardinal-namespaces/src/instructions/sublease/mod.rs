@@ -0,0 +1,5 @@
+pub mod close_sublease;
+pub mod end_sublease;
+pub mod init_sublease;
+pub mod rent_sublease;
+pub mod set_sublease_reverse_name_entry;
@@ -0,0 +1,68 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token::{self, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct RentSubleaseCtx<'info> {
+    #[account(
+        mut,
+        constraint = name_entry.key() == sublease.name_entry @ ErrorCode::InvalidSublease,
+        seeds = [ENTRY_SEED.as_bytes(), sublease.namespace.as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        mut,
+        constraint = sublease.lessee.is_none() @ ErrorCode::SubleaseActive,
+        seeds = [SUBLEASE_SEED.as_bytes(), sublease.mint.as_ref()],
+        bump = sublease.bump,
+    )]
+    sublease: Box<Account<'info, Sublease>>,
+    /// CHECK: This is not dangerous because we check the address
+    #[account(mut, address = sublease.holder @ ErrorCode::InvalidSublease)]
+    holder: UncheckedAccount<'info>,
+    #[account(mut, constraint = holder_payment_token_account.mint == sublease.payment_mint && holder_payment_token_account.owner == sublease.holder @ ErrorCode::InvalidPaymentMint)]
+    holder_payment_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because we check inside the handler, pass the namespaces program id when the entry has no reverse entry
+    reverse_entry: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we check inside the handler
+    time_invalidator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    lessee: Signer<'info>,
+    #[account(mut, constraint = lessee_payment_token_account.mint == sublease.payment_mint && lessee_payment_token_account.owner == lessee.key() @ ErrorCode::InvalidPayerTokenAccount)]
+    lessee_payment_token_account: Box<Account<'info, TokenAccount>>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RentSubleaseCtx>) -> Result<()> {
+    // the holder's rental has to outlast the lease
+    let lease_expires_at = Clock::get()?.unix_timestamp.checked_add(ctx.accounts.sublease.duration).expect("Add error");
+    assert_name_entry_live(
+        &ctx.accounts.sublease.namespace,
+        &ctx.accounts.name_entry,
+        &ctx.accounts.token_manager,
+        &ctx.accounts.time_invalidator,
+        lease_expires_at,
+    )?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.lessee_payment_token_account.to_account_info(),
+        to: ctx.accounts.holder_payment_token_account.to_account_info(),
+        authority: ctx.accounts.lessee.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_context, ctx.accounts.sublease.price)?;
+
+    let sublease = &mut ctx.accounts.sublease;
+    sublease.lessee = Some(ctx.accounts.lessee.key());
+    sublease.lease_expires_at = Some(lease_expires_at);
+
+    // the lessee takes over the entry data and the holder's reverse entry is released
+    reassign_name_entry(&mut ctx.accounts.name_entry, &ctx.accounts.reverse_entry, &ctx.accounts.holder, &ctx.accounts.lessee.key())?;
+    Ok(())
+}
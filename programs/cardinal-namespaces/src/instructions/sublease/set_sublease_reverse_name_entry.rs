@@ -0,0 +1,55 @@
+use {
+    crate::{errors::ErrorCode, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SetSubleaseReverseNameEntryCtx<'info> {
+    namespace: Box<Account<'info, Namespace>>,
+    #[account(
+        mut,
+        constraint = name_entry.namespace == namespace.key() @ ErrorCode::InvalidNamespace,
+        seeds = [ENTRY_SEED.as_bytes(), namespace.key().as_ref(), name_seed(&name_entry.name).as_ref()],
+        bump = name_entry.bump,
+    )]
+    name_entry: Box<Account<'info, Entry>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = reverse_entry_size(&name_entry.name),
+        seeds = [REVERSE_ENTRY_SEED.as_bytes(), namespace.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    reverse_entry: Box<Account<'info, ReverseEntry>>,
+    #[account(
+        constraint = sublease.name_entry == name_entry.key() && sublease.lessee == Some(user.key()) @ ErrorCode::InvalidSublease,
+        constraint = sublease_active(&sublease) @ ErrorCode::InvalidSublease,
+        seeds = [SUBLEASE_SEED.as_bytes(), sublease.mint.as_ref()],
+        bump = sublease.bump,
+    )]
+    sublease: Box<Account<'info, Sublease>>,
+
+    #[account(mut)]
+    user: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetSubleaseReverseNameEntryCtx>) -> Result<()> {
+    // reverse entries set before a long name was assigned need room for it
+    resize_account(
+        &ctx.accounts.reverse_entry.to_account_info(),
+        reverse_entry_size(&ctx.accounts.name_entry.name),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    let name_entry = &mut ctx.accounts.name_entry;
+    name_entry.reverse_entry = Some(ctx.accounts.reverse_entry.key());
+
+    let reverse_entry = &mut ctx.accounts.reverse_entry;
+    reverse_entry.bump = *ctx.bumps.get("reverse_entry").unwrap();
+    reverse_entry.entry_name = name_entry.name.clone();
+    reverse_entry.namespace_name = ctx.accounts.namespace.name.clone();
+    Ok(())
+}
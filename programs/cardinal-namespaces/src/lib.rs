@@ -1,6 +1,7 @@
 pub mod errors;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 use instructions::*;
@@ -20,16 +21,43 @@ pub mod namespaces {
         global_context::init_global_context::handler(ctx, ix)
     }
 
+    pub fn set_namespace_fee_override(ctx: Context<SetNamespaceFeeOverrideCtx>, fee_basis_points: u64) -> Result<()> {
+        global_context::set_namespace_fee_override::handler(ctx, fee_basis_points)
+    }
+
+    pub fn remove_namespace_fee_override(ctx: Context<RemoveNamespaceFeeOverrideCtx>) -> Result<()> {
+        global_context::remove_namespace_fee_override::handler(ctx)
+    }
+
     pub fn update_global_context(ctx: Context<UpdateGlobalContextCtx>, ix: UpdateGlobalContextIx) -> Result<()> {
         global_context::update_global_context::handler(ctx, ix)
     }
 
+    // listing
+    pub fn list_name_entry(ctx: Context<ListNameEntryCtx>, price: u64) -> Result<()> {
+        listing::list_name_entry::handler(ctx, price)
+    }
+
+    pub fn delist_name_entry(ctx: Context<DelistNameEntryCtx>) -> Result<()> {
+        listing::delist_name_entry::handler(ctx)
+    }
+
+    pub fn buy_name_entry<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, BuyNameEntryCtx<'info>>) -> Result<()> {
+        listing::buy_name_entry::handler(ctx)
+    }
+
     // name entry
+    // v1 claims stay for existing integrations, referrals, gates and wallet limits are only supported by v2
+    #[deprecated]
     pub fn claim_name_entry<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ClaimNameEntryCtx<'info>>, ix: ClaimNameEntryIx) -> Result<()> {
         name_entry::claim_name_entry::handler(ctx, ix)
     }
 
-    pub fn init_name_entry_mint(ctx: Context<InitNameEntryMintCtx>) -> Result<()> {
+    pub fn claim_name_entry_v2<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ClaimNameEntryV2Ctx<'info>>, ix: ClaimNameEntryIx) -> Result<()> {
+        name_entry::claim_name_entry_v2::handler(ctx, ix)
+    }
+
+    pub fn init_name_entry_mint<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InitNameEntryMintCtx<'info>>) -> Result<()> {
         name_entry::init_name_entry_mint::handler(ctx)
     }
 
@@ -41,7 +69,7 @@ pub mod namespaces {
         name_entry::invalidate_expired_name_entry::handler(ctx)
     }
 
-    pub fn invalidate_transferable_name_entry(ctx: Context<InvalidateTransferableNameEntryCtx>) -> Result<()> {
+    pub fn invalidate_transferable_name_entry<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateTransferableNameEntryCtx<'info>>) -> Result<()> {
         name_entry::invalidate_transferable_name_entry::handler(ctx)
     }
 
@@ -53,10 +81,17 @@ pub mod namespaces {
         name_entry::set_name_entry_data::handler(ctx)
     }
 
-    pub fn update_name_entry_mint_metadata(ctx: Context<UpdateNameEntryMintMetadataCtx>, ix: UpdateNameEntryMintMetadataIx) -> Result<()> {
+    pub fn update_name_entry_mint_metadata<'key, 'accounts, 'remaining, 'info>(
+        ctx: Context<'key, 'accounts, 'remaining, 'info, UpdateNameEntryMintMetadataCtx<'info>>,
+        ix: UpdateNameEntryMintMetadataIx,
+    ) -> Result<()> {
         name_entry::update_name_entry_mint_metadata::handler(ctx, ix)
     }
 
+    pub fn sync_name_entry_mint_metadata(ctx: Context<SyncNameEntryMintMetadataCtx>) -> Result<()> {
+        name_entry::sync_name_entry_mint_metadata::handler(ctx)
+    }
+
     pub fn close_name_entry(ctx: Context<CloseNameEntryCtx>) -> Result<()> {
         name_entry::close_name_entry::handler(ctx)
     }
@@ -67,17 +102,64 @@ pub mod namespaces {
 
     // namespace
     pub fn collect_namespace_funds(ctx: Context<CollectNamespaceFundsCtx>, amount: u64) -> Result<()> {
-        namespace::collect_namespace_funds::handler(ctx, amount)
+        namespace::collect_namespace_funds::handler(ctx, Some(amount))
+    }
+
+    pub fn collect_all_namespace_funds(ctx: Context<CollectNamespaceFundsCtx>) -> Result<()> {
+        namespace::collect_namespace_funds::handler(ctx, None)
     }
 
     pub fn create_namespace(ctx: Context<CreateNamespace>, ix: CreateNamespaceIx) -> Result<()> {
         namespace::create_namespace::handler(ctx, ix)
     }
 
+    pub fn distribute_namespace_funds<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, DistributeNamespaceFundsCtx<'info>>) -> Result<()> {
+        namespace::distribute_namespace_funds::handler(ctx)
+    }
+
+    pub fn init_namespace_collection(ctx: Context<InitNamespaceCollectionCtx>) -> Result<()> {
+        namespace::init_namespace_collection::handler(ctx)
+    }
+
+    pub fn set_emoji_allowlist(ctx: Context<SetEmojiAllowlistCtx>, sequences: Vec<String>) -> Result<()> {
+        namespace::set_emoji_allowlist::handler(ctx, sequences)
+    }
+
     pub fn update_namespace(ctx: Context<UpdateNamepsace>, ix: UpdateNamespaceIx) -> Result<()> {
         namespace::update_namespace::handler(ctx, ix)
     }
 
+    // offer
+    pub fn make_name_entry_offer(ctx: Context<MakeNameEntryOfferCtx>, ix: MakeNameEntryOfferIx) -> Result<()> {
+        offer::make_name_entry_offer::handler(ctx, ix)
+    }
+
+    pub fn accept_name_entry_offer<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, AcceptNameEntryOfferCtx<'info>>) -> Result<()> {
+        offer::accept_name_entry_offer::handler(ctx)
+    }
+
+    pub fn cancel_name_entry_offer(ctx: Context<CancelNameEntryOfferCtx>) -> Result<()> {
+        offer::cancel_name_entry_offer::handler(ctx)
+    }
+
+    // primary name
+    pub fn set_primary_name(ctx: Context<SetPrimaryNameCtx>) -> Result<()> {
+        primary_name::set_primary_name::handler(ctx)
+    }
+
+    pub fn close_primary_name(ctx: Context<ClosePrimaryNameCtx>) -> Result<()> {
+        primary_name::close_primary_name::handler(ctx)
+    }
+
+    pub fn resolve_primary_name(ctx: Context<ResolvePrimaryNameCtx>) -> Result<()> {
+        primary_name::resolve_primary_name::handler(ctx)
+    }
+
+    // referrer
+    pub fn init_referrer(ctx: Context<InitReferrerCtx>) -> Result<()> {
+        referrer::init_referrer::handler(ctx)
+    }
+
     // requests
     pub fn create_claim_request(ctx: Context<CreateClaimRequestCtx>, entry_name: String, claim_request_bump: u8, user: Pubkey) -> Result<()> {
         requests::create_claim_request::handler(ctx, entry_name, claim_request_bump, user)
@@ -87,8 +169,36 @@ pub mod namespaces {
         requests::update_claim_request::handler(ctx, is_approved)
     }
 
-    pub fn approve_claim_request(ctx: Context<ApproveClaimRequestCtx>, entry_name: String, user: Pubkey) -> Result<()> {
-        requests::approve_claim_request::handler(ctx, entry_name, user)
+    pub fn approve_claim_request(ctx: Context<ApproveClaimRequestCtx>, entry_name: String, user: Pubkey, expires_at: Option<i64>) -> Result<()> {
+        requests::approve_claim_request::handler(ctx, entry_name, user, expires_at)
+    }
+
+    pub fn approve_claim_request_with_proof(ctx: Context<ApproveClaimRequestWithProofCtx>, entry_name: String, user: Pubkey, proof: Vec<[u8; 32]>) -> Result<()> {
+        requests::approve_claim_request_with_proof::handler(ctx, entry_name, user, proof)
+    }
+
+    pub fn approve_claim_request_with_signature(ctx: Context<ApproveClaimRequestWithSignatureCtx>, entry_name: String, user: Pubkey, expires_at: i64) -> Result<()> {
+        requests::approve_claim_request_with_signature::handler(ctx, entry_name, user, expires_at)
+    }
+
+    pub fn approve_claim_requests<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ApproveClaimRequestsCtx<'info>>, ix: ApproveClaimRequestsIx) -> Result<()> {
+        requests::approve_claim_requests::handler(ctx, ix)
+    }
+
+    pub fn migrate_claim_request(ctx: Context<MigrateClaimRequestCtx>) -> Result<()> {
+        requests::migrate_claim_request::handler(ctx)
+    }
+
+    pub fn reject_claim_request(ctx: Context<RejectClaimRequestCtx>) -> Result<()> {
+        requests::reject_claim_request::handler(ctx)
+    }
+
+    pub fn withdraw_claim_request(ctx: Context<WithdrawClaimRequestCtx>) -> Result<()> {
+        requests::withdraw_claim_request::handler(ctx)
+    }
+
+    pub fn close_expired_claim_request(ctx: Context<CloseExpiredClaimRequestCtx>) -> Result<()> {
+        requests::close_expired_claim_request::handler(ctx)
     }
 
     // reverse name entry
@@ -112,6 +222,27 @@ pub mod namespaces {
         reverse_name_entry::set_global_reverse_entry::handler(ctx)
     }
 
+    // sublease
+    pub fn init_sublease(ctx: Context<InitSubleaseCtx>, ix: InitSubleaseIx) -> Result<()> {
+        sublease::init_sublease::handler(ctx, ix)
+    }
+
+    pub fn rent_sublease(ctx: Context<RentSubleaseCtx>) -> Result<()> {
+        sublease::rent_sublease::handler(ctx)
+    }
+
+    pub fn set_sublease_reverse_name_entry(ctx: Context<SetSubleaseReverseNameEntryCtx>) -> Result<()> {
+        sublease::set_sublease_reverse_name_entry::handler(ctx)
+    }
+
+    pub fn end_sublease(ctx: Context<EndSubleaseCtx>) -> Result<()> {
+        sublease::end_sublease::handler(ctx)
+    }
+
+    pub fn close_sublease(ctx: Context<CloseSubleaseCtx>) -> Result<()> {
+        sublease::close_sublease::handler(ctx)
+    }
+
     // deprecated
     #[deprecated]
     pub fn init_entry(ctx: Context<InitEntry>, ix: InitEntryIx) -> Result<()> {
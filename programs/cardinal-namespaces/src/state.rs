@@ -5,6 +5,36 @@ pub const NAMESPACE_PREFIX: &str = "namespace";
 pub const ENTRY_SEED: &str = "entry";
 pub const REVERSE_ENTRY_SEED: &str = "reverse-entry";
 pub const CLAIM_REQUEST_SEED: &str = "rent-request";
+pub const REFERRER_SEED: &str = "referrer";
+pub const FEE_OVERRIDE_SEED: &str = "fee-override";
+pub const ALLOWLIST_CLAIM_SEED: &str = "allowlist-claim";
+pub const WALLET_ENTRY_COUNTER_SEED: &str = "wallet-entry-counter";
+pub const LISTING_SEED: &str = "listing";
+pub const OFFER_SEED: &str = "offer";
+pub const SUBLEASE_SEED: &str = "sublease";
+pub const EMOJI_ALLOWLIST_SEED: &str = "emoji-allowlist";
+pub const PRIMARY_NAME_SEED: &str = "primary-name";
+// payment manager that collects entry rental payments
+pub const DEFAULT_PAYMENT_MANAGER_NAME: &str = "cardinal";
+
+// names up to the max seed length are seeded on their bytes, longer names are seeded on their prefixed sha256 hash
+// so a hash can never collide with a short name made of the same 32 bytes
+pub const MAX_NAME_SEED_LENGTH: usize = 32;
+pub const LONG_NAME_SEED_PREFIX: &str = "long:";
+pub const MAX_ENTRY_NAME_LENGTH: usize = 128;
+
+pub fn name_seed(name: &str) -> Vec<u8> {
+    if name.len() <= MAX_NAME_SEED_LENGTH {
+        name.as_bytes().to_vec()
+    } else {
+        anchor_lang::solana_program::hash::hashv(&[LONG_NAME_SEED_PREFIX.as_bytes(), name.as_bytes()]).to_bytes().to_vec()
+    }
+}
+
+// sizes below fit names up to the max seed length, longer names extend the account
+fn long_name_size(name: &str) -> usize {
+    name.len().saturating_sub(MAX_NAME_SEED_LENGTH)
+}
 
 pub const GLOBAL_CONTEXT_SIZE: usize = 8 + std::mem::size_of::<GlobalContext>() + 24;
 pub const BASIS_POINTS_DIVISOR: u16 = 10000;
@@ -16,7 +46,82 @@ pub struct GlobalContext {
     pub fee_basis_points: u64,
 }
 
-pub const NAMESPACE_SIZE: usize = 8 + std::mem::size_of::<Namespace>() + 52;
+pub const NAMESPACE_FEE_OVERRIDE_SIZE: usize = 8 + std::mem::size_of::<NamespaceFeeOverride>() + 8;
+#[account]
+pub struct NamespaceFeeOverride {
+    pub bump: u8,
+    pub namespace: Pubkey,
+    pub fee_basis_points: u64,
+}
+
+pub const DEFAULT_METADATA_URI_TEMPLATE: &str = "https://nft.cardinal.so/metadata/{mint}?name={name}";
+pub const DEFAULT_METADATA_SYMBOL: &str = "NAME";
+pub const DEFAULT_METADATA_NAME_FORMAT: &str = "{name}.{namespace}";
+pub const MAX_METADATA_URI_TEMPLATE_LENGTH: usize = 128;
+pub const MAX_METADATA_SYMBOL_LENGTH: usize = 10;
+pub const MAX_METADATA_NAME_FORMAT_LENGTH: usize = 32;
+
+// metaplex allows 5 creators and the namespace is always the first
+pub const MAX_NAMESPACE_CREATORS: usize = 4;
+pub const CREATOR_SIZE: usize = 32 + 1 + 1;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+pub const MAX_NAMESPACE_BENEFICIARIES: usize = 5;
+pub const BENEFICIARY_SIZE: usize = 32 + 2;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Beneficiary {
+    pub address: Pubkey,
+    pub basis_points: u16,
+}
+
+pub const MAX_PAYMENT_TIERS: usize = 5;
+pub const PAYMENT_TIER_SIZE: usize = 4 + 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PaymentTier {
+    // names up to this many grapheme clusters pay this tier
+    pub max_name_length: u32,
+    pub payment_amount_daily: u64,
+}
+
+pub enum GateType {
+    Collection = 1,
+    Mint = 2,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Gate {
+    pub gate_type: u8,
+    // collection mint for collection gates, token mint for mint gates
+    pub mint: Pubkey,
+    pub min_amount: u64,
+}
+
+pub enum NamePolicy {
+    // names are seeded on their raw bytes
+    Unrestricted = 0,
+    // names must be lowercase, precomposed and free of invisible or compatibility characters
+    Canonical = 1,
+    // canonical names that also do not mix latin, greek and cyrillic or spell a latin lookalike in another script
+    Strict = 2,
+}
+
+pub const NAMESPACE_SIZE: usize = 8
+    + std::mem::size_of::<Namespace>()
+    + 52
+    + MAX_METADATA_URI_TEMPLATE_LENGTH
+    + MAX_METADATA_SYMBOL_LENGTH
+    + MAX_METADATA_NAME_FORMAT_LENGTH
+    + MAX_NAMESPACE_CREATORS * CREATOR_SIZE
+    + MAX_NAMESPACE_BENEFICIARIES * BENEFICIARY_SIZE
+    + MAX_PAYMENT_TIERS * PAYMENT_TIER_SIZE;
 #[account]
 pub struct Namespace {
     pub bump: u8,
@@ -36,9 +141,39 @@ pub struct Namespace {
     pub limit: Option<u32>,
     pub max_expiration: Option<i64>,
     pub invalidation_type: u8,
+    // metadata
+    pub collection_mint: Option<Pubkey>,
+    pub metadata_uri_template: Option<String>,
+    pub metadata_symbol: Option<String>,
+    pub metadata_name_format: Option<String>,
+    // royalties
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<Creator>>,
+    // referrals
+    pub referral_basis_points: u16,
+    // revenue splits
+    pub beneficiaries: Option<Vec<Beneficiary>>,
+    // protocol fee accounting
+    pub accrued_protocol_fees: u64,
+    pub accounted_balance: u64,
+    // allowlist
+    pub allowlist_root: Option<[u8; 32]>,
+    // token gate
+    pub gate: Option<Gate>,
+    // per wallet limit
+    pub max_entries_per_wallet: Option<u32>,
+    // name normalization
+    pub name_policy: u8,
+    // name length in grapheme clusters
+    pub min_name_length: Option<u32>,
+    pub max_name_length: Option<u32>,
+    pub payment_tiers: Option<Vec<PaymentTier>>,
 }
 
 pub const CLAIM_REQUEST_SIZE: usize = 8 + std::mem::size_of::<ClaimRequest>() + 24;
+pub fn claim_request_size(entry_name: &str) -> usize {
+    CLAIM_REQUEST_SIZE + long_name_size(entry_name)
+}
 #[account]
 pub struct ClaimRequest {
     pub bump: u8,
@@ -47,9 +182,14 @@ pub struct ClaimRequest {
     pub namespace: Pubkey,
     pub entry_name: String,
     pub counter: u32,
+    pub payer: Pubkey,
+    pub expires_at: Option<i64>,
 }
 
 pub const ENTRY_SIZE: usize = 8 + std::mem::size_of::<Entry>() + 24;
+pub fn entry_size(name: &str) -> usize {
+    ENTRY_SIZE + long_name_size(name)
+}
 #[account]
 pub struct Entry {
     pub bump: u8,
@@ -60,12 +200,103 @@ pub struct Entry {
     pub mint: Pubkey,
     pub is_claimed: bool,
     pub claim_request_counter: u32,
+    // wallet counted against the namespace per wallet limit
+    pub claimer: Option<Pubkey>,
 }
 
 pub const REVERSE_ENTRY_SIZE: usize = 8 + std::mem::size_of::<ReverseEntry>() + 24 + 24;
+pub fn reverse_entry_size(entry_name: &str) -> usize {
+    REVERSE_ENTRY_SIZE + long_name_size(entry_name)
+}
 #[account]
 pub struct ReverseEntry {
     pub bump: u8,
     pub entry_name: String,
     pub namespace_name: String,
 }
+
+pub const REFERRER_SIZE: usize = 8 + std::mem::size_of::<Referrer>() + 8;
+#[account]
+pub struct Referrer {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub referral_count: u64,
+}
+
+pub const ALLOWLIST_CLAIM_SIZE: usize = 8 + std::mem::size_of::<AllowlistClaim>() + 8;
+#[account]
+pub struct AllowlistClaim {
+    pub bump: u8,
+    pub namespace: Pubkey,
+    pub leaf: [u8; 32],
+}
+
+pub const WALLET_ENTRY_COUNTER_SIZE: usize = 8 + std::mem::size_of::<WalletEntryCounter>() + 8;
+#[account]
+pub struct WalletEntryCounter {
+    pub bump: u8,
+    pub namespace: Pubkey,
+    pub wallet: Pubkey,
+    pub count: u32,
+}
+
+pub const LISTING_SIZE: usize = 8 + std::mem::size_of::<Listing>() + 8;
+#[account]
+pub struct Listing {
+    pub bump: u8,
+    pub namespace: Pubkey,
+    pub name_entry: Pubkey,
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub payment_mint: Pubkey,
+    pub price: u64,
+}
+
+pub const OFFER_SIZE: usize = 8 + std::mem::size_of::<Offer>() + 8;
+#[account]
+pub struct Offer {
+    pub bump: u8,
+    pub namespace: Pubkey,
+    pub name_entry: Pubkey,
+    pub buyer: Pubkey,
+    pub payment_mint: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+}
+
+pub const SUBLEASE_SIZE: usize = 8 + std::mem::size_of::<Sublease>() + 8;
+#[account]
+pub struct Sublease {
+    pub bump: u8,
+    pub namespace: Pubkey,
+    pub name_entry: Pubkey,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub payment_mint: Pubkey,
+    pub price: u64,
+    pub duration: i64,
+    pub lessee: Option<Pubkey>,
+    pub lease_expires_at: Option<i64>,
+}
+
+pub const EMOJI_ALLOWLIST_SIZE: usize = 8 + std::mem::size_of::<EmojiAllowlist>() + 8;
+pub fn emoji_allowlist_size(sequences: &[String]) -> usize {
+    EMOJI_ALLOWLIST_SIZE + sequences.iter().map(|s| 4 + s.len()).sum::<usize>()
+}
+#[account]
+pub struct EmojiAllowlist {
+    pub bump: u8,
+    pub namespace: Pubkey,
+    // emoji sequences, each a single grapheme cluster
+    pub sequences: Vec<String>,
+}
+
+pub const PRIMARY_NAME_SIZE: usize = 8 + std::mem::size_of::<PrimaryName>() + 8;
+#[account]
+pub struct PrimaryName {
+    pub bump: u8,
+    pub wallet: Pubkey,
+    pub namespace: Pubkey,
+    pub name_entry: Pubkey,
+    pub reverse_entry: Pubkey,
+}
@@ -0,0 +1,919 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::{
+        prelude::*,
+        solana_program::{
+            ed25519_program, keccak,
+            program::{invoke, invoke_signed},
+            system_instruction,
+            sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+        },
+        AccountsClose,
+    },
+    anchor_spl::{
+        associated_token,
+        token::{self, Mint, TokenAccount, Transfer},
+    },
+    cardinal_payment_manager::state::{PaymentManager, PAYMENT_MANAGER_SEED},
+    cardinal_time_invalidator::state::{TimeInvalidator, TIME_INVALIDATOR_SEED},
+    cardinal_token_manager::state::{InvalidationType, TokenManager, TokenManagerKind, TokenManagerState, MINT_COUNTER_SEED, TOKEN_MANAGER_SEED},
+    mpl_token_metadata::state::{Creator as MCreator, Metadata, TokenMetadataAccount, MAX_NAME_LENGTH, MAX_URI_LENGTH},
+    spl_associated_token_account::get_associated_token_address,
+    unicode_normalization::is_nfkc,
+    unicode_segmentation::UnicodeSegmentation,
+    urlencoding::encode,
+};
+
+pub fn assert_derivation(program_id: &Pubkey, account: &AccountInfo, path: &[&[u8]], error: ErrorCode) -> Result<u8> {
+    let (key, bump) = Pubkey::find_program_address(path, program_id);
+    if key != *account.key {
+        return Err(error.into());
+    }
+    Ok(bump)
+}
+
+pub fn assert_token_manager(token_manager: &AccountInfo, mint: &Pubkey) -> Result<()> {
+    assert_derivation(
+        &cardinal_token_manager::id(),
+        token_manager,
+        &[TOKEN_MANAGER_SEED.as_bytes(), mint.as_ref()],
+        ErrorCode::InvalidTokenManager,
+    )?;
+    Ok(())
+}
+
+pub fn assert_mint_counter(mint_counter: &AccountInfo, mint: &Pubkey) -> Result<()> {
+    assert_derivation(
+        &cardinal_token_manager::id(),
+        mint_counter,
+        &[MINT_COUNTER_SEED.as_bytes(), mint.as_ref()],
+        ErrorCode::InvalidMintCounter,
+    )?;
+    Ok(())
+}
+
+pub fn assert_time_invalidator(time_invalidator: &AccountInfo, token_manager: &Pubkey) -> Result<()> {
+    assert_derivation(
+        &cardinal_time_invalidator::id(),
+        time_invalidator,
+        &[TIME_INVALIDATOR_SEED.as_bytes(), token_manager.as_ref()],
+        ErrorCode::InvalidTimeInvalidator,
+    )?;
+    Ok(())
+}
+
+pub fn assert_payment_manager<'info>(payment_manager_info: &AccountInfo<'info>) -> Result<Account<'info, PaymentManager>> {
+    assert_derivation(
+        &cardinal_payment_manager::id(),
+        payment_manager_info,
+        &[PAYMENT_MANAGER_SEED.as_bytes(), DEFAULT_PAYMENT_MANAGER_NAME.as_bytes()],
+        ErrorCode::InvalidPaymentManager,
+    )?;
+    Account::<PaymentManager>::try_from(payment_manager_info).map_err(|_| error!(ErrorCode::InvalidPaymentManager))
+}
+
+pub fn assert_token_account<'info>(token_account_info: &AccountInfo<'info>, mint: &Pubkey, owner: &Pubkey, error: ErrorCode) -> Result<Account<'info, TokenAccount>> {
+    let token_account = Account::<TokenAccount>::try_from(token_account_info).map_err(|_| -> Error { error.into() })?;
+    if token_account.mint != *mint || token_account.owner != *owner {
+        return Err(error.into());
+    }
+    Ok(token_account)
+}
+
+pub fn assert_namespace_collection(collection_mint: &Pubkey, collection_mint_info: &AccountInfo, collection_metadata: &AccountInfo, collection_master_edition: &AccountInfo) -> Result<()> {
+    if collection_mint_info.key() != *collection_mint {
+        return Err(error!(ErrorCode::InvalidNamespaceCollection));
+    }
+    assert_derivation(
+        &mpl_token_metadata::id(),
+        collection_metadata,
+        &[mpl_token_metadata::state::PREFIX.as_bytes(), mpl_token_metadata::id().as_ref(), collection_mint.as_ref()],
+        ErrorCode::InvalidNamespaceCollection,
+    )?;
+    assert_derivation(
+        &mpl_token_metadata::id(),
+        collection_master_edition,
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            collection_mint.as_ref(),
+            mpl_token_metadata::state::EDITION.as_bytes(),
+        ],
+        ErrorCode::InvalidNamespaceCollection,
+    )?;
+    Ok(())
+}
+
+/// Metadata uri for a mint using the namespace uri template, `{mint}` and `{name}` are substituted
+pub fn metadata_uri(namespace: &Namespace, mint: &Pubkey, name: &str) -> String {
+    let template = namespace.metadata_uri_template.as_deref().unwrap_or(DEFAULT_METADATA_URI_TEMPLATE).replace("{mint}", &mint.to_string());
+    // metaplex rejects uris longer than MAX_URI_LENGTH, drop whole graphemes so no percent escape or emoji sequence is split
+    let name_capacity = MAX_URI_LENGTH.saturating_sub(template.len() - template.matches("{name}").count() * "{name}".len());
+    let name_uses = template.matches("{name}").count().max(1);
+    let mut encoded_name = String::new();
+    for grapheme in name.graphemes(true) {
+        let encoded_grapheme = encode(grapheme);
+        if (encoded_name.len() + encoded_grapheme.len()) * name_uses > name_capacity {
+            break;
+        }
+        encoded_name.push_str(&encoded_grapheme);
+    }
+    template.replace("{name}", &encoded_name)
+}
+
+/// Metadata name for an entry using the namespace display name format, `{name}` and `{namespace}` are substituted
+pub fn metadata_name(namespace: &Namespace, name: &str) -> String {
+    let mut metadata_name = namespace
+        .metadata_name_format
+        .as_deref()
+        .unwrap_or(DEFAULT_METADATA_NAME_FORMAT)
+        .replace("{name}", name)
+        .replace("{namespace}", &namespace.name);
+    // metaplex rejects names longer than MAX_NAME_LENGTH, truncate on a grapheme boundary so emoji sequences stay whole
+    if metadata_name.len() > MAX_NAME_LENGTH {
+        let end = metadata_name
+            .grapheme_indices(true)
+            .map(|(i, g)| i + g.len())
+            .take_while(|end| *end <= MAX_NAME_LENGTH)
+            .last()
+            .unwrap_or(0);
+        metadata_name.truncate(end);
+    }
+    metadata_name
+}
+
+pub fn metadata_symbol(namespace: &Namespace) -> String {
+    namespace.metadata_symbol.clone().unwrap_or_else(|| DEFAULT_METADATA_SYMBOL.to_string())
+}
+
+/// Metadata creators for an entry, the namespace is always the first verified creator followed by the namespace creators
+pub fn metadata_creators(namespace_key: &Pubkey, namespace: &Namespace) -> Vec<MCreator> {
+    match &namespace.creators {
+        Some(creators) if !creators.is_empty() => [
+            vec![MCreator {
+                address: *namespace_key,
+                verified: true,
+                share: 0,
+            }],
+            creators
+                .iter()
+                .map(|c| MCreator {
+                    address: c.address,
+                    verified: false,
+                    share: c.share,
+                })
+                .collect(),
+        ]
+        .concat(),
+        _ => vec![MCreator {
+            address: *namespace_key,
+            verified: true,
+            share: 100,
+        }],
+    }
+}
+
+pub fn assert_royalty_config(namespace_key: &Pubkey, seller_fee_basis_points: u16, creators: &Option<Vec<Creator>>) -> Result<()> {
+    if seller_fee_basis_points > BASIS_POINTS_DIVISOR {
+        return Err(error!(ErrorCode::InvalidRoyaltyConfig));
+    }
+    if let Some(creators) = creators {
+        if creators.len() > MAX_NAMESPACE_CREATORS {
+            return Err(error!(ErrorCode::InvalidRoyaltyConfig));
+        }
+        if !creators.is_empty() && creators.iter().map(|c| c.share as u16).sum::<u16>() != 100 {
+            return Err(error!(ErrorCode::InvalidRoyaltyConfig));
+        }
+        // the namespace is always prepended as a verified creator and metaplex rejects duplicates
+        for (i, creator) in creators.iter().enumerate() {
+            if creator.address == *namespace_key || creators[..i].iter().any(|c| c.address == creator.address) {
+                return Err(error!(ErrorCode::InvalidRoyaltyConfig));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Protocol fee for a namespace, a fee override account takes precedence over the global fee when initialized
+pub fn namespace_fee_basis_points(global_context: &GlobalContext, namespace_fee_override: &AccountInfo, namespace: &Pubkey) -> Result<u64> {
+    assert_derivation(
+        &crate::id(),
+        namespace_fee_override,
+        &[FEE_OVERRIDE_SEED.as_bytes(), namespace.as_ref()],
+        ErrorCode::InvalidNamespaceFeeOverride,
+    )?;
+    if namespace_fee_override.data_is_empty() {
+        return Ok(global_context.fee_basis_points);
+    }
+    let fee_override = Account::<NamespaceFeeOverride>::try_from(namespace_fee_override).map_err(|_| error!(ErrorCode::InvalidNamespaceFeeOverride))?;
+    Ok(fee_override.fee_basis_points)
+}
+
+/// Accrue protocol fees on payments the namespace received since its payment account was last accounted
+pub fn accrue_protocol_fees(namespace: &mut Namespace, payment_balance: u64, fee_basis_points: u64) {
+    if payment_balance > namespace.accounted_balance {
+        let protocol_fee = (payment_balance - namespace.accounted_balance)
+            .checked_mul(fee_basis_points)
+            .expect("Multiplication error")
+            .checked_div(BASIS_POINTS_DIVISOR.into())
+            .expect("Division error");
+        namespace.accrued_protocol_fees = namespace.accrued_protocol_fees.checked_add(protocol_fee).expect("Add error");
+    }
+    namespace.accounted_balance = payment_balance;
+}
+
+/// Protocol fee on a secondary sale of `sale_amount`, deposited into the namespace payment account and accrued for the global context
+pub fn accrue_sale_fee(namespace: &mut Namespace, sale_amount: u64, payment_balance: u64, fee_basis_points: u64) -> u64 {
+    accrue_protocol_fees(namespace, payment_balance, fee_basis_points);
+    let sale_fee = sale_amount
+        .checked_mul(fee_basis_points)
+        .expect("Multiplication error")
+        .checked_div(BASIS_POINTS_DIVISOR.into())
+        .expect("Division error");
+    namespace.accrued_protocol_fees = namespace.accrued_protocol_fees.checked_add(sale_fee).expect("Add error");
+    namespace.accounted_balance = namespace.accounted_balance.checked_add(sale_fee).expect("Add error");
+    sale_fee
+}
+
+/// Asserts the protocol fee and royalties on a sale at `price` leave the seller a non negative amount
+pub fn assert_sale_price(namespace: &Namespace, price: u64, fee_basis_points: u64) -> Result<()> {
+    let deducted_basis_points = fee_basis_points.checked_add(namespace.seller_fee_basis_points.into()).expect("Add error");
+    if deducted_basis_points > BASIS_POINTS_DIVISOR.into() || price == 0 {
+        return Err(error!(ErrorCode::InvalidListingPrice));
+    }
+    Ok(())
+}
+
+/// Account that paid rent for a claim request, requests created before the payer was recorded were paid by the requestor
+pub fn claim_request_payer(claim_request: &ClaimRequest) -> Pubkey {
+    if claim_request.payer == Pubkey::default() {
+        claim_request.requestor
+    } else {
+        claim_request.payer
+    }
+}
+
+pub fn claim_request_expired(claim_request: &ClaimRequest) -> bool {
+    match claim_request.expires_at {
+        Some(expires_at) => Clock::get().map(|clock| clock.unix_timestamp >= expires_at).unwrap_or(true),
+        None => false,
+    }
+}
+
+pub fn offer_expired(offer: &Offer) -> bool {
+    Clock::get().map(|clock| clock.unix_timestamp >= offer.expires_at).unwrap_or(true)
+}
+
+pub fn sublease_active(sublease: &Sublease) -> bool {
+    match sublease.lease_expires_at {
+        Some(lease_expires_at) => sublease.lessee.is_some() && Clock::get().map(|clock| clock.unix_timestamp < lease_expires_at).unwrap_or(false),
+        None => false,
+    }
+}
+
+const ED25519_SIGNATURE_OFFSETS_START: usize = 2;
+const ED25519_SIGNATURE_OFFSETS_SIZE: usize = 14;
+const ED25519_PUBKEY_SIZE: usize = 32;
+
+/// Asserts that the instruction before the current one is an ed25519 verification of `message` signed by `signer`
+pub fn assert_ed25519_signature(instructions_sysvar: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(error!(ErrorCode::InvalidApprovalSignature));
+    }
+    let ed25519_ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    if ed25519_ix.program_id != ed25519_program::id() || !ed25519_ix.accounts.is_empty() {
+        return Err(error!(ErrorCode::InvalidApprovalSignature));
+    }
+
+    // a single signature with its public key and message in the ed25519 instruction itself
+    let data = &ed25519_ix.data;
+    if data.len() < ED25519_SIGNATURE_OFFSETS_START + ED25519_SIGNATURE_OFFSETS_SIZE || data[0] != 1 {
+        return Err(error!(ErrorCode::InvalidApprovalSignature));
+    }
+    let offsets = &data[ED25519_SIGNATURE_OFFSETS_START..ED25519_SIGNATURE_OFFSETS_START + ED25519_SIGNATURE_OFFSETS_SIZE];
+    let read_u16 = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+    let (signature_instruction_index, public_key_offset, public_key_instruction_index, message_data_offset, message_data_size, message_instruction_index) =
+        (read_u16(2), read_u16(4) as usize, read_u16(6), read_u16(8) as usize, read_u16(10) as usize, read_u16(12));
+    if signature_instruction_index != u16::MAX || public_key_instruction_index != u16::MAX || message_instruction_index != u16::MAX {
+        return Err(error!(ErrorCode::InvalidApprovalSignature));
+    }
+    if data.get(public_key_offset..public_key_offset + ED25519_PUBKEY_SIZE) != Some(signer.as_ref()) || data.get(message_data_offset..message_data_offset + message_data_size) != Some(message) {
+        return Err(error!(ErrorCode::InvalidApprovalSignature));
+    }
+    Ok(())
+}
+
+const MERKLE_LEAF_PREFIX: &[u8] = &[0];
+const MERKLE_NODE_PREFIX: &[u8] = &[1];
+
+/// Allowlist leaf for an entry name and requestor
+pub fn allowlist_leaf(entry_name: &str, requestor: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[MERKLE_LEAF_PREFIX, entry_name.as_bytes(), requestor.as_ref()]).0
+}
+
+/// Verifies a merkle proof where each pair of nodes is hashed in sorted order, leaves and nodes are domain separated by a prefix byte
+pub fn assert_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> Result<()> {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[MERKLE_NODE_PREFIX, &node, sibling]).0
+        } else {
+            keccak::hashv(&[MERKLE_NODE_PREFIX, sibling, &node]).0
+        }
+    });
+    if computed != *root {
+        return Err(error!(ErrorCode::InvalidAllowlistProof));
+    }
+    Ok(())
+}
+
+/// Asserts the holder satisfies the namespace gate using their token account, and for collection gates the token metadata
+pub fn assert_gate(gate: &Gate, holder: &Pubkey, gate_token_account: Option<&AccountInfo>, gate_metadata: Option<&AccountInfo>) -> Result<()> {
+    let token_account = Account::<TokenAccount>::try_from(gate_token_account.ok_or_else(|| error!(ErrorCode::GateNotSatisfied))?).map_err(|_| error!(ErrorCode::GateNotSatisfied))?;
+    if token_account.owner != *holder || token_account.amount < gate.min_amount.max(1) {
+        return Err(error!(ErrorCode::GateNotSatisfied));
+    }
+    match gate.gate_type {
+        t if t == GateType::Mint as u8 => {
+            if token_account.mint != gate.mint {
+                return Err(error!(ErrorCode::GateNotSatisfied));
+            }
+        }
+        t if t == GateType::Collection as u8 => {
+            let gate_metadata = gate_metadata.ok_or_else(|| error!(ErrorCode::GateNotSatisfied))?;
+            assert_derivation(
+                &mpl_token_metadata::id(),
+                gate_metadata,
+                &[mpl_token_metadata::state::PREFIX.as_bytes(), mpl_token_metadata::id().as_ref(), token_account.mint.as_ref()],
+                ErrorCode::GateNotSatisfied,
+            )?;
+            let metadata: Metadata = Metadata::from_account_info(gate_metadata).map_err(|_| error!(ErrorCode::GateNotSatisfied))?;
+            if !metadata.collection.map(|c| c.verified && c.key == gate.mint).unwrap_or(false) {
+                return Err(error!(ErrorCode::GateNotSatisfied));
+            }
+        }
+        _ => return Err(error!(ErrorCode::InvalidGate)),
+    }
+    Ok(())
+}
+
+/// Grows an account owned by this program to `new_size`, topping up rent from the payer
+pub fn resize_account<'info>(account: &AccountInfo<'info>, new_size: usize, payer: &AccountInfo<'info>, system_program: &AccountInfo<'info>) -> Result<()> {
+    if account.data_len() >= new_size {
+        return Ok(());
+    }
+    let lamports_needed = Rent::get()?.minimum_balance(new_size).saturating_sub(account.lamports());
+    if lamports_needed > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, lamports_needed),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    account.realloc(new_size, false)?;
+    Ok(())
+}
+
+/// Creates a program owned PDA, accounts that were already sent lamports are topped up, allocated and assigned instead
+pub fn create_pda_account<'info>(account: &AccountInfo<'info>, space: usize, payer: &AccountInfo<'info>, system_program: &AccountInfo<'info>, signer_seeds: &[&[u8]]) -> Result<()> {
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    if account.lamports() == 0 {
+        invoke_signed(
+            &system_instruction::create_account(payer.key, account.key, rent_lamports, space as u64, &crate::id()),
+            &[payer.clone(), account.clone(), system_program.clone()],
+            &[signer_seeds],
+        )?;
+        return Ok(());
+    }
+    let lamports_needed = rent_lamports.saturating_sub(account.lamports());
+    if lamports_needed > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, lamports_needed),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    invoke_signed(&system_instruction::allocate(account.key, space as u64), &[account.clone(), system_program.clone()], &[signer_seeds])?;
+    invoke_signed(&system_instruction::assign(account.key, &crate::id()), &[account.clone(), system_program.clone()], &[signer_seeds])?;
+    Ok(())
+}
+
+/// Claim request counter of the name entry, zero until the entry is initialized
+pub fn name_entry_claim_request_counter(program_id: &Pubkey, namespace: &Pubkey, entry_name: &str, name_entry: &AccountInfo) -> Result<u32> {
+    assert_derivation(
+        program_id,
+        name_entry,
+        &[ENTRY_SEED.as_bytes(), namespace.as_ref(), name_seed(entry_name).as_ref()],
+        ErrorCode::InvalidEntry,
+    )?;
+    if name_entry.data_is_empty() {
+        return Ok(0);
+    }
+    Ok(Account::<Entry>::try_from(name_entry)?.claim_request_counter)
+}
+
+/// Approves the claim request for `requestor`, growing requests created before the payer and expiration were stored
+#[allow(clippy::too_many_arguments)]
+pub fn fill_approved_claim_request<'info>(
+    claim_request: &mut Account<'info, ClaimRequest>,
+    bump: u8,
+    namespace: &Pubkey,
+    entry_name: String,
+    requestor: Pubkey,
+    counter: u32,
+    expires_at: Option<i64>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    resize_account(&claim_request.to_account_info(), claim_request_size(&entry_name), payer, system_program)?;
+    // only new requests record the payer, legacy requests leave it unset so rent returns to the requestor
+    if claim_request.requestor == Pubkey::default() {
+        claim_request.payer = payer.key();
+    }
+    claim_request.bump = bump;
+    claim_request.namespace = *namespace;
+    claim_request.entry_name = entry_name;
+    claim_request.is_approved = true;
+    claim_request.requestor = requestor;
+    claim_request.counter = counter;
+    claim_request.expires_at = expires_at;
+    Ok(())
+}
+
+/// Time invalidator accounts for a claim, required when the namespace charges for or expires its entries
+pub struct ClaimTimeInvalidatorAccounts<'info> {
+    pub payment_mint: AccountInfo<'info>,
+    pub payment_manager: AccountInfo<'info>,
+    pub time_invalidator: AccountInfo<'info>,
+    pub time_invalidator_program: AccountInfo<'info>,
+}
+
+/// Accounts used to issue a claimed entry's mint to the recipient through the token manager
+pub struct IssueNameEntryAccounts<'info> {
+    pub mint: AccountInfo<'info>,
+    pub namespace_token_account: AccountInfo<'info>,
+    pub token_manager: AccountInfo<'info>,
+    pub token_manager_token_account: AccountInfo<'info>,
+    pub mint_counter: AccountInfo<'info>,
+    pub recipient: AccountInfo<'info>,
+    pub recipient_token_account: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub token_manager_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    /// mint edition and token metadata program, required for non transferable entries
+    pub mint_edition: Option<(AccountInfo<'info>, AccountInfo<'info>)>,
+    pub time_invalidator: Option<ClaimTimeInvalidatorAccounts<'info>>,
+}
+
+/// Issues a claimed entry's mint to the recipient, adding a time invalidator when the namespace charges `daily_payment_amount` or expires entries
+pub fn issue_name_entry<'info>(namespace: &Account<'info, Namespace>, accounts: &IssueNameEntryAccounts<'info>, daily_payment_amount: u64) -> Result<()> {
+    let namespace_seeds = &[NAMESPACE_PREFIX.as_bytes(), namespace.name.as_bytes(), &[namespace.bump]];
+    let namespace_signer = &[&namespace_seeds[..]];
+
+    if accounts.token_manager_token_account.data_is_empty() {
+        // create associated token account for certificate mint
+        let cpi_accounts = associated_token::Create {
+            payer: accounts.payer.clone(),
+            associated_token: accounts.token_manager_token_account.clone(),
+            authority: accounts.token_manager.clone(),
+            mint: accounts.mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: accounts.token_program.clone(),
+            rent: accounts.rent.clone(),
+        };
+        let cpi_context = CpiContext::new(accounts.token_program.clone(), cpi_accounts);
+        associated_token::create(cpi_context)?;
+    }
+
+    let has_time_invalidator = daily_payment_amount > 0 || namespace.max_expiration.is_some();
+
+    // token manager init
+    let init_ix = cardinal_token_manager::instructions::InitIx {
+        amount: 1,
+        kind: if namespace.transferable_entries {
+            TokenManagerKind::Unmanaged as u8
+        } else {
+            TokenManagerKind::Edition as u8
+        },
+        // for old versions of namespace, field invalidation_type didn't exist and defaults to zero
+        invalidation_type: if namespace.invalidation_type == 0 {
+            if namespace.transferable_entries {
+                InvalidationType::Invalidate as u8
+            } else {
+                InvalidationType::Return as u8
+            }
+        } else {
+            namespace.invalidation_type
+        },
+        num_invalidators: if has_time_invalidator { 2 } else { 1 },
+    };
+    let cpi_accounts = cardinal_token_manager::cpi::accounts::InitCtx {
+        token_manager: accounts.token_manager.clone(),
+        mint_counter: accounts.mint_counter.clone(),
+        issuer: namespace.to_account_info(),
+        payer: accounts.payer.clone(),
+        issuer_token_account: accounts.namespace_token_account.clone(),
+        system_program: accounts.system_program.clone(),
+        mint: accounts.mint.clone(),
+    };
+    let cpi_ctx = CpiContext::new(accounts.token_manager_program.clone(), cpi_accounts).with_signer(namespace_signer);
+    cardinal_token_manager::cpi::init(cpi_ctx, init_ix)?;
+
+    // add invalidator
+    let cpi_accounts = cardinal_token_manager::cpi::accounts::AddInvalidatorCtx {
+        token_manager: accounts.token_manager.clone(),
+        issuer: namespace.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(accounts.token_manager_program.clone(), cpi_accounts).with_signer(namespace_signer);
+    cardinal_token_manager::cpi::add_invalidator(cpi_ctx, namespace.key())?;
+
+    if has_time_invalidator {
+        let time_invalidator_accounts = accounts.time_invalidator.as_ref().ok_or_else(|| error!(ErrorCode::MissingRequiredAccount))?;
+        let payment_mint = Account::<Mint>::try_from(&time_invalidator_accounts.payment_mint)?;
+        if payment_mint.key() != namespace.payment_mint {
+            return Err(error!(ErrorCode::InvalidPaymentMint));
+        }
+        assert_payment_manager(&time_invalidator_accounts.payment_manager)?;
+        assert_time_invalidator(&time_invalidator_accounts.time_invalidator, &accounts.token_manager.key())?;
+        if time_invalidator_accounts.time_invalidator_program.key() != cardinal_time_invalidator::id() {
+            return Err(error!(ErrorCode::InvalidTimeInvalidatorProgramId));
+        }
+
+        // init time invalidator
+        let init_ix = cardinal_time_invalidator::instructions::InitIx {
+            collector: namespace.key(),
+            payment_manager: time_invalidator_accounts.payment_manager.key(),
+            duration_seconds: if daily_payment_amount > 0 { Some(0) } else { None },
+            extension_payment_amount: if daily_payment_amount > 0 { Some(daily_payment_amount) } else { None },
+            extension_duration_seconds: if daily_payment_amount > 0 { Some(86400) } else { None },
+            extension_payment_mint: if daily_payment_amount > 0 { Some(payment_mint.key()) } else { None },
+            max_expiration: namespace.max_expiration,
+            disable_partial_extension: None,
+        };
+        let cpi_accounts = cardinal_time_invalidator::cpi::accounts::InitCtx {
+            token_manager: accounts.token_manager.clone(),
+            issuer: namespace.to_account_info(),
+            payer: accounts.payer.clone(),
+            time_invalidator: time_invalidator_accounts.time_invalidator.clone(),
+            system_program: accounts.system_program.clone(),
+        };
+        let cpi_ctx = CpiContext::new(time_invalidator_accounts.time_invalidator_program.clone(), cpi_accounts).with_signer(namespace_signer);
+        cardinal_time_invalidator::cpi::init(cpi_ctx, init_ix)?;
+
+        // add time invalidator
+        let cpi_accounts = cardinal_token_manager::cpi::accounts::AddInvalidatorCtx {
+            token_manager: accounts.token_manager.clone(),
+            issuer: namespace.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(accounts.token_manager_program.clone(), cpi_accounts).with_signer(namespace_signer);
+        cardinal_token_manager::cpi::add_invalidator(cpi_ctx, time_invalidator_accounts.time_invalidator.key())?;
+    }
+
+    // token manager issue
+    let cpi_accounts = cardinal_token_manager::cpi::accounts::IssueCtx {
+        token_manager: accounts.token_manager.clone(),
+        token_manager_token_account: accounts.token_manager_token_account.clone(),
+        issuer: namespace.to_account_info(),
+        issuer_token_account: accounts.namespace_token_account.clone(),
+        payer: accounts.payer.clone(),
+        token_program: accounts.token_program.clone(),
+        system_program: accounts.system_program.clone(),
+    };
+    let cpi_ctx = CpiContext::new(accounts.token_manager_program.clone(), cpi_accounts).with_signer(namespace_signer);
+    cardinal_token_manager::cpi::issue(cpi_ctx)?;
+
+    // token manager claim
+    let cpi_accounts = cardinal_token_manager::cpi::accounts::ClaimCtx {
+        token_manager: accounts.token_manager.clone(),
+        token_manager_token_account: accounts.token_manager_token_account.clone(),
+        mint: accounts.mint.clone(),
+        recipient: accounts.recipient.clone(),
+        recipient_token_account: accounts.recipient_token_account.clone(),
+        token_program: accounts.token_program.clone(),
+        system_program: accounts.system_program.clone(),
+    };
+    if namespace.transferable_entries {
+        let cpi_ctx = CpiContext::new(accounts.token_manager_program.clone(), cpi_accounts);
+        cardinal_token_manager::cpi::claim(cpi_ctx)?;
+    } else {
+        let (mint_edition, token_metadata_program) = accounts.mint_edition.as_ref().ok_or_else(|| error!(ErrorCode::MissingRequiredAccount))?;
+        let cpi_ctx = CpiContext::new(accounts.token_manager_program.clone(), cpi_accounts).with_remaining_accounts([mint_edition.clone(), token_metadata_program.clone()].to_vec());
+        cardinal_token_manager::cpi::claim(cpi_ctx)?;
+    }
+    Ok(())
+}
+
+/// Accounts used to pay for the initial rental duration of a claimed entry
+pub struct ExtendNameEntryAccounts<'info> {
+    pub token_manager: AccountInfo<'info>,
+    pub time_invalidator: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub payment_manager: AccountInfo<'info>,
+    pub payer_token_account: AccountInfo<'info>,
+    pub payment_token_account: AccountInfo<'info>,
+    pub fee_collector_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub payment_manager_program: AccountInfo<'info>,
+    pub time_invalidator_program: AccountInfo<'info>,
+}
+
+/// Pays the namespace for `duration` seconds of a claimed entry through its time invalidator
+pub fn extend_name_entry<'info>(namespace: &Account<'info, Namespace>, accounts: &ExtendNameEntryAccounts<'info>, duration: i64, remaining_accounts: Vec<AccountInfo<'info>>) -> Result<()> {
+    if accounts.payment_manager_program.key() != cardinal_payment_manager::id() {
+        return Err(error!(ErrorCode::InvalidPaymentManagerProgramId));
+    }
+    let payment_manager = assert_payment_manager(&accounts.payment_manager)?;
+    assert_token_account(&accounts.payer_token_account, &namespace.payment_mint, &accounts.payer.key(), ErrorCode::InvalidPayerTokenAccount)?;
+    assert_token_account(&accounts.payment_token_account, &namespace.payment_mint, &namespace.key(), ErrorCode::InvalidNamespacePaymentAccount)?;
+    assert_token_account(
+        &accounts.fee_collector_token_account,
+        &namespace.payment_mint,
+        &payment_manager.fee_collector,
+        ErrorCode::InvalidFeeCollectorTokenAccount,
+    )?;
+
+    let namespace_seeds = &[NAMESPACE_PREFIX.as_bytes(), namespace.name.as_bytes(), &[namespace.bump]];
+    let namespace_signer = &[&namespace_seeds[..]];
+    let cpi_accounts = cardinal_time_invalidator::cpi::accounts::ExtendExpirationCtx {
+        token_manager: accounts.token_manager.clone(),
+        time_invalidator: accounts.time_invalidator.clone(),
+        payer: accounts.payer.clone(),
+        payment_manager: accounts.payment_manager.clone(),
+        payment_token_account: accounts.payment_token_account.clone(),
+        fee_collector_token_account: accounts.fee_collector_token_account.clone(),
+        payer_token_account: accounts.payer_token_account.clone(),
+        token_program: accounts.token_program.clone(),
+        cardinal_payment_manager: accounts.payment_manager_program.clone(),
+    };
+    let cpi_ctx = CpiContext::new(accounts.time_invalidator_program.clone(), cpi_accounts)
+        .with_signer(namespace_signer)
+        .with_remaining_accounts(remaining_accounts);
+    cardinal_time_invalidator::cpi::extend_expiration(cpi_ctx, duration.try_into().expect("Duration invalid"))?;
+    Ok(())
+}
+
+/// Counts a newly claimed entry against the wallet, creating the wallet counter if needed
+pub fn increment_wallet_entry_counter<'info>(
+    namespace: &Namespace,
+    namespace_key: &Pubkey,
+    wallet: &Pubkey,
+    counter_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let bump = assert_derivation(
+        &crate::id(),
+        counter_info,
+        &[WALLET_ENTRY_COUNTER_SEED.as_bytes(), namespace_key.as_ref(), wallet.as_ref()],
+        ErrorCode::InvalidWalletEntryCounter,
+    )?;
+    let mut counter = if counter_info.data_is_empty() {
+        create_pda_account(
+            counter_info,
+            WALLET_ENTRY_COUNTER_SIZE,
+            payer,
+            system_program,
+            &[WALLET_ENTRY_COUNTER_SEED.as_bytes(), namespace_key.as_ref(), wallet.as_ref(), &[bump]],
+        )?;
+        let mut counter = Account::<WalletEntryCounter>::try_from_unchecked(counter_info)?;
+        counter.bump = bump;
+        counter.namespace = *namespace_key;
+        counter.wallet = *wallet;
+        counter.count = 0;
+        counter
+    } else {
+        Account::<WalletEntryCounter>::try_from(counter_info)?
+    };
+    counter.count = counter.count.checked_add(1).expect("Add error");
+    if namespace.max_entries_per_wallet.map(|max| counter.count > max).unwrap_or(false) {
+        return Err(error!(ErrorCode::WalletReachedLimit));
+    }
+    counter.exit(&crate::id())?;
+    Ok(())
+}
+
+/// Releases an entry from its claimer's wallet counter. Instructions that release entries pass the counter as the first
+/// remaining account, it is only read for entries that were counted against a per wallet limit
+pub fn decrement_wallet_entry_counter(name_entry: &mut Entry, remaining_accs: &mut std::slice::Iter<AccountInfo>) -> Result<()> {
+    if let Some(claimer) = name_entry.claimer {
+        let counter_info = next_account_info(remaining_accs).map_err(|_| error!(ErrorCode::InvalidWalletEntryCounter))?;
+        assert_derivation(
+            &crate::id(),
+            counter_info,
+            &[WALLET_ENTRY_COUNTER_SEED.as_bytes(), name_entry.namespace.as_ref(), claimer.as_ref()],
+            ErrorCode::InvalidWalletEntryCounter,
+        )?;
+        let mut counter = Account::<WalletEntryCounter>::try_from(counter_info)?;
+        counter.count = counter.count.saturating_sub(1);
+        counter.exit(&crate::id())?;
+        name_entry.claimer = None;
+    }
+    Ok(())
+}
+
+/// Pays royalties on a secondary sale to the namespace metadata creators with a share, remaining accounts hold a payment token account for each
+/// creator in order and the namespace is paid to its payment account. Returns the amount paid
+#[allow(clippy::too_many_arguments)]
+pub fn pay_royalties<'info>(
+    namespace: &Account<'info, Namespace>,
+    sale_amount: u64,
+    payment_mint: &Pubkey,
+    from: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    token_program: &AccountInfo<'info>,
+    remaining_accs: &mut std::slice::Iter<AccountInfo<'info>>,
+) -> Result<u64> {
+    let royalty_amount = sale_amount
+        .checked_mul(namespace.seller_fee_basis_points.into())
+        .expect("Multiplication error")
+        .checked_div(BASIS_POINTS_DIVISOR.into())
+        .expect("Division error");
+    let mut royalties_paid: u64 = 0;
+    if royalty_amount == 0 {
+        return Ok(royalties_paid);
+    }
+    for creator in metadata_creators(&namespace.key(), namespace).iter().filter(|c| c.share > 0) {
+        let creator_token_account_info = next_account_info(remaining_accs)?;
+        if creator.address == namespace.key() {
+            if creator_token_account_info.key() != get_associated_token_address(&creator.address, payment_mint) {
+                return Err(error!(ErrorCode::InvalidCreatorTokenAccount));
+            }
+        } else {
+            assert_token_account(creator_token_account_info, payment_mint, &creator.address, ErrorCode::InvalidCreatorTokenAccount)?;
+        }
+        let creator_amount = royalty_amount
+            .checked_mul(creator.share.into())
+            .expect("Multiplication error")
+            .checked_div(100)
+            .expect("Division error");
+        royalties_paid = royalties_paid.checked_add(creator_amount).expect("Add error");
+
+        let cpi_accounts = Transfer {
+            from: from.clone(),
+            to: creator_token_account_info.clone(),
+            authority: authority.clone(),
+        };
+        let cpi_context = CpiContext::new(token_program.clone(), cpi_accounts).with_signer(signer_seeds);
+        token::transfer(cpi_context, creator_amount)?;
+    }
+    Ok(royalties_paid)
+}
+
+/// Points a sold entry at its new owner, closing the previous owner's reverse entry for it
+pub fn reassign_name_entry<'info>(name_entry: &mut Entry, reverse_entry_info: &AccountInfo<'info>, previous_owner: &AccountInfo<'info>, new_owner: &Pubkey) -> Result<()> {
+    if let Some(reverse_entry_key) = name_entry.reverse_entry {
+        if reverse_entry_info.key() != reverse_entry_key {
+            return Err(error!(ErrorCode::InvalidReverseEntryForNameEntry));
+        }
+        if !reverse_entry_info.data_is_empty() {
+            let reverse_entry = Account::<ReverseEntry>::try_from(reverse_entry_info)?;
+            if reverse_entry.entry_name == name_entry.name {
+                if !reverse_entry_info.is_writable {
+                    return Err(error!(anchor_lang::error::ErrorCode::ConstraintMut));
+                }
+                reverse_entry.close(previous_owner.clone())?;
+            }
+        }
+        name_entry.reverse_entry = None;
+    }
+    name_entry.data = Some(*new_owner);
+    Ok(())
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum NameScript {
+    Common,
+    Latin,
+    Greek,
+    Cyrillic,
+    Other,
+}
+
+fn name_script(c: char) -> NameScript {
+    if !c.is_alphabetic() {
+        return NameScript::Common;
+    }
+    match c as u32 {
+        0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F | 0x1E00..=0x1EFF | 0x2C60..=0x2C7F | 0xA720..=0xA7FF => NameScript::Latin,
+        0x370..=0x3FF | 0x1F00..=0x1FFF => NameScript::Greek,
+        0x400..=0x52F | 0x1C80..=0x1C8F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => NameScript::Cyrillic,
+        _ => NameScript::Other,
+    }
+}
+
+// invisible characters that normalization keeps as they are
+fn is_invisible_char(c: char) -> bool {
+    c.is_control()
+        || c.is_whitespace()
+        || matches!(
+            c as u32,
+            // soft hyphen, zero width and bidi formatting, word joiners and byte order mark
+            0xAD | 0x200B..=0x200F | 0x202A..=0x202E | 0x2060..=0x206F | 0xFEFF
+        )
+}
+
+// greek and cyrillic letters that render the same as a latin letter
+const LATIN_LOOKALIKES: &[char] = &['а', 'е', 'о', 'р', 'с', 'у', 'х', 'ѕ', 'і', 'ј', 'ԁ', 'һ', 'ԛ', 'ԝ', 'ӏ', 'α', 'ι', 'κ', 'ν', 'ο', 'ρ', 'υ'];
+
+/// Checks a name length and the namespace name policy, names are never rewritten on chain so clients derive entry
+/// and claim request addresses from the canonical name they submit
+pub fn assert_name(namespace: &Namespace, name: &str) -> Result<()> {
+    let length = name_length(name);
+    if name.len() > MAX_ENTRY_NAME_LENGTH || matches!(namespace.max_name_length, Some(max) if length > max as usize) {
+        return Err(error!(ErrorCode::NameTooLong));
+    }
+    if matches!(namespace.min_name_length, Some(min) if length < min as usize) {
+        return Err(error!(ErrorCode::NameTooShort));
+    }
+    if namespace.name_policy == NamePolicy::Unrestricted as u8 {
+        return Ok(());
+    }
+    // emoji sequences carry joiners and modifiers, they are checked against the namespace emoji allowlist instead
+    let text: String = name.graphemes(true).filter(|g| !is_emoji_grapheme(g)).collect();
+    // nfkc text is also nfc, so this rejects both decomposed and compatibility forms and only runs the full
+    // normalization when the quick check is unsure
+    if name.is_empty() || !is_nfkc(&text) || text.chars().any(|c| is_invisible_char(c) || c.to_lowercase().ne(std::iter::once(c))) {
+        return Err(error!(ErrorCode::NonCanonicalName));
+    }
+    if namespace.name_policy == NamePolicy::Strict as u8 {
+        let mut scripts = name.chars().map(name_script).filter(|s| matches!(s, NameScript::Latin | NameScript::Greek | NameScript::Cyrillic));
+        let first_script = scripts.next();
+        if scripts.any(|s| Some(s) != first_script) {
+            return Err(error!(ErrorCode::MixedScriptName));
+        }
+        if matches!(first_script, Some(NameScript::Greek | NameScript::Cyrillic)) && name.chars().filter(|c| c.is_alphabetic()).all(|c| LATIN_LOOKALIKES.contains(&c)) {
+            return Err(error!(ErrorCode::ConfusableName));
+        }
+    }
+    Ok(())
+}
+
+/// Name length in grapheme clusters, so an emoji sequence or an accented letter counts once
+pub fn name_length(name: &str) -> usize {
+    name.graphemes(true).count()
+}
+
+pub fn is_emoji_grapheme(grapheme: &str) -> bool {
+    grapheme.chars().any(|c| matches!(c as u32, 0x1F000..=0x1FAFF | 0x2300..=0x23FF | 0x2600..=0x27BF | 0x2B00..=0x2BFF))
+}
+
+/// Checks emoji in a new name against the namespace emoji allowlist, namespaces without a name policy accept any emoji
+pub fn assert_emoji_allowlist(namespace: &Namespace, namespace_key: &Pubkey, name: &str, emoji_allowlist: Option<&AccountInfo>) -> Result<()> {
+    if namespace.name_policy == NamePolicy::Unrestricted as u8 {
+        return Ok(());
+    }
+    let mut emoji = name.graphemes(true).filter(|g| is_emoji_grapheme(g)).peekable();
+    if emoji.peek().is_none() {
+        return Ok(());
+    }
+    let emoji_allowlist = emoji_allowlist.ok_or_else(|| error!(ErrorCode::EmojiNotAllowed))?;
+    assert_derivation(
+        &crate::id(),
+        emoji_allowlist,
+        &[EMOJI_ALLOWLIST_SEED.as_bytes(), namespace_key.as_ref()],
+        ErrorCode::InvalidEmojiAllowlist,
+    )?;
+    if emoji_allowlist.data_is_empty() {
+        return Err(error!(ErrorCode::EmojiNotAllowed));
+    }
+    let emoji_allowlist = Account::<EmojiAllowlist>::try_from(emoji_allowlist)?;
+    if emoji.any(|g| !emoji_allowlist.sequences.iter().any(|s| s == g)) {
+        return Err(error!(ErrorCode::EmojiNotAllowed));
+    }
+    Ok(())
+}
+
+/// Daily payment for a name, the first tier that fits the name length applies and longer names pay the namespace default
+pub fn payment_amount_daily(namespace: &Namespace, name: &str) -> u64 {
+    let length = name_length(name);
+    namespace
+        .payment_tiers
+        .as_ref()
+        .and_then(|tiers| tiers.iter().find(|t| length <= t.max_name_length as usize))
+        .map(|t| t.payment_amount_daily)
+        .unwrap_or(namespace.payment_amount_daily)
+}
+
+/// Checks an entry is currently held, its token manager is claimed and its time invalidator will not have expired by
+/// `live_until` even if nobody has invalidated it yet
+pub fn assert_name_entry_live(namespace_key: &Pubkey, name_entry: &Entry, token_manager_info: &AccountInfo, time_invalidator_info: &AccountInfo, live_until: i64) -> Result<()> {
+    assert_token_manager(token_manager_info, &name_entry.mint)?;
+    let token_manager = Account::<TokenManager>::try_from(token_manager_info)?;
+    if !name_entry.is_claimed || token_manager.issuer != *namespace_key || token_manager.state != TokenManagerState::Claimed as u8 {
+        return Err(error!(ErrorCode::NameEntryNotLive));
+    }
+    assert_time_invalidator(time_invalidator_info, &token_manager.key())?;
+    if token_manager.invalidators.contains(&time_invalidator_info.key()) {
+        let time_invalidator = Account::<TimeInvalidator>::try_from(time_invalidator_info)?;
+        let expiration = match (time_invalidator.expiration, time_invalidator.duration_seconds) {
+            (Some(expiration), _) => Some(expiration),
+            (None, Some(duration_seconds)) => Some(token_manager.state_changed_at.checked_add(duration_seconds).expect("Add error")),
+            (None, None) => None,
+        };
+        if matches!(expiration, Some(expiration) if live_until >= expiration) || matches!(time_invalidator.max_expiration, Some(max_expiration) if live_until >= max_expiration) {
+            return Err(error!(ErrorCode::NameEntryNotLive));
+        }
+    }
+    Ok(())
+}
@@ -10,8 +10,14 @@ import type { Connection, PublicKey } from "@solana/web3.js";
 import type {
   ClaimRequestData,
   EntryData,
+  ListingData,
   NamespaceData,
+  OfferData,
+  PrimaryNameData,
+  ReferrerData,
   ReverseEntryData,
+  SubleaseData,
+  WalletEntryCounterData,
 } from ".";
 import { NAMESPACES_IDL, NAMESPACES_PROGRAM_ID } from ".";
 import type { NAMESPACES_PROGRAM } from "./constants";
@@ -20,9 +26,15 @@ import {
   findDeprecatedReverseEntryId,
   findGlobalContextId,
   findGlobalReverseNameEntryId,
+  findListingId,
   findNameEntryId,
   findNamespaceId,
+  findOfferId,
+  findPrimaryNameId,
+  findReferrerId,
   findReverseNameEntryForNamespaceId,
+  findSubleaseId,
+  findWalletEntryCounterId,
 } from "./pda";
 
 export async function getNamespaceByName(
@@ -355,3 +367,129 @@ export async function tryGetNameEntry(
     return null;
   }
 }
+
+export async function getReferrer(
+  connection: Connection,
+  authority: PublicKey
+): Promise<AccountData<ReferrerData>> {
+  const referrerId = findReferrerId(authority);
+  // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+  // @ts-ignore
+  const provider = new AnchorProvider(connection, null, {});
+  const namespacesProgram = new Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const parsed = await namespacesProgram.account.referrer.fetch(referrerId);
+  return {
+    parsed,
+    pubkey: referrerId,
+  };
+}
+
+export async function getListing(
+  connection: Connection,
+  mintId: PublicKey
+): Promise<AccountData<ListingData>> {
+  const listingId = findListingId(mintId);
+  // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+  // @ts-ignore
+  const provider = new AnchorProvider(connection, null, {});
+  const namespacesProgram = new Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const parsed = await namespacesProgram.account.listing.fetch(listingId);
+  return {
+    parsed,
+    pubkey: listingId,
+  };
+}
+
+export async function getOffer(
+  connection: Connection,
+  nameEntryId: PublicKey,
+  buyer: PublicKey
+): Promise<AccountData<OfferData>> {
+  const offerId = findOfferId(nameEntryId, buyer);
+  // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+  // @ts-ignore
+  const provider = new AnchorProvider(connection, null, {});
+  const namespacesProgram = new Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const parsed = await namespacesProgram.account.offer.fetch(offerId);
+  return {
+    parsed,
+    pubkey: offerId,
+  };
+}
+
+export async function getSublease(
+  connection: Connection,
+  mintId: PublicKey
+): Promise<AccountData<SubleaseData>> {
+  const subleaseId = findSubleaseId(mintId);
+  // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+  // @ts-ignore
+  const provider = new AnchorProvider(connection, null, {});
+  const namespacesProgram = new Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const parsed = await namespacesProgram.account.sublease.fetch(subleaseId);
+  return {
+    parsed,
+    pubkey: subleaseId,
+  };
+}
+
+export async function getPrimaryName(
+  connection: Connection,
+  wallet: PublicKey
+): Promise<AccountData<PrimaryNameData>> {
+  const primaryNameId = findPrimaryNameId(wallet);
+  // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+  // @ts-ignore
+  const provider = new AnchorProvider(connection, null, {});
+  const namespacesProgram = new Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const parsed = await namespacesProgram.account.primaryName.fetch(
+    primaryNameId
+  );
+  return {
+    parsed,
+    pubkey: primaryNameId,
+  };
+}
+
+export async function getWalletEntryCounter(
+  connection: Connection,
+  namespaceId: PublicKey,
+  wallet: PublicKey
+): Promise<AccountData<WalletEntryCounterData>> {
+  const walletEntryCounterId = findWalletEntryCounterId(namespaceId, wallet);
+  // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+  // @ts-ignore
+  const provider = new AnchorProvider(connection, null, {});
+  const namespacesProgram = new Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const parsed = await namespacesProgram.account.walletEntryCounter.fetch(
+    walletEntryCounterId
+  );
+  return {
+    parsed,
+    pubkey: walletEntryCounterId,
+  };
+}
@@ -39,6 +39,27 @@ export type ClaimRequestData = ParsedIdlAccountData<
   NAMESPACES_PROGRAM
 >;
 
+export type ReferrerData = ParsedIdlAccountData<"referrer", NAMESPACES_PROGRAM>;
+
+export type ListingData = ParsedIdlAccountData<"listing", NAMESPACES_PROGRAM>;
+
+export type OfferData = ParsedIdlAccountData<"offer", NAMESPACES_PROGRAM>;
+
+export type SubleaseData = ParsedIdlAccountData<
+  "sublease",
+  NAMESPACES_PROGRAM
+>;
+
+export type PrimaryNameData = ParsedIdlAccountData<
+  "primaryName",
+  NAMESPACES_PROGRAM
+>;
+
+export type WalletEntryCounterData = ParsedIdlAccountData<
+  "walletEntryCounter",
+  NAMESPACES_PROGRAM
+>;
+
 export const DEFAULT_PAYMENT_MANAGER = "cardinal";
 export const IDENTITIES = [
   "twitter",
@@ -54,6 +75,19 @@ export const NAMESPACE_SEED = "namespace";
 export const ENTRY_SEED = "entry";
 export const REVERSE_ENTRY_SEED = "reverse-entry";
 export const CLAIM_REQUEST_SEED = "rent-request";
+export const REFERRER_SEED = "referrer";
+export const FEE_OVERRIDE_SEED = "fee-override";
+export const ALLOWLIST_CLAIM_SEED = "allowlist-claim";
+export const WALLET_ENTRY_COUNTER_SEED = "wallet-entry-counter";
+export const PRIMARY_NAME_SEED = "primary-name";
+export const LISTING_SEED = "listing";
+export const OFFER_SEED = "offer";
+export const SUBLEASE_SEED = "sublease";
+export const EMOJI_ALLOWLIST_SEED = "emoji-allowlist";
+export const MAX_NAME_SEED_LENGTH = 32;
+export const LONG_NAME_SEED_PREFIX = "long:";
+// claim requests were allocated at this size before payer and expiration
+export const LEGACY_CLAIM_REQUEST_SIZE = 128;
 
 export const namespacesProgram = (
   connection: Connection,
@@ -28,6 +28,7 @@ import {
   NAMESPACES_PROGRAM_ID,
   REVERSE_ENTRY_SEED,
 } from "./constants";
+import { entryNameSeed } from "./pda";
 
 export async function withClaimEntry(
   connection: Connection,
@@ -56,7 +57,7 @@ export async function withClaimEntry(
     [
       anchor.utils.bytes.utf8.encode(ENTRY_SEED),
       namespaceId.toBytes(),
-      anchor.utils.bytes.utf8.encode(entryName),
+      entryNameSeed(entryName),
     ],
     namespacesProgram.programId
   );
@@ -65,7 +66,7 @@ export async function withClaimEntry(
     [
       anchor.utils.bytes.utf8.encode(CLAIM_REQUEST_SEED),
       namespaceId.toBytes(),
-      anchor.utils.bytes.utf8.encode(entryName),
+      entryNameSeed(entryName),
       provider.wallet.publicKey.toBytes(),
     ],
     namespacesProgram.programId
@@ -194,7 +195,7 @@ export async function withInitEntry(
     [
       anchor.utils.bytes.utf8.encode(ENTRY_SEED),
       namespaceId.toBytes(),
-      anchor.utils.bytes.utf8.encode(entryName),
+      entryNameSeed(entryName),
     ],
     namespacesProgram.programId
   );
@@ -290,7 +291,7 @@ export async function withRevokeEntry(
     [
       anchor.utils.bytes.utf8.encode(ENTRY_SEED),
       namespaceId.toBytes(),
-      anchor.utils.bytes.utf8.encode(entryName),
+      entryNameSeed(entryName),
     ],
     namespacesProgram.programId
   );
@@ -410,7 +411,7 @@ export async function withSetEntryData(
     [
       anchor.utils.bytes.utf8.encode(ENTRY_SEED),
       namespaceId.toBytes(),
-      anchor.utils.bytes.utf8.encode(entryName),
+      entryNameSeed(entryName),
     ],
     namespacesProgram.programId
   );
@@ -471,7 +472,7 @@ export async function withSetReverseEntry(
     [
       anchor.utils.bytes.utf8.encode(ENTRY_SEED),
       namespaceId.toBytes(),
-      anchor.utils.bytes.utf8.encode(entryName),
+      entryNameSeed(entryName),
     ],
     namespacesProgram.programId
   );
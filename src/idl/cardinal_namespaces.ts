@@ -72,10 +72,20 @@ export type Namespaces = {
       ];
     },
     {
-      name: "updateGlobalContext";
+      name: "setNamespaceFeeOverride";
       accounts: [
         {
           name: "globalContext";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "namespaceFeeOverride";
           isMut: true;
           isSigner: false;
         },
@@ -83,82 +93,114 @@ export type Namespaces = {
           name: "updateAuthority";
           isMut: false;
           isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
         {
-          name: "ix";
-          type: {
-            defined: "UpdateGlobalContextIx";
-          };
+          name: "feeBasisPoints";
+          type: "u64";
         }
       ];
     },
     {
-      name: "claimNameEntry";
+      name: "removeNamespaceFeeOverride";
       accounts: [
         {
-          name: "namespace";
-          isMut: true;
+          name: "globalContext";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "nameEntry";
+          name: "namespaceFeeOverride";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "requestor";
+          name: "updateAuthority";
           isMut: true;
           isSigner: true;
-        },
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateGlobalContext";
+      accounts: [
         {
-          name: "recipient";
+          name: "globalContext";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "payer";
-          isMut: true;
+          name: "updateAuthority";
+          isMut: false;
           isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "UpdateGlobalContextIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "listNameEntry";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "claimRequest";
-          isMut: true;
+          name: "nameEntry";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "mint";
+          name: "listing";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "namespaceTokenAccount";
+          name: "listingTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenManager";
-          isMut: true;
+          name: "mint";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenManagerTokenAccount";
+          name: "seller";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "mintCounter";
+          name: "sellerTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "recipientTokenAccount";
-          isMut: true;
+          name: "globalContext";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenManagerProgram";
+          name: "namespaceFeeOverride";
           isMut: false;
           isSigner: false;
         },
@@ -168,7 +210,7 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "associatedToken";
+          name: "associatedTokenProgram";
           isMut: false;
           isSigner: false;
         },
@@ -185,166 +227,150 @@ export type Namespaces = {
       ];
       args: [
         {
-          name: "ix";
-          type: {
-            defined: "ClaimNameEntryIx";
-          };
+          name: "price";
+          type: "u64";
         }
       ];
     },
     {
-      name: "initNameEntryMint";
+      name: "delistNameEntry";
       accounts: [
         {
-          name: "namespace";
-          isMut: false;
+          name: "listing";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "nameEntry";
+          name: "listingTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "payer";
+          name: "seller";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "namespaceTokenAccount";
+          name: "sellerTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "mint";
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "buyNameEntry";
+      accounts: [
+        {
+          name: "namespace";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "mintMetadata";
+          name: "nameEntry";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "masterEdition";
+          name: "listing";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
-          isMut: false;
+          name: "listingTokenAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "mint";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedToken";
-          isMut: false;
+          name: "seller";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "rent";
-          isMut: false;
+          name: "sellerPaymentTokenAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "reverseEntry";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "initNameEntry";
-      accounts: [
+        },
         {
-          name: "namespace";
+          name: "tokenManager";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "nameEntry";
-          isMut: true;
+          name: "timeInvalidator";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "payer";
+          name: "buyer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "systemProgram";
-          isMut: false;
+          name: "buyerTokenAccount";
+          isMut: true;
           isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "ix";
-          type: {
-            defined: "InitNameEntryIx";
-          };
-        }
-      ];
-    },
-    {
-      name: "invalidateExpiredNameEntry";
-      accounts: [
+        },
         {
-          name: "namespace";
+          name: "buyerPaymentTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "nameEntry";
+          name: "namespacePaymentTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "namespaceTokenAccount";
-          isMut: true;
+          name: "globalContext";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "invalidator";
-          isMut: true;
+          name: "namespaceFeeOverride";
+          isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "invalidateTransferableNameEntry";
-      accounts: [
+        },
         {
-          name: "namespace";
-          isMut: true;
+          name: "tokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "nameEntry";
-          isMut: true;
+          name: "associatedTokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenManager";
+          name: "rent";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "invalidator";
-          isMut: true;
+          name: "systemProgram";
+          isMut: false;
           isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "revokeNameEntry";
+      name: "claimNameEntry";
       accounts: [
         {
           name: "namespace";
@@ -357,17 +383,22 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "claimRequest";
+          name: "requestor";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "invalidator";
-          isMut: false;
+          name: "recipient";
+          isMut: true;
           isSigner: true;
         },
         {
-          name: "tokenManager";
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "claimRequest";
           isMut: true;
           isSigner: false;
         },
@@ -376,11 +407,26 @@ export type Namespaces = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "namespaceTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "tokenManagerTokenAccount";
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "mintCounter";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "recipientTokenAccount";
           isMut: true;
@@ -396,20 +442,37 @@ export type Namespaces = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "associatedToken";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "rent";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "ClaimNameEntryIx";
+          };
         }
       ];
-      args: [];
     },
     {
-      name: "setNameEntryData";
+      name: "claimNameEntryV2";
       accounts: [
         {
           name: "namespace";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
@@ -418,157 +481,122 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "userTokenAccount";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "tokenManager";
-          isMut: false;
-          isSigner: false;
+          name: "requestor";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "user";
+          name: "recipient";
           isMut: true;
           isSigner: true;
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "updateNameEntryMintMetadata";
-      accounts: [
-        {
-          name: "namespace";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "nameEntry";
-          isMut: false;
-          isSigner: false;
         },
         {
-          name: "updateAuthority";
-          isMut: false;
+          name: "payer";
+          isMut: true;
           isSigner: true;
         },
         {
-          name: "mintMetadata";
+          name: "claimRequest";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
-          isMut: false;
+          name: "mint";
+          isMut: true;
           isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "ix";
-          type: {
-            defined: "UpdateNameEntryMintMetadataIx";
-          };
-        }
-      ];
-    },
-    {
-      name: "closeNameEntry";
-      accounts: [
+        },
         {
-          name: "namespace";
+          name: "namespaceTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "invalidator";
+          name: "tokenManager";
           isMut: true;
           isSigner: false;
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "migrateNameEntryMint";
-      accounts: [
+        },
         {
-          name: "namespace";
+          name: "tokenManagerTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "nameEntry";
+          name: "mintCounter";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "namespaceTokenAccount";
+          name: "recipientTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
+          name: "mintEdition";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "namespaceCertificateTokenAccount";
-          isMut: true;
+          name: "paymentMint";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "mint";
-          isMut: true;
-          isSigner: true;
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mintMetadata";
-          isMut: true;
+          name: "timeInvalidator";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "masterEdition";
-          isMut: true;
+          name: "payerTokenAccount";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "mintCounter";
-          isMut: true;
+          name: "paymentTokenAccount";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenManager";
-          isMut: true;
+          name: "feeCollectorTokenAccount";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenManagerTokenAccount";
-          isMut: true;
+          name: "globalContext";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "recipientTokenAccount";
-          isMut: true;
+          name: "namespaceFeeOverride";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "claimRequest";
-          isMut: true;
+          name: "referrer";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
+          name: "referrerTokenAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "gateTokenAccount";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedToken";
+          name: "gateMetadata";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "walletEntryCounter";
           isMut: false;
           isSigner: false;
         },
@@ -577,6 +605,31 @@ export type Namespaces = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "timeInvalidatorProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManagerProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedToken";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "rent";
           isMut: false;
@@ -592,68 +645,88 @@ export type Namespaces = {
         {
           name: "ix";
           type: {
-            defined: "MigrateNameEntryMintIx";
+            defined: "ClaimNameEntryIx";
           };
         }
       ];
     },
     {
-      name: "collectNamespaceFunds";
+      name: "initNameEntryMint";
       accounts: [
         {
-          name: "globalContext";
+          name: "namespace";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "globalContextPaymentAccount";
+          name: "nameEntry";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "namespace";
-          isMut: false;
-          isSigner: false;
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "namespacePaymentAccount";
+          name: "namespaceTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "rentAuthority";
-          isMut: false;
+          name: "mint";
+          isMut: true;
           isSigner: true;
         },
         {
-          name: "rentAuthorityTokenAccount";
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "masterEdition";
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [
+        },
         {
-          name: "amount";
-          type: "u64";
+          name: "associatedToken";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "createNamespace";
+      name: "initNameEntry";
       accounts: [
         {
           name: "namespace";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "authority";
-          isMut: false;
+          name: "nameEntry";
+          isMut: true;
           isSigner: false;
         },
         {
@@ -671,13 +744,13 @@ export type Namespaces = {
         {
           name: "ix";
           type: {
-            defined: "CreateNamespaceIx";
+            defined: "InitNameEntryIx";
           };
         }
       ];
     },
     {
-      name: "updateNamespace";
+      name: "invalidateExpiredNameEntry";
       accounts: [
         {
           name: "namespace";
@@ -685,160 +758,104 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "updateAuthority";
-          isMut: false;
-          isSigner: true;
-        }
-      ];
-      args: [
+          name: "nameEntry";
+          isMut: true;
+          isSigner: false;
+        },
         {
-          name: "ix";
-          type: {
-            defined: "UpdateNamespaceIx";
-          };
+          name: "namespaceTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "invalidator";
+          isMut: true;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "createClaimRequest";
+      name: "invalidateTransferableNameEntry";
       accounts: [
         {
           name: "namespace";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "payer";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "claimRequest";
+          name: "nameEntry";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "tokenManager";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "entryName";
-          type: "string";
-        },
-        {
-          name: "claimRequestBump";
-          type: "u8";
         },
         {
-          name: "user";
-          type: "publicKey";
+          name: "invalidator";
+          isMut: true;
+          isSigner: false;
         }
       ];
+      args: [];
     },
     {
-      name: "updateClaimRequest";
+      name: "revokeNameEntry";
       accounts: [
+        {
+          name: "namespace";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "nameEntry";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "namespace";
-          isMut: false;
+          name: "claimRequest";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "approveAuthority";
+          name: "invalidator";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "rentRequest";
+          name: "tokenManager";
           isMut: true;
           isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "isApproved";
-          type: "bool";
-        }
-      ];
-    },
-    {
-      name: "approveClaimRequest";
-      accounts: [
-        {
-          name: "namespace";
-          isMut: false;
-          isSigner: false;
         },
         {
-          name: "payer";
+          name: "mint";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "claimRequest";
+          name: "tokenManagerTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "nameEntry";
+          name: "recipientTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "approveAuthority";
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "systemProgram";
+          name: "tokenManagerProgram";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "entryName";
-          type: "string";
         },
         {
-          name: "user";
-          type: "publicKey";
-        }
-      ];
-    },
-    {
-      name: "invalidateExpiredReverseEntry";
-      accounts: [
-        {
-          name: "namespace";
+          name: "tokenProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "nameEntry";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "reverseNameEntry";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "namespaceTokenAccount";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "invalidator";
+          name: "rent";
           isMut: false;
           isSigner: false;
         }
@@ -846,7 +863,7 @@ export type Namespaces = {
       args: [];
     },
     {
-      name: "invalidateTransferableReverseEntry";
+      name: "setNameEntryData";
       accounts: [
         {
           name: "namespace";
@@ -859,8 +876,8 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "reverseNameEntry";
-          isMut: true;
+          name: "userTokenAccount";
+          isMut: false;
           isSigner: false;
         },
         {
@@ -869,15 +886,15 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "invalidator";
+          name: "user";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         }
       ];
       args: [];
     },
     {
-      name: "revokeReverseEntry";
+      name: "updateNameEntryMintMetadata";
       accounts: [
         {
           name: "namespace";
@@ -890,25 +907,32 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "reverseEntry";
+          name: "updateAuthority";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "claimRequest";
+          name: "mintMetadata";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "invalidator";
+          name: "tokenMetadataProgram";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "UpdateNameEntryMintMetadataIx";
+          };
         }
       ];
-      args: [];
     },
     {
-      name: "setNamespaceReverseEntry";
+      name: "syncNameEntryMintMetadata";
       accounts: [
         {
           name: "namespace";
@@ -917,48 +941,44 @@ export type Namespaces = {
         },
         {
           name: "nameEntry";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "reverseEntry";
+          name: "mintMetadata";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userTokenAccount";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "tokenManager";
+          name: "tokenMetadataProgram";
           isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeNameEntry";
+      accounts: [
         {
-          name: "user";
+          name: "namespace";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "payer";
+          name: "invalidator";
           isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "systemProgram";
-          isMut: false;
           isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "setGlobalReverseEntry";
+      name: "migrateNameEntryMint";
       accounts: [
         {
           name: "namespace";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
@@ -967,93 +987,77 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "reverseNameEntry";
+          name: "namespaceTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userNameEntryMintTokenAccount";
-          isMut: false;
-          isSigner: false;
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "tokenManager";
+          name: "namespaceCertificateTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "user";
+          name: "mint";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "payer";
+          name: "mintMetadata";
           isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "initEntry";
-      accounts: [
-        {
-          name: "namespace";
-          isMut: false;
           isSigner: false;
         },
         {
-          name: "entry";
+          name: "masterEdition";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "payer";
+          name: "mintCounter";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "namespaceCertificateTokenAccount";
+          name: "tokenManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "mintManager";
+          name: "tokenManagerTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "certificateMint";
+          name: "recipientTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "certificateMintMetadata";
+          name: "claimRequest";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "certificateProgram";
+          name: "tokenMetadataProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
+          name: "tokenProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "associatedToken";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedToken";
+          name: "tokenManagerProgram";
           isMut: false;
           isSigner: false;
         },
@@ -1072,104 +1076,126 @@ export type Namespaces = {
         {
           name: "ix";
           type: {
-            defined: "InitEntryIx";
+            defined: "MigrateNameEntryMintIx";
           };
         }
       ];
     },
     {
-      name: "claimEntry";
+      name: "collectNamespaceFunds";
       accounts: [
         {
-          name: "namespace";
-          isMut: true;
+          name: "globalContext";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "entry";
+          name: "globalContextPaymentAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "user";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
+          name: "namespaceFeeOverride";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "claimRequest";
+          name: "namespace";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "paymentMint";
+          name: "namespacePaymentAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "namespaceCertificateTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "rentAuthority";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "mintManager";
+          name: "rentAuthorityTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "certificate";
-          isMut: true;
+          name: "tokenProgram";
+          isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "certificateMint";
-          isMut: true;
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "collectAllNamespaceFunds";
+      accounts: [
+        {
+          name: "globalContext";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "certificateTokenAccount";
+          name: "globalContextPaymentAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "certificatePaymentTokenAccount";
-          isMut: true;
+          name: "namespaceFeeOverride";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "userCertificateTokenAccount";
+          name: "namespace";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userPaymentTokenAccount";
+          name: "namespacePaymentAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "certificateProgram";
+          name: "rentAuthority";
           isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "rentAuthorityTokenAccount";
+          isMut: true;
           isSigner: false;
         },
         {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createNamespace";
+      accounts: [
         {
-          name: "associatedToken";
-          isMut: false;
+          name: "namespace";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "rent";
+          name: "authority";
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
         {
           name: "systemProgram";
           isMut: false;
@@ -1180,44 +1206,169 @@ export type Namespaces = {
         {
           name: "ix";
           type: {
-            defined: "ClaimEntryIx";
+            defined: "CreateNamespaceIx";
           };
         }
       ];
     },
     {
-      name: "setEntryData";
+      name: "distributeNamespaceFunds";
       accounts: [
+        {
+          name: "globalContext";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "globalContextPaymentAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "namespaceFeeOverride";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "namespace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "namespacePaymentAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initNamespaceCollection";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "entry";
+          name: "updateAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "namespaceTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userCertificateTokenAccount";
+          name: "collectionMint";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "collectionMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collectionMasterEdition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "certificate";
+          name: "tokenProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "user";
+          name: "associatedToken";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setEmojiAllowlist";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "emojiAllowlist";
           isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "updateAuthority";
+          isMut: false;
           isSigner: true;
         },
         {
           name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "sequences";
+          type: {
+            vec: "string";
+          };
+        }
+      ];
+    },
+    {
+      name: "updateNamespace";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "updateAuthority";
           isMut: false;
           isSigner: true;
         },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
         {
           name: "systemProgram";
           isMut: false;
@@ -1226,13 +1377,15 @@ export type Namespaces = {
       ];
       args: [
         {
-          name: "data";
-          type: "publicKey";
+          name: "ix";
+          type: {
+            defined: "UpdateNamespaceIx";
+          };
         }
       ];
     },
     {
-      name: "setReverseEntry";
+      name: "makeNameEntryOffer";
       accounts: [
         {
           name: "namespace";
@@ -1240,34 +1393,49 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "entry";
-          isMut: true;
+          name: "nameEntry";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "reverseEntry";
+          name: "offer";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userCertificateTokenAccount";
-          isMut: false;
+          name: "offerTokenAccount";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "certificate";
+          name: "paymentMint";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "user";
+          name: "buyer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "payer";
+          name: "buyerPaymentTokenAccount";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
         },
         {
           name: "systemProgram";
@@ -1277,13 +1445,15 @@ export type Namespaces = {
       ];
       args: [
         {
-          name: "reverseEntryBump";
-          type: "u8";
+          name: "ix";
+          type: {
+            defined: "MakeNameEntryOfferIx";
+          };
         }
       ];
     },
     {
-      name: "revokeEntry";
+      name: "acceptNameEntryOffer";
       accounts: [
         {
           name: "namespace";
@@ -1291,67 +1461,77 @@ export type Namespaces = {
           isSigner: false;
         },
         {
-          name: "entry";
+          name: "nameEntry";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "claimRequest";
+          name: "offer";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "namespaceCertificateTokenAccount";
+          name: "offerTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "namespacePaymentTokenAccount";
-          isMut: true;
+          name: "mint";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "invalidator";
-          isMut: false;
-          isSigner: true;
+          name: "buyer";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mintManager";
+          name: "buyerTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "certificate";
+          name: "holderTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "certificateMint";
-          isMut: true;
+          name: "tokenManager";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "certificateTokenAccount";
-          isMut: true;
+          name: "timeInvalidator";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "certificatePaymentTokenAccount";
+          name: "holder";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "userCertificateTokenAccount";
+          name: "holderPaymentTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userPaymentTokenAccount";
+          name: "reverseEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "namespacePaymentTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "certificateProgram";
+          name: "globalContext";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "namespaceFeeOverride";
           isMut: false;
           isSigner: false;
         },
@@ -1359,2055 +1539,6524 @@ export type Namespaces = {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [];
-    }
-  ];
-  accounts: [
+    },
     {
-      name: "globalContext";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "updateAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "rentAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "feeBasisPoints";
-            type: "u64";
-          }
-        ];
-      };
-    },
-    {
-      name: "namespace";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "updateAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "rentAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "approveAuthority";
-            type: {
-              option: "publicKey";
-            };
-          },
-          {
-            name: "schema";
-            type: "u8";
-          },
-          {
-            name: "paymentAmountDaily";
-            type: "u64";
-          },
-          {
-            name: "paymentMint";
-            type: "publicKey";
-          },
-          {
-            name: "minRentalSeconds";
-            type: "i64";
-          },
-          {
-            name: "maxRentalSeconds";
-            type: {
-              option: "i64";
-            };
-          },
-          {
-            name: "transferableEntries";
-            type: "bool";
-          },
-          {
-            name: "count";
-            type: "u32";
-          },
-          {
-            name: "limit";
-            type: {
-              option: "u32";
-            };
-          },
-          {
-            name: "maxExpiration";
-            type: {
-              option: "i64";
-            };
-          },
-          {
-            name: "invalidationType";
-            type: "u8";
-          }
-        ];
-      };
+      name: "cancelNameEntryOffer";
+      accounts: [
+        {
+          name: "offer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "offerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "claimRequest";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "requestor";
-            type: "publicKey";
-          },
-          {
-            name: "isApproved";
-            type: "bool";
-          },
-          {
-            name: "namespace";
-            type: "publicKey";
-          },
-          {
-            name: "entryName";
-            type: "string";
-          },
-          {
-            name: "counter";
-            type: "u32";
-          }
-        ];
-      };
+      name: "setPrimaryName";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nameEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reverseEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "primaryName";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "timeInvalidator";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "entry";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "namespace";
-            type: "publicKey";
-          },
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "data";
-            type: {
-              option: "publicKey";
-            };
-          },
-          {
-            name: "reverseEntry";
-            type: {
-              option: "publicKey";
-            };
-          },
-          {
-            name: "mint";
-            type: "publicKey";
-          },
-          {
-            name: "isClaimed";
-            type: "bool";
-          },
-          {
-            name: "claimRequestCounter";
-            type: "u32";
-          }
-        ];
-      };
+      name: "closePrimaryName";
+      accounts: [
+        {
+          name: "primaryName";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
     },
     {
-      name: "reverseEntry";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "entryName";
-            type: "string";
-          },
-          {
-            name: "namespaceName";
-            type: "string";
-          }
-        ];
-      };
-    }
-  ];
-  types: [
-    {
-      name: "ClaimEntryIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "duration";
-            type: {
-              option: "i64";
-            };
-          },
-          {
-            name: "certificateBump";
-            type: "u8";
-          }
-        ];
-      };
+      name: "resolvePrimaryName";
+      accounts: [
+        {
+          name: "primaryName";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nameEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reverseEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "timeInvalidator";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "InitEntryIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "entryBump";
-            type: "u8";
-          },
-          {
-            name: "mintManagerBump";
-            type: "u8";
-          }
-        ];
-      };
+      name: "initReferrer";
+      accounts: [
+        {
+          name: "referrer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "InitGlobalContextIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "feeBasisPoints";
-            type: "u64";
-          }
-        ];
-      };
+      name: "createClaimRequest";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "entryName";
+          type: "string";
+        },
+        {
+          name: "claimRequestBump";
+          type: "u8";
+        },
+        {
+          name: "user";
+          type: "publicKey";
+        }
+      ];
     },
     {
-      name: "UpdateGlobalContextIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "updateAuthority";
-            type: {
-              option: "publicKey";
-            };
-          },
-          {
-            name: "rentAuthority";
-            type: {
-              option: "publicKey";
-            };
-          },
-          {
-            name: "feeBasisPoints";
-            type: {
-              option: "u64";
-            };
-          }
-        ];
-      };
+      name: "updateClaimRequest";
+      accounts: [
+        {
+          name: "nameEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "approveAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "rentRequest";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "isApproved";
+          type: "bool";
+        }
+      ];
     },
     {
-      name: "ClaimNameEntryIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "duration";
-            type: {
-              option: "i64";
-            };
-          }
-        ];
-      };
+      name: "approveClaimRequest";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nameEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "approveAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "entryName";
+          type: "string";
+        },
+        {
+          name: "user";
+          type: "publicKey";
+        },
+        {
+          name: "expiresAt";
+          type: {
+            option: "i64";
+          };
+        }
+      ];
     },
     {
-      name: "Creator";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "address";
-            type: "publicKey";
-          },
-          {
-            name: "verified";
-            type: "bool";
-          },
-          {
-            name: "share";
-            type: "u8";
-          }
-        ];
-      };
+      name: "approveClaimRequestWithProof";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "allowlistClaim";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nameEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "entryName";
+          type: "string";
+        },
+        {
+          name: "user";
+          type: "publicKey";
+        },
+        {
+          name: "proof";
+          type: {
+            vec: {
+              array: ["u8", 32];
+            };
+          };
+        }
+      ];
     },
     {
-      name: "InitNameEntryIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "name";
-            type: "string";
-          }
-        ];
-      };
+      name: "approveClaimRequestWithSignature";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nameEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "entryName";
+          type: "string";
+        },
+        {
+          name: "user";
+          type: "publicKey";
+        },
+        {
+          name: "expiresAt";
+          type: "i64";
+        }
+      ];
     },
     {
-      name: "MigrateNameEntryMintIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "duration";
-            type: {
-              option: "i64";
-            };
-          }
-        ];
-      };
+      name: "approveClaimRequests";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "approveAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "ApproveClaimRequestsIx";
+          };
+        }
+      ];
     },
     {
-      name: "UpdateNameEntryMintMetadataIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "sellerFeeBasisPoints";
-            docs: [
-              "Royalty basis points that goes to creators in secondary sales (0-10000)"
-            ];
-            type: "u16";
-          },
-          {
-            name: "creators";
-            docs: ["Array of creators, optional"];
-            type: {
-              option: {
-                vec: {
-                  defined: "Creator";
-                };
-              };
-            };
-          },
-          {
-            name: "primarySaleHappened";
-            type: {
-              option: "bool";
-            };
-          }
-        ];
-      };
+      name: "migrateClaimRequest";
+      accounts: [
+        {
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "CreateNamespaceIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "updateAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "rentAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "approveAuthority";
-            type: {
-              option: "publicKey";
-            };
-          },
-          {
-            name: "schema";
-            type: "u8";
-          },
-          {
-            name: "paymentAmountDaily";
-            type: "u64";
-          },
-          {
-            name: "paymentMint";
-            type: "publicKey";
-          },
-          {
-            name: "minRentalSeconds";
-            type: "i64";
-          },
-          {
-            name: "maxRentalSeconds";
-            type: {
-              option: "i64";
-            };
-          },
-          {
-            name: "transferableEntries";
-            type: "bool";
-          },
-          {
-            name: "limit";
-            type: {
-              option: "u32";
-            };
-          },
-          {
-            name: "maxExpiration";
-            type: {
-              option: "i64";
-            };
-          },
-          {
-            name: "invalidationType";
-            type: "u8";
-          }
-        ];
-      };
-    },
-    {
-      name: "UpdateNamespaceIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "updateAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "rentAuthority";
-            type: "publicKey";
-          },
-          {
-            name: "approveAuthority";
-            type: {
-              option: "publicKey";
-            };
-          },
-          {
-            name: "schema";
-            type: "u8";
-          },
-          {
-            name: "paymentAmountDaily";
-            type: "u64";
-          },
-          {
-            name: "paymentMint";
-            type: "publicKey";
-          },
-          {
-            name: "minRentalSeconds";
-            type: "i64";
-          },
-          {
-            name: "maxRentalSeconds";
-            type: {
-              option: "i64";
-            };
-          },
-          {
-            name: "transferableEntries";
-            type: "bool";
-          },
-          {
-            name: "limit";
-            type: {
-              option: "u32";
-            };
-          },
-          {
-            name: "maxExpiration";
-            type: {
-              option: "i64";
-            };
-          },
-          {
-            name: "invalidationType";
-            type: "u8";
-          }
-        ];
-      };
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "InvalidOwnerMint";
-      msg: "Owner mint is invalid";
-    },
-    {
-      code: 6001;
-      name: "EntryNotExpired";
-      msg: "Entry has not expired";
-    },
-    {
-      code: 6002;
-      name: "RentalDurationTooSmall";
-      msg: "Rental duration too small try adding more funds";
+      name: "rejectClaimRequest";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "approveAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      code: 6003;
-      name: "RentalDurationTooLarge";
-      msg: "Rental duration too large try adding less funds";
+      name: "withdrawClaimRequest";
+      accounts: [
+        {
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "requestor";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      code: 6004;
-      name: "NamespaceRequiresDuration";
-      msg: "Namespace requires duration";
+      name: "closeExpiredClaimRequest";
+      accounts: [
+        {
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      code: 6005;
-      name: "InvalidAuthority";
-      msg: "Authority is invalid";
+      name: "invalidateExpiredReverseEntry";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nameEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reverseNameEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "namespaceTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "invalidator";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      code: 6006;
-      name: "InvalidAuthorityTokenAccount";
-      msg: "Invalid authorty token account";
+      name: "invalidateTransferableReverseEntry";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nameEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reverseNameEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "invalidator";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      code: 6007;
-      name: "InvalidRequestor";
-      msg: "Invalid requestor";
+      name: "revokeReverseEntry";
+      accounts: [
+        {
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nameEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reverseEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "invalidator";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
     },
     {
-      code: 6008;
-      name: "InvalidNamespacePaymentAccount";
-      msg: "Invalid namespace payment account";
-    },
-    {
-      code: 6009;
-      name: "InvalidGlobalContextPaymentAccount";
-      msg: "Invalid global context payment account";
-    },
-    {
-      code: 6010;
-      name: "InvalidUserTokenAccount";
-      msg: "Invalid user token account";
-    },
-    {
-      code: 6011;
-      name: "InvalidNamespace";
-      msg: "Invalid namespace";
-    },
-    {
-      code: 6012;
-      name: "InvalidEntry";
-      msg: "Invalid entry";
-    },
-    {
-      code: 6013;
-      name: "InvalidPaymentMint";
-      msg: "Invalid payment mint";
-    },
-    {
-      code: 6014;
-      name: "InvalidReverseEntry";
-      msg: "Invalid reverse entry";
-    },
-    {
-      code: 6015;
-      name: "ClaimNotAllowed";
-      msg: "Claim not allowed";
-    },
-    {
-      code: 6016;
-      name: "InvalidUpdateAuthority";
-      msg: "Invalid update authority";
-    },
-    {
-      code: 6017;
-      name: "InvalidApproveAuthority";
-      msg: "Invalid approve authority";
-    },
-    {
-      code: 6018;
-      name: "NamespaceRequiresToken";
-      msg: "Namespace requires token";
-    },
-    {
-      code: 6019;
-      name: "MintAlreadyInitialized";
-      msg: "Mint already initialized";
-    },
-    {
-      code: 6020;
-      name: "InvalidEntryMint";
-      msg: "Mint invalid for entry";
-    },
-    {
-      code: 6021;
-      name: "InvalidTimeInvalidatorProgramId";
-      msg: "Time invalidator program ID is invalid";
-    },
-    {
-      code: 6022;
-      name: "InvalidTokenManager";
-      msg: "Invalid token manager";
-    },
-    {
-      code: 6023;
-      name: "NameEntryAlreadyClaimed";
-      msg: "Name Entry already claimed";
-    },
-    {
-      code: 6024;
-      name: "InvalidCertificate";
-      msg: "Invalid certificate";
-    },
-    {
-      code: 6025;
-      name: "NamespaceReachedLimit";
-      msg: "Namespace has reached the limit";
-    },
-    {
-      code: 6026;
-      name: "InvalidInvalidationType";
-      msg: "Namespace has invalid invalidation type";
-    },
-    {
-      code: 6027;
-      name: "InvalidReverseEntryForNameEntry";
-      msg: "Invalid reverse entry for name entry";
-    }
-  ];
-};
-
-export const IDL: Namespaces = {
-  version: "4.1.62",
-  name: "namespaces",
-  instructions: [
-    {
-      name: "collectGlobalContextFunds",
+      name: "setNamespaceReverseEntry";
       accounts: [
         {
-          name: "globalContext",
-          isMut: false,
-          isSigner: false,
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "globalContextPaymentAccount",
-          isMut: true,
-          isSigner: false,
+          name: "nameEntry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "rentAuthority",
-          isMut: false,
-          isSigner: true,
+          name: "reverseEntry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "authorityTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "userTokenAccount";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "amount",
-          type: "u64",
+          name: "user";
+          isMut: true;
+          isSigner: true;
         },
-      ],
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "initGlobalContext",
+      name: "setGlobalReverseEntry";
       accounts: [
         {
-          name: "globalContext",
-          isMut: true,
-          isSigner: false,
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
+          name: "nameEntry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "reverseNameEntry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "userNameEntryMintTokenAccount";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "InitGlobalContextIx",
-          },
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-    },
-    {
-      name: "updateGlobalContext",
-      accounts: [
         {
-          name: "globalContext",
-          isMut: true,
-          isSigner: false,
+          name: "user";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "updateAuthority",
-          isMut: false,
-          isSigner: true,
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "UpdateGlobalContextIx",
-          },
-        },
-      ],
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "claimNameEntry",
+      name: "initSublease";
       accounts: [
         {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
+          name: "nameEntry";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "requestor",
-          isMut: true,
-          isSigner: true,
+          name: "sublease";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "recipient",
-          isMut: true,
-          isSigner: true,
+          name: "subleaseTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "mint";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "claimRequest",
-          isMut: true,
-          isSigner: false,
+          name: "holderTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mint",
-          isMut: true,
-          isSigner: false,
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "namespaceTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "holder";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "tokenManager",
-          isMut: true,
-          isSigner: false,
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenManagerTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mintCounter",
-          isMut: true,
-          isSigner: false,
+          name: "rent";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "recipientTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
         {
-          name: "tokenManagerProgram",
-          isMut: false,
-          isSigner: false,
-        },
+          name: "ix";
+          type: {
+            defined: "InitSubleaseIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "rentSublease";
+      accounts: [
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "nameEntry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "associatedToken",
-          isMut: false,
-          isSigner: false,
+          name: "sublease";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "holder";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "holderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "ClaimNameEntryIx",
-          },
+          name: "reverseEntry";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-    },
-    {
-      name: "initNameEntryMint",
-      accounts: [
         {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
+          name: "timeInvalidator";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "lessee";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "namespaceTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "lesseePaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mint",
-          isMut: true,
-          isSigner: true,
-        },
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setSubleaseReverseNameEntry";
+      accounts: [
         {
-          name: "mintMetadata",
-          isMut: true,
-          isSigner: false,
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "masterEdition",
-          isMut: true,
-          isSigner: false,
+          name: "nameEntry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "reverseEntry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "sublease";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "associatedToken",
-          isMut: false,
-          isSigner: false,
+          name: "user";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "initNameEntry",
+      name: "endSublease";
       accounts: [
         {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
+          name: "nameEntry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
+          name: "sublease";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "lessee";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "reverseEntry";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "InitNameEntryIx",
-          },
-        },
-      ],
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
     },
     {
-      name: "invalidateExpiredNameEntry",
+      name: "closeSublease";
       accounts: [
         {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
+          name: "sublease";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
+          name: "subleaseTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "namespaceTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "holder";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "invalidator",
-          isMut: true,
-          isSigner: false,
+          name: "holderTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-      args: [],
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "invalidateTransferableNameEntry",
+      name: "initEntry";
       accounts: [
         {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
+          name: "entry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenManager",
-          isMut: false,
-          isSigner: false,
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "invalidator",
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [],
-    },
-    {
-      name: "revokeNameEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
+          name: "namespaceCertificateTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "claimRequest",
-          isMut: true,
-          isSigner: false,
+          name: "certificateMint";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "invalidator",
-          isMut: false,
-          isSigner: true,
+          name: "certificateMintMetadata";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenManager",
-          isMut: true,
-          isSigner: false,
+          name: "certificateProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mint",
-          isMut: true,
-          isSigner: false,
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenManagerTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "recipientTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "associatedToken";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenManagerProgram",
-          isMut: false,
-          isSigner: false,
+          name: "rent";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+          name: "ix";
+          type: {
+            defined: "InitEntryIx";
+          };
+        }
+      ];
     },
     {
-      name: "setNameEntryData",
+      name: "claimEntry";
       accounts: [
         {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
+          name: "namespace";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
+          name: "entry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "userTokenAccount",
-          isMut: false,
-          isSigner: false,
+          name: "user";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "tokenManager",
-          isMut: false,
-          isSigner: false,
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-      args: [],
-    },
-    {
-      name: "updateNameEntryMintMetadata",
-      accounts: [
         {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
+          name: "paymentMint";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "nameEntry",
-          isMut: false,
-          isSigner: false,
+          name: "namespaceCertificateTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "updateAuthority",
-          isMut: false,
-          isSigner: true,
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mintMetadata",
-          isMut: true,
-          isSigner: false,
+          name: "certificate";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "certificateMint";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "UpdateNameEntryMintMetadataIx",
-          },
+          name: "certificateTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-    },
-    {
-      name: "closeNameEntry",
-      accounts: [
         {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
+          name: "certificatePaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "invalidator",
-          isMut: true,
-          isSigner: false,
+          name: "userCertificateTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-      args: [],
-    },
-    {
-      name: "migrateNameEntryMint",
-      accounts: [
         {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
+          name: "userPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
+          name: "certificateProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "namespaceTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "associatedToken";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "namespaceCertificateTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "rent";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mint",
-          isMut: true,
-          isSigner: true,
-        },
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
         {
-          name: "mintMetadata",
-          isMut: true,
-          isSigner: false,
-        },
+          name: "ix";
+          type: {
+            defined: "ClaimEntryIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "setEntryData";
+      accounts: [
         {
-          name: "masterEdition",
-          isMut: true,
-          isSigner: false,
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mintCounter",
-          isMut: true,
-          isSigner: false,
+          name: "entry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenManager",
-          isMut: true,
-          isSigner: false,
+          name: "userCertificateTokenAccount";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenManagerTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "recipientTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "claimRequest",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "associatedToken",
-          isMut: false,
-          isSigner: false,
+          name: "certificate";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenManagerProgram",
-          isMut: false,
-          isSigner: false,
+          name: "user";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "payer";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
       args: [
         {
-          name: "ix",
-          type: {
-            defined: "MigrateNameEntryMintIx",
-          },
-        },
-      ],
+          name: "data";
+          type: "publicKey";
+        }
+      ];
     },
     {
-      name: "collectNamespaceFunds",
+      name: "setReverseEntry";
       accounts: [
         {
-          name: "globalContext",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "globalContextPaymentAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "namespacePaymentAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "rentAuthority",
-          isMut: false,
-          isSigner: true,
+          name: "namespace";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "rentAuthorityTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "entry";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "reverseEntry";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "amount",
-          type: "u64",
+          name: "userCertificateTokenAccount";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-    },
-    {
-      name: "createNamespace",
-      accounts: [
         {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
+          name: "certificate";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "authority",
-          isMut: false,
-          isSigner: false,
+          name: "user";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
       args: [
         {
-          name: "ix",
-          type: {
-            defined: "CreateNamespaceIx",
-          },
-        },
-      ],
+          name: "reverseEntryBump";
+          type: "u8";
+        }
+      ];
     },
     {
-      name: "updateNamespace",
+      name: "revokeEntry";
       accounts: [
         {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
+          name: "namespace";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "updateAuthority",
-          isMut: false,
-          isSigner: true,
+          name: "entry";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "UpdateNamespaceIx",
-          },
+          name: "claimRequest";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-    },
-    {
-      name: "createClaimRequest",
-      accounts: [
         {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
+          name: "namespaceCertificateTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "namespacePaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "claimRequest",
-          isMut: true,
-          isSigner: false,
+          name: "invalidator";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "entryName",
-          type: "string",
+          name: "certificate";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "claimRequestBump",
-          type: "u8",
+          name: "certificateMint";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "user",
-          type: "publicKey",
+          name: "certificateTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-    },
-    {
-      name: "updateClaimRequest",
-      accounts: [
         {
-          name: "nameEntry",
-          isMut: false,
-          isSigner: false,
+          name: "certificatePaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
+          name: "userCertificateTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "approveAuthority",
-          isMut: false,
-          isSigner: true,
+          name: "userPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "rentRequest",
-          isMut: true,
-          isSigner: false,
+          name: "certificateProgram";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "isApproved",
-          type: "bool",
-        },
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "globalContext";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "updateAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "rentAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "feeBasisPoints";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "namespaceFeeOverride";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "feeBasisPoints";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "namespace";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "updateAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "rentAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "approveAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "schema";
+            type: "u8";
+          },
+          {
+            name: "paymentAmountDaily";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "minRentalSeconds";
+            type: "i64";
+          },
+          {
+            name: "maxRentalSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "transferableEntries";
+            type: "bool";
+          },
+          {
+            name: "count";
+            type: "u32";
+          },
+          {
+            name: "limit";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "maxExpiration";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "invalidationType";
+            type: "u8";
+          },
+          {
+            name: "collectionMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "metadataUriTemplate";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "metadataSymbol";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "metadataNameFormat";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "sellerFeeBasisPoints";
+            type: "u16";
+          },
+          {
+            name: "creators";
+            type: {
+              option: {
+                vec: {
+                  defined: "Creator";
+                };
+              };
+            };
+          },
+          {
+            name: "referralBasisPoints";
+            type: "u16";
+          },
+          {
+            name: "beneficiaries";
+            type: {
+              option: {
+                vec: {
+                  defined: "Beneficiary";
+                };
+              };
+            };
+          },
+          {
+            name: "accruedProtocolFees";
+            type: "u64";
+          },
+          {
+            name: "accountedBalance";
+            type: "u64";
+          },
+          {
+            name: "allowlistRoot";
+            type: {
+              option: {
+                array: ["u8", 32];
+              };
+            };
+          },
+          {
+            name: "gate";
+            type: {
+              option: {
+                defined: "Gate";
+              };
+            };
+          },
+          {
+            name: "maxEntriesPerWallet";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "namePolicy";
+            type: "u8";
+          },
+          {
+            name: "minNameLength";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "maxNameLength";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "paymentTiers";
+            type: {
+              option: {
+                vec: {
+                  defined: "PaymentTier";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "claimRequest";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "requestor";
+            type: "publicKey";
+          },
+          {
+            name: "isApproved";
+            type: "bool";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "entryName";
+            type: "string";
+          },
+          {
+            name: "counter";
+            type: "u32";
+          },
+          {
+            name: "payer";
+            type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "entry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "data";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "reverseEntry";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "isClaimed";
+            type: "bool";
+          },
+          {
+            name: "claimRequestCounter";
+            type: "u32";
+          },
+          {
+            name: "claimer";
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "reverseEntry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "entryName";
+            type: "string";
+          },
+          {
+            name: "namespaceName";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "referrer";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "referralCount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "allowlistClaim";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "leaf";
+            type: {
+              array: ["u8", 32];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "walletEntryCounter";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "count";
+            type: "u32";
+          }
+        ];
+      };
+    },
+    {
+      name: "listing";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "nameEntry";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "seller";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "price";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "offer";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "nameEntry";
+            type: "publicKey";
+          },
+          {
+            name: "buyer";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "sublease";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "nameEntry";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "holder";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "duration";
+            type: "i64";
+          },
+          {
+            name: "lessee";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "leaseExpiresAt";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "emojiAllowlist";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "sequences";
+            type: {
+              vec: "string";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "primaryName";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "nameEntry";
+            type: "publicKey";
+          },
+          {
+            name: "reverseEntry";
+            type: "publicKey";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "ClaimEntryIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "duration";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "certificateBump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "InitEntryIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "entryBump";
+            type: "u8";
+          },
+          {
+            name: "mintManagerBump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "InitGlobalContextIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "feeBasisPoints";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "UpdateGlobalContextIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "updateAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "rentAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "feeBasisPoints";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "ClaimNameEntryIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "duration";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "InitNameEntryIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "MigrateNameEntryMintIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "duration";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "UpdateNameEntryMintMetadataIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "sellerFeeBasisPoints";
+            docs: [
+              "Royalty basis points that goes to creators in secondary sales (0-10000)"
+            ];
+            type: "u16";
+          },
+          {
+            name: "creators";
+            docs: ["Array of creators, optional"];
+            type: {
+              option: {
+                vec: {
+                  defined: "Creator";
+                };
+              };
+            };
+          },
+          {
+            name: "primarySaleHappened";
+            type: {
+              option: "bool";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateNamespaceIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "updateAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "rentAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "approveAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "schema";
+            type: "u8";
+          },
+          {
+            name: "paymentAmountDaily";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "minRentalSeconds";
+            type: "i64";
+          },
+          {
+            name: "maxRentalSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "transferableEntries";
+            type: "bool";
+          },
+          {
+            name: "limit";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "maxExpiration";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "invalidationType";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "UpdateNamespaceIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "updateAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "rentAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "approveAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "schema";
+            type: "u8";
+          },
+          {
+            name: "paymentAmountDaily";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "minRentalSeconds";
+            type: "i64";
+          },
+          {
+            name: "maxRentalSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "transferableEntries";
+            type: "bool";
+          },
+          {
+            name: "limit";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "maxExpiration";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "invalidationType";
+            type: "u8";
+          },
+          {
+            name: "metadataUriTemplate";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "metadataSymbol";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "metadataNameFormat";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "sellerFeeBasisPoints";
+            type: "u16";
+          },
+          {
+            name: "creators";
+            type: {
+              option: {
+                vec: {
+                  defined: "Creator";
+                };
+              };
+            };
+          },
+          {
+            name: "referralBasisPoints";
+            type: "u16";
+          },
+          {
+            name: "beneficiaries";
+            type: {
+              option: {
+                vec: {
+                  defined: "Beneficiary";
+                };
+              };
+            };
+          },
+          {
+            name: "allowlistRoot";
+            type: {
+              option: {
+                array: ["u8", 32];
+              };
+            };
+          },
+          {
+            name: "gate";
+            type: {
+              option: {
+                defined: "Gate";
+              };
+            };
+          },
+          {
+            name: "maxEntriesPerWallet";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "namePolicy";
+            type: "u8";
+          },
+          {
+            name: "minNameLength";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "maxNameLength";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "paymentTiers";
+            type: {
+              option: {
+                vec: {
+                  defined: "PaymentTier";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "MakeNameEntryOfferIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "PrimaryNameResolution";
+      docs: ["Resolved primary name, returned as borsh encoded return data"];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "nameEntry";
+            type: "publicKey";
+          },
+          {
+            name: "namespaceName";
+            type: "string";
+          },
+          {
+            name: "entryName";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "ClaimApproval";
+      docs: [
+        "Message signed by the namespace approve authority, serialized with borsh"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "namespace";
+            type: "publicKey";
+          },
+          {
+            name: "entryName";
+            type: "string";
+          },
+          {
+            name: "requestor";
+            type: "publicKey";
+          },
+          {
+            name: "counter";
+            type: "u32";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "ClaimRequestApproval";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "entryName";
+            type: "string";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "ApproveClaimRequestsIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "approvals";
+            type: {
+              vec: {
+                defined: "ClaimRequestApproval";
+              };
+            };
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "InitSubleaseIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "duration";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "Creator";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "address";
+            type: "publicKey";
+          },
+          {
+            name: "verified";
+            type: "bool";
+          },
+          {
+            name: "share";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "Beneficiary";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "address";
+            type: "publicKey";
+          },
+          {
+            name: "basisPoints";
+            type: "u16";
+          }
+        ];
+      };
+    },
+    {
+      name: "PaymentTier";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "maxNameLength";
+            type: "u32";
+          },
+          {
+            name: "paymentAmountDaily";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "Gate";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "gateType";
+            type: "u8";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "minAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "GateType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Collection";
+          },
+          {
+            name: "Mint";
+          }
+        ];
+      };
+    },
+    {
+      name: "NamePolicy";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Unrestricted";
+          },
+          {
+            name: "Canonical";
+          },
+          {
+            name: "Strict";
+          }
+        ];
+      };
+    },
+    {
+      name: "NameScript";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Common";
+          },
+          {
+            name: "Latin";
+          },
+          {
+            name: "Greek";
+          },
+          {
+            name: "Cyrillic";
+          },
+          {
+            name: "Other";
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidOwnerMint";
+      msg: "Owner mint is invalid";
+    },
+    {
+      code: 6001;
+      name: "EntryNotExpired";
+      msg: "Entry has not expired";
+    },
+    {
+      code: 6002;
+      name: "RentalDurationTooSmall";
+      msg: "Rental duration too small try adding more funds";
+    },
+    {
+      code: 6003;
+      name: "RentalDurationTooLarge";
+      msg: "Rental duration too large try adding less funds";
+    },
+    {
+      code: 6004;
+      name: "NamespaceRequiresDuration";
+      msg: "Namespace requires duration";
+    },
+    {
+      code: 6005;
+      name: "InvalidAuthority";
+      msg: "Authority is invalid";
+    },
+    {
+      code: 6006;
+      name: "InvalidAuthorityTokenAccount";
+      msg: "Invalid authorty token account";
+    },
+    {
+      code: 6007;
+      name: "InvalidRequestor";
+      msg: "Invalid requestor";
+    },
+    {
+      code: 6008;
+      name: "InvalidNamespacePaymentAccount";
+      msg: "Invalid namespace payment account";
+    },
+    {
+      code: 6009;
+      name: "InvalidGlobalContextPaymentAccount";
+      msg: "Invalid global context payment account";
+    },
+    {
+      code: 6010;
+      name: "InvalidUserTokenAccount";
+      msg: "Invalid user token account";
+    },
+    {
+      code: 6011;
+      name: "InvalidNamespace";
+      msg: "Invalid namespace";
+    },
+    {
+      code: 6012;
+      name: "InvalidEntry";
+      msg: "Invalid entry";
+    },
+    {
+      code: 6013;
+      name: "InvalidPaymentMint";
+      msg: "Invalid payment mint";
+    },
+    {
+      code: 6014;
+      name: "InvalidReverseEntry";
+      msg: "Invalid reverse entry";
+    },
+    {
+      code: 6015;
+      name: "ClaimNotAllowed";
+      msg: "Claim not allowed";
+    },
+    {
+      code: 6016;
+      name: "InvalidUpdateAuthority";
+      msg: "Invalid update authority";
+    },
+    {
+      code: 6017;
+      name: "InvalidApproveAuthority";
+      msg: "Invalid approve authority";
+    },
+    {
+      code: 6018;
+      name: "NamespaceRequiresToken";
+      msg: "Namespace requires token";
+    },
+    {
+      code: 6019;
+      name: "MintAlreadyInitialized";
+      msg: "Mint already initialized";
+    },
+    {
+      code: 6020;
+      name: "InvalidEntryMint";
+      msg: "Mint invalid for entry";
+    },
+    {
+      code: 6021;
+      name: "InvalidTimeInvalidatorProgramId";
+      msg: "Time invalidator program ID is invalid";
+    },
+    {
+      code: 6022;
+      name: "InvalidTokenManager";
+      msg: "Invalid token manager";
+    },
+    {
+      code: 6023;
+      name: "NameEntryAlreadyClaimed";
+      msg: "Name Entry already claimed";
+    },
+    {
+      code: 6024;
+      name: "InvalidCertificate";
+      msg: "Invalid certificate";
+    },
+    {
+      code: 6025;
+      name: "NamespaceReachedLimit";
+      msg: "Namespace has reached the limit";
+    },
+    {
+      code: 6026;
+      name: "InvalidInvalidationType";
+      msg: "Namespace has invalid invalidation type";
+    },
+    {
+      code: 6027;
+      name: "InvalidReverseEntryForNameEntry";
+      msg: "Invalid reverse entry for name entry";
+    },
+    {
+      code: 6028;
+      name: "MissingRequiredAccount";
+      msg: "Account required by namespace is missing";
+    },
+    {
+      code: 6029;
+      name: "InvalidMintCounter";
+      msg: "Invalid mint counter";
+    },
+    {
+      code: 6030;
+      name: "InvalidTimeInvalidator";
+      msg: "Invalid time invalidator";
+    },
+    {
+      code: 6031;
+      name: "InvalidPaymentManager";
+      msg: "Invalid payment manager";
+    },
+    {
+      code: 6032;
+      name: "InvalidPaymentManagerProgramId";
+      msg: "Payment manager program ID is invalid";
+    },
+    {
+      code: 6033;
+      name: "InvalidFeeCollectorTokenAccount";
+      msg: "Invalid fee collector token account";
+    },
+    {
+      code: 6034;
+      name: "InvalidPayerTokenAccount";
+      msg: "Invalid payer token account";
+    },
+    {
+      code: 6035;
+      name: "InvalidNamespaceTokenAccount";
+      msg: "Invalid namespace token account";
+    },
+    {
+      code: 6036;
+      name: "CollectionAlreadyInitialized";
+      msg: "Namespace collection already initialized";
+    },
+    {
+      code: 6037;
+      name: "InvalidNamespaceCollection";
+      msg: "Invalid namespace collection";
+    },
+    {
+      code: 6038;
+      name: "InvalidMetadataConfig";
+      msg: "Invalid metadata configuration";
+    },
+    {
+      code: 6039;
+      name: "InvalidRoyaltyConfig";
+      msg: "Invalid royalty configuration";
+    },
+    {
+      code: 6040;
+      name: "InvalidReferralBasisPoints";
+      msg: "Invalid referral basis points";
+    },
+    {
+      code: 6041;
+      name: "InvalidReferrer";
+      msg: "Invalid referrer";
+    },
+    {
+      code: 6042;
+      name: "InvalidReferrerTokenAccount";
+      msg: "Invalid referrer token account";
+    },
+    {
+      code: 6043;
+      name: "InvalidBeneficiaries";
+      msg: "Invalid beneficiaries";
+    },
+    {
+      code: 6044;
+      name: "InvalidBeneficiaryTokenAccount";
+      msg: "Invalid beneficiary token account";
+    },
+    {
+      code: 6045;
+      name: "NamespaceHasBeneficiaries";
+      msg: "Namespace funds must be distributed to its beneficiaries";
+    },
+    {
+      code: 6046;
+      name: "InvalidFeeBasisPoints";
+      msg: "Invalid fee basis points";
+    },
+    {
+      code: 6047;
+      name: "InvalidNamespaceFeeOverride";
+      msg: "Invalid namespace fee override";
+    },
+    {
+      code: 6048;
+      name: "NamespaceHasAccruedFees";
+      msg: "Namespace has accrued protocol fees that must be collected first";
+    },
+    {
+      code: 6049;
+      name: "ClaimRequestExpired";
+      msg: "Claim request has expired";
+    },
+    {
+      code: 6050;
+      name: "ClaimRequestNotExpired";
+      msg: "Claim request has not expired";
+    },
+    {
+      code: 6051;
+      name: "InvalidPayer";
+      msg: "Invalid payer";
+    },
+    {
+      code: 6052;
+      name: "InvalidApprovalSignature";
+      msg: "Invalid approval signature";
+    },
+    {
+      code: 6053;
+      name: "InvalidAllowlistProof";
+      msg: "Invalid allowlist proof";
+    },
+    {
+      code: 6054;
+      name: "InvalidClaimRequest";
+      msg: "Invalid claim request";
+    },
+    {
+      code: 6055;
+      name: "InvalidGate";
+      msg: "Invalid gate";
+    },
+    {
+      code: 6056;
+      name: "GateNotSatisfied";
+      msg: "Requestor does not hold the tokens required by the namespace gate";
+    },
+    {
+      code: 6057;
+      name: "NamespaceGated";
+      msg: "Namespace is token gated, use claim_name_entry_v2";
+    },
+    {
+      code: 6058;
+      name: "InvalidWalletEntryCounter";
+      msg: "Invalid wallet entry counter";
+    },
+    {
+      code: 6059;
+      name: "WalletReachedLimit";
+      msg: "Wallet reached the namespace per wallet limit";
+    },
+    {
+      code: 6060;
+      name: "NamespaceWalletLimited";
+      msg: "Namespace has a per wallet limit, use claim_name_entry_v2";
+    },
+    {
+      code: 6061;
+      name: "EntriesNotTransferable";
+      msg: "Namespace entries are not transferable";
+    },
+    {
+      code: 6062;
+      name: "InvalidListing";
+      msg: "Invalid listing";
+    },
+    {
+      code: 6063;
+      name: "InvalidCreatorTokenAccount";
+      msg: "Invalid creator token account";
+    },
+    {
+      code: 6064;
+      name: "InvalidOffer";
+      msg: "Invalid offer";
+    },
+    {
+      code: 6065;
+      name: "OfferExpired";
+      msg: "Offer has expired";
+    },
+    {
+      code: 6066;
+      name: "OfferNotExpired";
+      msg: "Offer has not expired";
+    },
+    {
+      code: 6067;
+      name: "InvalidSublease";
+      msg: "Invalid sublease";
+    },
+    {
+      code: 6068;
+      name: "SubleaseActive";
+      msg: "Name is currently subleased";
+    },
+    {
+      code: 6069;
+      name: "SubleaseNotExpired";
+      msg: "Sublease has not expired";
+    },
+    {
+      code: 6070;
+      name: "InvalidNamePolicy";
+      msg: "Invalid name policy";
+    },
+    {
+      code: 6071;
+      name: "NonCanonicalName";
+      msg: "Name is not in canonical form";
+    },
+    {
+      code: 6072;
+      name: "MixedScriptName";
+      msg: "Name mixes scripts";
+    },
+    {
+      code: 6073;
+      name: "ConfusableName";
+      msg: "Name is confusable with a latin name";
+    },
+    {
+      code: 6074;
+      name: "NameTooLong";
+      msg: "Name is too long";
+    },
+    {
+      code: 6075;
+      name: "NameTooShort";
+      msg: "Name is too short";
+    },
+    {
+      code: 6076;
+      name: "InvalidPaymentTiers";
+      msg: "Invalid payment tiers";
+    },
+    {
+      code: 6077;
+      name: "InvalidEmojiAllowlist";
+      msg: "Invalid emoji allowlist";
+    },
+    {
+      code: 6078;
+      name: "EmojiNotAllowed";
+      msg: "Emoji is not allowed in this namespace";
+    },
+    {
+      code: 6079;
+      name: "InvalidNameLengthLimits";
+      msg: "Invalid name length limits";
+    },
+    {
+      code: 6080;
+      name: "NameEntryNotLive";
+      msg: "Name entry is not live";
+    },
+    {
+      code: 6081;
+      name: "InvalidListingPrice";
+      msg: "Listing price does not cover fees and royalties";
+    },
+    {
+      code: 6082;
+      name: "InvalidCollectAmount";
+      msg: "Collect amount exceeds the namespace balance";
+    }
+  ];
+};
+
+export const IDL: Namespaces = {
+  version: "4.1.62",
+  name: "namespaces",
+  instructions: [
+    {
+      name: "collectGlobalContextFunds",
+      accounts: [
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "globalContextPaymentAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rentAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "authorityTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "initGlobalContext",
+      accounts: [
+        {
+          name: "globalContext",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitGlobalContextIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "setNamespaceFeeOverride",
+      accounts: [
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespaceFeeOverride",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "updateAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "feeBasisPoints",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "removeNamespaceFeeOverride",
+      accounts: [
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespaceFeeOverride",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "updateAuthority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "updateGlobalContext",
+      accounts: [
+        {
+          name: "globalContext",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "updateAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateGlobalContextIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "listNameEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listingTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "sellerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespaceFeeOverride",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "price",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "delistNameEntry",
+      accounts: [
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listingTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "sellerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "buyNameEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listingTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespacePaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespaceFeeOverride",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimNameEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "requestor",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "recipient",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedToken",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "ClaimNameEntryIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "claimNameEntryV2",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "requestor",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "recipient",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintEdition",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payerTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespaceFeeOverride",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referrer",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referrerTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "gateTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "gateMetadata",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "walletEntryCounter",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidatorProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManagerProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedToken",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "ClaimNameEntryIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "initNameEntryMint",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "namespaceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "masterEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedToken",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initNameEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitNameEntryIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "invalidateExpiredNameEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "invalidateTransferableNameEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "revokeNameEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setNameEntryData",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "updateNameEntryMintMetadata",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "updateAuthority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateNameEntryMintMetadataIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "syncNameEntryMintMetadata",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeNameEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateNameEntryMint",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "namespaceCertificateTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "masterEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedToken",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "MigrateNameEntryMintIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "collectNamespaceFunds",
+      accounts: [
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "globalContextPaymentAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceFeeOverride",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespacePaymentAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rentAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "rentAuthorityTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "collectAllNamespaceFunds",
+      accounts: [
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "globalContextPaymentAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceFeeOverride",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespacePaymentAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rentAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "rentAuthorityTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createNamespace",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "CreateNamespaceIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "distributeNamespaceFunds",
+      accounts: [
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "globalContextPaymentAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceFeeOverride",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespacePaymentAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initNamespaceCollection",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "updateAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "namespaceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionMint",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "collectionMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionMasterEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedToken",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setEmojiAllowlist",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "emojiAllowlist",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "updateAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "sequences",
+          type: {
+            vec: "string",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateNamespace",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "updateAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateNamespaceIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "makeNameEntryOffer",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "MakeNameEntryOfferIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "acceptNameEntryOffer",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holderTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "holderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespacePaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalContext",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespaceFeeOverride",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "cancelNameEntryOffer",
+      accounts: [
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setPrimaryName",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "primaryName",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closePrimaryName",
+      accounts: [
+        {
+          name: "primaryName",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "resolvePrimaryName",
+      accounts: [
+        {
+          name: "primaryName",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initReferrer",
+      accounts: [
+        {
+          name: "referrer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createClaimRequest",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "entryName",
+          type: "string",
+        },
+        {
+          name: "claimRequestBump",
+          type: "u8",
+        },
+        {
+          name: "user",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "updateClaimRequest",
+      accounts: [
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "approveAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "rentRequest",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "isApproved",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "approveClaimRequest",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "approveAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "entryName",
+          type: "string",
+        },
+        {
+          name: "user",
+          type: "publicKey",
+        },
+        {
+          name: "expiresAt",
+          type: {
+            option: "i64",
+          },
+        },
+      ],
+    },
+    {
+      name: "approveClaimRequestWithProof",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "allowlistClaim",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "entryName",
+          type: "string",
+        },
+        {
+          name: "user",
+          type: "publicKey",
+        },
+        {
+          name: "proof",
+          type: {
+            vec: {
+              array: ["u8", 32],
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "approveClaimRequestWithSignature",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "entryName",
+          type: "string",
+        },
+        {
+          name: "user",
+          type: "publicKey",
+        },
+        {
+          name: "expiresAt",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "approveClaimRequests",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "approveAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "ApproveClaimRequestsIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "migrateClaimRequest",
+      accounts: [
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "rejectClaimRequest",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "approveAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "withdrawClaimRequest",
+      accounts: [
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "requestor",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeExpiredClaimRequest",
+      accounts: [
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "invalidateExpiredReverseEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseNameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "invalidateTransferableReverseEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseNameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "revokeReverseEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setNamespaceReverseEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setGlobalReverseEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseNameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userNameEntryMintTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initSublease",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "sublease",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "subleaseTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "holderTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitSubleaseIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "rentSublease",
+      accounts: [
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sublease",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "lessee",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "lesseePaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setSubleaseReverseNameEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sublease",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "endSublease",
+      accounts: [
+        {
+          name: "nameEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sublease",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lessee",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeSublease",
+      accounts: [
+        {
+          name: "sublease",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "subleaseTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "holderTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "entry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "namespaceCertificateTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificateMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificateMintMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificateProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedToken",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitEntryIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "claimEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "entry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceCertificateTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificate",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificateMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificateTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificatePaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userCertificateTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificateProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedToken",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "ClaimEntryIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "setEntryData",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "entry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userCertificateTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "certificate",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "data",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "setReverseEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "entry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reverseEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userCertificateTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "certificate",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "reverseEntryBump",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "revokeEntry",
+      accounts: [
+        {
+          name: "namespace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "entry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "claimRequest",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespaceCertificateTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "namespacePaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificate",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificateMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificateTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificatePaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userCertificateTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "certificateProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
       ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "globalContext",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "updateAuthority",
+            type: "publicKey",
+          },
+          {
+            name: "rentAuthority",
+            type: "publicKey",
+          },
+          {
+            name: "feeBasisPoints",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "namespaceFeeOverride",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "feeBasisPoints",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "namespace",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "updateAuthority",
+            type: "publicKey",
+          },
+          {
+            name: "rentAuthority",
+            type: "publicKey",
+          },
+          {
+            name: "approveAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "schema",
+            type: "u8",
+          },
+          {
+            name: "paymentAmountDaily",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "minRentalSeconds",
+            type: "i64",
+          },
+          {
+            name: "maxRentalSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "transferableEntries",
+            type: "bool",
+          },
+          {
+            name: "count",
+            type: "u32",
+          },
+          {
+            name: "limit",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "maxExpiration",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "invalidationType",
+            type: "u8",
+          },
+          {
+            name: "collectionMint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "metadataUriTemplate",
+            type: {
+              option: "string",
+            },
+          },
+          {
+            name: "metadataSymbol",
+            type: {
+              option: "string",
+            },
+          },
+          {
+            name: "metadataNameFormat",
+            type: {
+              option: "string",
+            },
+          },
+          {
+            name: "sellerFeeBasisPoints",
+            type: "u16",
+          },
+          {
+            name: "creators",
+            type: {
+              option: {
+                vec: {
+                  defined: "Creator",
+                },
+              },
+            },
+          },
+          {
+            name: "referralBasisPoints",
+            type: "u16",
+          },
+          {
+            name: "beneficiaries",
+            type: {
+              option: {
+                vec: {
+                  defined: "Beneficiary",
+                },
+              },
+            },
+          },
+          {
+            name: "accruedProtocolFees",
+            type: "u64",
+          },
+          {
+            name: "accountedBalance",
+            type: "u64",
+          },
+          {
+            name: "allowlistRoot",
+            type: {
+              option: {
+                array: ["u8", 32],
+              },
+            },
+          },
+          {
+            name: "gate",
+            type: {
+              option: {
+                defined: "Gate",
+              },
+            },
+          },
+          {
+            name: "maxEntriesPerWallet",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "namePolicy",
+            type: "u8",
+          },
+          {
+            name: "minNameLength",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "maxNameLength",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "paymentTiers",
+            type: {
+              option: {
+                vec: {
+                  defined: "PaymentTier",
+                },
+              },
+            },
+          },
+        ],
+      },
     },
     {
-      name: "approveClaimRequest",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "claimRequest",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "approveAuthority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "entryName",
-          type: "string",
-        },
-        {
-          name: "user",
-          type: "publicKey",
-        },
-      ],
+      name: "claimRequest",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "requestor",
+            type: "publicKey",
+          },
+          {
+            name: "isApproved",
+            type: "bool",
+          },
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "entryName",
+            type: "string",
+          },
+          {
+            name: "counter",
+            type: "u32",
+          },
+          {
+            name: "payer",
+            type: "publicKey",
+          },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "invalidateExpiredReverseEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "reverseNameEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "namespaceTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "invalidator",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "entry",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "data",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "reverseEntry",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "isClaimed",
+            type: "bool",
+          },
+          {
+            name: "claimRequestCounter",
+            type: "u32",
+          },
+          {
+            name: "claimer",
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "reverseEntry",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "entryName",
+            type: "string",
+          },
+          {
+            name: "namespaceName",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "referrer",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "referralCount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "allowlistClaim",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "leaf",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+        ],
+      },
     },
     {
-      name: "invalidateTransferableReverseEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "reverseNameEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "tokenManager",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "invalidator",
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "walletEntryCounter",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+          {
+            name: "count",
+            type: "u32",
+          },
+        ],
+      },
     },
     {
-      name: "revokeReverseEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "nameEntry",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "reverseEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "claimRequest",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "invalidator",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [],
+      name: "listing",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "nameEntry",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "seller",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "setNamespaceReverseEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "reverseEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userTokenAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenManager",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "user",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "offer",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "nameEntry",
+            type: "publicKey",
+          },
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+        ],
+      },
     },
     {
-      name: "setGlobalReverseEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "nameEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "reverseNameEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userNameEntryMintTokenAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenManager",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "user",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "sublease",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "nameEntry",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "holder",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "duration",
+            type: "i64",
+          },
+          {
+            name: "lessee",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "leaseExpiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "initEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "entry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "namespaceCertificateTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "mintManager",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificateMint",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificateMintMetadata",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificateProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "associatedToken",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "InitEntryIx",
+      name: "emojiAllowlist",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
           },
-        },
-      ],
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "sequences",
+            type: {
+              vec: "string",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "claimEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "entry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "user",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "claimRequest",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "paymentMint",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "namespaceCertificateTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "mintManager",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificate",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificateMint",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificateTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificatePaymentTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userCertificateTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userPaymentTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificateProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "associatedToken",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "ClaimEntryIx",
+      name: "primaryName",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+          {
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "nameEntry",
+            type: "publicKey",
+          },
+          {
+            name: "reverseEntry",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "ClaimEntryIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "duration",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "certificateBump",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "InitEntryIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
           },
-        },
-      ],
+          {
+            name: "entryBump",
+            type: "u8",
+          },
+          {
+            name: "mintManagerBump",
+            type: "u8",
+          },
+        ],
+      },
     },
     {
-      name: "setEntryData",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "entry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userCertificateTokenAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "certificate",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "user",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "data",
-          type: "publicKey",
-        },
-      ],
+      name: "InitGlobalContextIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "feeBasisPoints",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "setReverseEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "entry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "reverseEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userCertificateTokenAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "certificate",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "user",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "reverseEntryBump",
-          type: "u8",
-        },
-      ],
+      name: "UpdateGlobalContextIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "updateAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "rentAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "feeBasisPoints",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "revokeEntry",
-      accounts: [
-        {
-          name: "namespace",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "entry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "claimRequest",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "namespaceCertificateTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "namespacePaymentTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "invalidator",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "mintManager",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificate",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificateMint",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificateTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificatePaymentTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userCertificateTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userPaymentTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "certificateProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "ClaimNameEntryIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "duration",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "InitNameEntryIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "MigrateNameEntryMintIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "duration",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
     },
-  ],
-  accounts: [
     {
-      name: "globalContext",
+      name: "UpdateNameEntryMintMetadataIx",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
-          },
-          {
-            name: "updateAuthority",
-            type: "publicKey",
+            name: "sellerFeeBasisPoints",
+            docs: [
+              "Royalty basis points that goes to creators in secondary sales (0-10000)",
+            ],
+            type: "u16",
           },
           {
-            name: "rentAuthority",
-            type: "publicKey",
+            name: "creators",
+            docs: ["Array of creators, optional"],
+            type: {
+              option: {
+                vec: {
+                  defined: "Creator",
+                },
+              },
+            },
           },
           {
-            name: "feeBasisPoints",
-            type: "u64",
+            name: "primarySaleHappened",
+            type: {
+              option: "bool",
+            },
           },
         ],
       },
     },
     {
-      name: "namespace",
+      name: "CreateNamespaceIx",
       type: {
         kind: "struct",
         fields: [
-          {
-            name: "bump",
-            type: "u8",
-          },
           {
             name: "name",
             type: "string",
@@ -3452,10 +8101,6 @@ export const IDL: Namespaces = {
             name: "transferableEntries",
             type: "bool",
           },
-          {
-            name: "count",
-            type: "u32",
-          },
           {
             name: "limit",
             type: {
@@ -3476,189 +8121,285 @@ export const IDL: Namespaces = {
       },
     },
     {
-      name: "claimRequest",
+      name: "UpdateNamespaceIx",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
+            name: "updateAuthority",
+            type: "publicKey",
           },
           {
-            name: "requestor",
+            name: "rentAuthority",
             type: "publicKey",
           },
           {
-            name: "isApproved",
-            type: "bool",
+            name: "approveAuthority",
+            type: {
+              option: "publicKey",
+            },
           },
           {
-            name: "namespace",
+            name: "schema",
+            type: "u8",
+          },
+          {
+            name: "paymentAmountDaily",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
             type: "publicKey",
           },
           {
-            name: "entryName",
-            type: "string",
+            name: "minRentalSeconds",
+            type: "i64",
           },
           {
-            name: "counter",
-            type: "u32",
+            name: "maxRentalSeconds",
+            type: {
+              option: "i64",
+            },
           },
-        ],
-      },
-    },
-    {
-      name: "entry",
-      type: {
-        kind: "struct",
-        fields: [
           {
-            name: "bump",
+            name: "transferableEntries",
+            type: "bool",
+          },
+          {
+            name: "limit",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "maxExpiration",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "invalidationType",
             type: "u8",
           },
           {
-            name: "namespace",
-            type: "publicKey",
+            name: "metadataUriTemplate",
+            type: {
+              option: "string",
+            },
           },
           {
-            name: "name",
-            type: "string",
+            name: "metadataSymbol",
+            type: {
+              option: "string",
+            },
           },
           {
-            name: "data",
+            name: "metadataNameFormat",
             type: {
-              option: "publicKey",
+              option: "string",
             },
           },
           {
-            name: "reverseEntry",
+            name: "sellerFeeBasisPoints",
+            type: "u16",
+          },
+          {
+            name: "creators",
             type: {
-              option: "publicKey",
+              option: {
+                vec: {
+                  defined: "Creator",
+                },
+              },
             },
           },
           {
-            name: "mint",
-            type: "publicKey",
+            name: "referralBasisPoints",
+            type: "u16",
           },
           {
-            name: "isClaimed",
-            type: "bool",
+            name: "beneficiaries",
+            type: {
+              option: {
+                vec: {
+                  defined: "Beneficiary",
+                },
+              },
+            },
           },
           {
-            name: "claimRequestCounter",
-            type: "u32",
+            name: "allowlistRoot",
+            type: {
+              option: {
+                array: ["u8", 32],
+              },
+            },
+          },
+          {
+            name: "gate",
+            type: {
+              option: {
+                defined: "Gate",
+              },
+            },
+          },
+          {
+            name: "maxEntriesPerWallet",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "namePolicy",
+            type: "u8",
+          },
+          {
+            name: "minNameLength",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "maxNameLength",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "paymentTiers",
+            type: {
+              option: {
+                vec: {
+                  defined: "PaymentTier",
+                },
+              },
+            },
           },
         ],
       },
     },
     {
-      name: "reverseEntry",
+      name: "MakeNameEntryOfferIx",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
-          },
-          {
-            name: "entryName",
-            type: "string",
+            name: "amount",
+            type: "u64",
           },
           {
-            name: "namespaceName",
-            type: "string",
+            name: "expiresAt",
+            type: "i64",
           },
         ],
       },
     },
-  ],
-  types: [
     {
-      name: "ClaimEntryIx",
+      name: "PrimaryNameResolution",
+      docs: ["Resolved primary name, returned as borsh encoded return data"],
       type: {
         kind: "struct",
         fields: [
           {
-            name: "duration",
-            type: {
-              option: "i64",
-            },
+            name: "wallet",
+            type: "publicKey",
           },
           {
-            name: "certificateBump",
-            type: "u8",
+            name: "nameEntry",
+            type: "publicKey",
+          },
+          {
+            name: "namespaceName",
+            type: "string",
+          },
+          {
+            name: "entryName",
+            type: "string",
           },
         ],
       },
     },
     {
-      name: "InitEntryIx",
+      name: "ClaimApproval",
+      docs: [
+        "Message signed by the namespace approve authority, serialized with borsh",
+      ],
       type: {
         kind: "struct",
         fields: [
           {
-            name: "name",
+            name: "namespace",
+            type: "publicKey",
+          },
+          {
+            name: "entryName",
             type: "string",
           },
           {
-            name: "entryBump",
-            type: "u8",
+            name: "requestor",
+            type: "publicKey",
           },
           {
-            name: "mintManagerBump",
-            type: "u8",
+            name: "counter",
+            type: "u32",
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
           },
         ],
       },
     },
     {
-      name: "InitGlobalContextIx",
+      name: "ClaimRequestApproval",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "feeBasisPoints",
-            type: "u64",
+            name: "entryName",
+            type: "string",
+          },
+          {
+            name: "user",
+            type: "publicKey",
           },
         ],
       },
     },
     {
-      name: "UpdateGlobalContextIx",
+      name: "ApproveClaimRequestsIx",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "updateAuthority",
-            type: {
-              option: "publicKey",
-            },
-          },
-          {
-            name: "rentAuthority",
+            name: "approvals",
             type: {
-              option: "publicKey",
+              vec: {
+                defined: "ClaimRequestApproval",
+              },
             },
           },
           {
-            name: "feeBasisPoints",
+            name: "expiresAt",
             type: {
-              option: "u64",
+              option: "i64",
             },
           },
         ],
       },
     },
     {
-      name: "ClaimNameEntryIx",
+      name: "InitSubleaseIx",
       type: {
         kind: "struct",
         fields: [
+          {
+            name: "price",
+            type: "u64",
+          },
           {
             name: "duration",
-            type: {
-              option: "i64",
-            },
+            type: "i64",
           },
         ],
       },
@@ -3684,191 +8425,107 @@ export const IDL: Namespaces = {
       },
     },
     {
-      name: "InitNameEntryIx",
+      name: "Beneficiary",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "name",
-            type: "string",
+            name: "address",
+            type: "publicKey",
           },
-        ],
-      },
-    },
-    {
-      name: "MigrateNameEntryMintIx",
-      type: {
-        kind: "struct",
-        fields: [
           {
-            name: "duration",
-            type: {
-              option: "i64",
-            },
+            name: "basisPoints",
+            type: "u16",
           },
         ],
       },
     },
     {
-      name: "UpdateNameEntryMintMetadataIx",
+      name: "PaymentTier",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "sellerFeeBasisPoints",
-            docs: [
-              "Royalty basis points that goes to creators in secondary sales (0-10000)",
-            ],
-            type: "u16",
-          },
-          {
-            name: "creators",
-            docs: ["Array of creators, optional"],
-            type: {
-              option: {
-                vec: {
-                  defined: "Creator",
-                },
-              },
-            },
+            name: "maxNameLength",
+            type: "u32",
           },
           {
-            name: "primarySaleHappened",
-            type: {
-              option: "bool",
-            },
+            name: "paymentAmountDaily",
+            type: "u64",
           },
         ],
       },
     },
     {
-      name: "CreateNamespaceIx",
+      name: "Gate",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "name",
-            type: "string",
-          },
-          {
-            name: "updateAuthority",
-            type: "publicKey",
-          },
-          {
-            name: "rentAuthority",
-            type: "publicKey",
-          },
-          {
-            name: "approveAuthority",
-            type: {
-              option: "publicKey",
-            },
-          },
-          {
-            name: "schema",
+            name: "gateType",
             type: "u8",
           },
           {
-            name: "paymentAmountDaily",
-            type: "u64",
-          },
-          {
-            name: "paymentMint",
+            name: "mint",
             type: "publicKey",
           },
           {
-            name: "minRentalSeconds",
-            type: "i64",
-          },
-          {
-            name: "maxRentalSeconds",
-            type: {
-              option: "i64",
-            },
-          },
-          {
-            name: "transferableEntries",
-            type: "bool",
-          },
-          {
-            name: "limit",
-            type: {
-              option: "u32",
-            },
-          },
-          {
-            name: "maxExpiration",
-            type: {
-              option: "i64",
-            },
-          },
-          {
-            name: "invalidationType",
-            type: "u8",
+            name: "minAmount",
+            type: "u64",
           },
         ],
       },
     },
     {
-      name: "UpdateNamespaceIx",
+      name: "GateType",
       type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "updateAuthority",
-            type: "publicKey",
-          },
+        kind: "enum",
+        variants: [
           {
-            name: "rentAuthority",
-            type: "publicKey",
-          },
-          {
-            name: "approveAuthority",
-            type: {
-              option: "publicKey",
-            },
+            name: "Collection",
           },
           {
-            name: "schema",
-            type: "u8",
+            name: "Mint",
           },
+        ],
+      },
+    },
+    {
+      name: "NamePolicy",
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "paymentAmountDaily",
-            type: "u64",
+            name: "Unrestricted",
           },
           {
-            name: "paymentMint",
-            type: "publicKey",
+            name: "Canonical",
           },
           {
-            name: "minRentalSeconds",
-            type: "i64",
+            name: "Strict",
           },
+        ],
+      },
+    },
+    {
+      name: "NameScript",
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "maxRentalSeconds",
-            type: {
-              option: "i64",
-            },
+            name: "Common",
           },
           {
-            name: "transferableEntries",
-            type: "bool",
+            name: "Latin",
           },
           {
-            name: "limit",
-            type: {
-              option: "u32",
-            },
+            name: "Greek",
           },
           {
-            name: "maxExpiration",
-            type: {
-              option: "i64",
-            },
+            name: "Cyrillic",
           },
           {
-            name: "invalidationType",
-            type: "u8",
+            name: "Other",
           },
         ],
       },
@@ -4015,5 +8672,280 @@ export const IDL: Namespaces = {
       name: "InvalidReverseEntryForNameEntry",
       msg: "Invalid reverse entry for name entry",
     },
+    {
+      code: 6028,
+      name: "MissingRequiredAccount",
+      msg: "Account required by namespace is missing",
+    },
+    {
+      code: 6029,
+      name: "InvalidMintCounter",
+      msg: "Invalid mint counter",
+    },
+    {
+      code: 6030,
+      name: "InvalidTimeInvalidator",
+      msg: "Invalid time invalidator",
+    },
+    {
+      code: 6031,
+      name: "InvalidPaymentManager",
+      msg: "Invalid payment manager",
+    },
+    {
+      code: 6032,
+      name: "InvalidPaymentManagerProgramId",
+      msg: "Payment manager program ID is invalid",
+    },
+    {
+      code: 6033,
+      name: "InvalidFeeCollectorTokenAccount",
+      msg: "Invalid fee collector token account",
+    },
+    {
+      code: 6034,
+      name: "InvalidPayerTokenAccount",
+      msg: "Invalid payer token account",
+    },
+    {
+      code: 6035,
+      name: "InvalidNamespaceTokenAccount",
+      msg: "Invalid namespace token account",
+    },
+    {
+      code: 6036,
+      name: "CollectionAlreadyInitialized",
+      msg: "Namespace collection already initialized",
+    },
+    {
+      code: 6037,
+      name: "InvalidNamespaceCollection",
+      msg: "Invalid namespace collection",
+    },
+    {
+      code: 6038,
+      name: "InvalidMetadataConfig",
+      msg: "Invalid metadata configuration",
+    },
+    {
+      code: 6039,
+      name: "InvalidRoyaltyConfig",
+      msg: "Invalid royalty configuration",
+    },
+    {
+      code: 6040,
+      name: "InvalidReferralBasisPoints",
+      msg: "Invalid referral basis points",
+    },
+    {
+      code: 6041,
+      name: "InvalidReferrer",
+      msg: "Invalid referrer",
+    },
+    {
+      code: 6042,
+      name: "InvalidReferrerTokenAccount",
+      msg: "Invalid referrer token account",
+    },
+    {
+      code: 6043,
+      name: "InvalidBeneficiaries",
+      msg: "Invalid beneficiaries",
+    },
+    {
+      code: 6044,
+      name: "InvalidBeneficiaryTokenAccount",
+      msg: "Invalid beneficiary token account",
+    },
+    {
+      code: 6045,
+      name: "NamespaceHasBeneficiaries",
+      msg: "Namespace funds must be distributed to its beneficiaries",
+    },
+    {
+      code: 6046,
+      name: "InvalidFeeBasisPoints",
+      msg: "Invalid fee basis points",
+    },
+    {
+      code: 6047,
+      name: "InvalidNamespaceFeeOverride",
+      msg: "Invalid namespace fee override",
+    },
+    {
+      code: 6048,
+      name: "NamespaceHasAccruedFees",
+      msg: "Namespace has accrued protocol fees that must be collected first",
+    },
+    {
+      code: 6049,
+      name: "ClaimRequestExpired",
+      msg: "Claim request has expired",
+    },
+    {
+      code: 6050,
+      name: "ClaimRequestNotExpired",
+      msg: "Claim request has not expired",
+    },
+    {
+      code: 6051,
+      name: "InvalidPayer",
+      msg: "Invalid payer",
+    },
+    {
+      code: 6052,
+      name: "InvalidApprovalSignature",
+      msg: "Invalid approval signature",
+    },
+    {
+      code: 6053,
+      name: "InvalidAllowlistProof",
+      msg: "Invalid allowlist proof",
+    },
+    {
+      code: 6054,
+      name: "InvalidClaimRequest",
+      msg: "Invalid claim request",
+    },
+    {
+      code: 6055,
+      name: "InvalidGate",
+      msg: "Invalid gate",
+    },
+    {
+      code: 6056,
+      name: "GateNotSatisfied",
+      msg: "Requestor does not hold the tokens required by the namespace gate",
+    },
+    {
+      code: 6057,
+      name: "NamespaceGated",
+      msg: "Namespace is token gated, use claim_name_entry_v2",
+    },
+    {
+      code: 6058,
+      name: "InvalidWalletEntryCounter",
+      msg: "Invalid wallet entry counter",
+    },
+    {
+      code: 6059,
+      name: "WalletReachedLimit",
+      msg: "Wallet reached the namespace per wallet limit",
+    },
+    {
+      code: 6060,
+      name: "NamespaceWalletLimited",
+      msg: "Namespace has a per wallet limit, use claim_name_entry_v2",
+    },
+    {
+      code: 6061,
+      name: "EntriesNotTransferable",
+      msg: "Namespace entries are not transferable",
+    },
+    {
+      code: 6062,
+      name: "InvalidListing",
+      msg: "Invalid listing",
+    },
+    {
+      code: 6063,
+      name: "InvalidCreatorTokenAccount",
+      msg: "Invalid creator token account",
+    },
+    {
+      code: 6064,
+      name: "InvalidOffer",
+      msg: "Invalid offer",
+    },
+    {
+      code: 6065,
+      name: "OfferExpired",
+      msg: "Offer has expired",
+    },
+    {
+      code: 6066,
+      name: "OfferNotExpired",
+      msg: "Offer has not expired",
+    },
+    {
+      code: 6067,
+      name: "InvalidSublease",
+      msg: "Invalid sublease",
+    },
+    {
+      code: 6068,
+      name: "SubleaseActive",
+      msg: "Name is currently subleased",
+    },
+    {
+      code: 6069,
+      name: "SubleaseNotExpired",
+      msg: "Sublease has not expired",
+    },
+    {
+      code: 6070,
+      name: "InvalidNamePolicy",
+      msg: "Invalid name policy",
+    },
+    {
+      code: 6071,
+      name: "NonCanonicalName",
+      msg: "Name is not in canonical form",
+    },
+    {
+      code: 6072,
+      name: "MixedScriptName",
+      msg: "Name mixes scripts",
+    },
+    {
+      code: 6073,
+      name: "ConfusableName",
+      msg: "Name is confusable with a latin name",
+    },
+    {
+      code: 6074,
+      name: "NameTooLong",
+      msg: "Name is too long",
+    },
+    {
+      code: 6075,
+      name: "NameTooShort",
+      msg: "Name is too short",
+    },
+    {
+      code: 6076,
+      name: "InvalidPaymentTiers",
+      msg: "Invalid payment tiers",
+    },
+    {
+      code: 6077,
+      name: "InvalidEmojiAllowlist",
+      msg: "Invalid emoji allowlist",
+    },
+    {
+      code: 6078,
+      name: "EmojiNotAllowed",
+      msg: "Emoji is not allowed in this namespace",
+    },
+    {
+      code: 6079,
+      name: "InvalidNameLengthLimits",
+      msg: "Invalid name length limits",
+    },
+    {
+      code: 6080,
+      name: "NameEntryNotLive",
+      msg: "Name entry is not live",
+    },
+    {
+      code: 6081,
+      name: "InvalidListingPrice",
+      msg: "Listing price does not cover fees and royalties",
+    },
+    {
+      code: 6082,
+      name: "InvalidCollectAmount",
+      msg: "Collect amount exceeds the namespace balance",
+    },
   ],
 };
@@ -6,6 +6,11 @@ import {
   tryGetAccount,
   withFindOrInitAssociatedTokenAccount,
 } from "@cardinal/common";
+import { PAYMENT_MANAGER_ADDRESS } from "@cardinal/payment-manager";
+import { findPaymentManagerAddress } from "@cardinal/payment-manager/dist/cjs/pda";
+import { withRemainingAccountsForPayment } from "@cardinal/payment-manager/dist/cjs/utils";
+import { TIME_INVALIDATOR_ADDRESS } from "@cardinal/token-manager/dist/cjs/programs/timeInvalidator";
+import { findTimeInvalidatorAddress } from "@cardinal/token-manager/dist/cjs/programs/timeInvalidator/pda";
 import {
   getRemainingAccountsForKind,
   InvalidationType,
@@ -26,23 +31,57 @@ import {
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import type { Connection, Keypair, Transaction } from "@solana/web3.js";
-import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import type {
+  AccountMeta,
+  Connection,
+  Keypair,
+  Transaction,
+} from "@solana/web3.js";
+import {
+  Ed25519Program,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
 import { BN } from "bn.js";
 
 import type { NAMESPACES_PROGRAM } from ".";
 import {
+  allowlistLeaf,
+  claimRequestPayer,
+  DEFAULT_PAYMENT_MANAGER,
+  findAllowlistClaimId,
   findClaimRequestId,
+  findEmojiAllowlistId,
   findGlobalContextId,
   findGlobalReverseNameEntryId,
+  findListingId,
   findNameEntryId,
+  findNamespaceFeeOverrideId,
   findNamespaceId,
+  findOfferId,
+  findPrimaryNameId,
+  findReferrerId,
   findReverseNameEntryForNamespaceId,
+  findSubleaseId,
+  findWalletEntryCounterId,
+  getClaimRequest,
   getNameEntry,
+  getListing,
   getNamespace,
+  getOffer,
+  getPrimaryName,
+  getRemainingAccountsForCollection,
+  getRemainingAccountsForRoyalties,
+  getRemainingAccountsForWalletEntryCounter,
+  getSublease,
+  LEGACY_CLAIM_REQUEST_SIZE,
+  paymentAmountDaily,
   NAMESPACES_IDL,
   NAMESPACES_PROGRAM_ID,
   withRemainingAccountsForClaim,
+  withWritableAccounts,
 } from ".";
 
 export function withInit(
@@ -158,7 +197,22 @@ export function withUpdateNamespace(
     maxRentalSeconds?: anchor.BN;
     limit?: number;
     maxExpiration?: anchor.BN;
-  }
+    metadataUriTemplate?: string;
+    metadataSymbol?: string;
+    metadataNameFormat?: string;
+    sellerFeeBasisPoints?: number;
+    creators?: { address: PublicKey; verified: boolean; share: number }[];
+    referralBasisPoints?: number;
+    beneficiaries?: { address: PublicKey; basisPoints: number }[];
+    allowlistRoot?: number[];
+    gate?: { gateType: number; mint: PublicKey; minAmount: anchor.BN };
+    maxEntriesPerWallet?: number;
+    namePolicy?: number;
+    minNameLength?: number;
+    maxNameLength?: number;
+    paymentTiers?: { maxNameLength: number; paymentAmountDaily: anchor.BN }[];
+  },
+  payer = wallet.publicKey
 ): Transaction {
   const provider = new anchor.AnchorProvider(connection, wallet, {});
   const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
@@ -183,11 +237,27 @@ export function withUpdateNamespace(
         limit: params.limit ?? null,
         maxExpiration: params.maxExpiration ?? null,
         invalidationType: params.invalidationType ?? null,
+        metadataUriTemplate: params.metadataUriTemplate ?? null,
+        metadataSymbol: params.metadataSymbol ?? null,
+        metadataNameFormat: params.metadataNameFormat ?? null,
+        sellerFeeBasisPoints: params.sellerFeeBasisPoints ?? 0,
+        creators: params.creators ?? null,
+        referralBasisPoints: params.referralBasisPoints ?? 0,
+        beneficiaries: params.beneficiaries ?? null,
+        allowlistRoot: params.allowlistRoot ?? null,
+        gate: params.gate ?? null,
+        maxEntriesPerWallet: params.maxEntriesPerWallet ?? null,
+        namePolicy: params.namePolicy ?? 0,
+        minNameLength: params.minNameLength ?? null,
+        maxNameLength: params.maxNameLength ?? null,
+        paymentTiers: params.paymentTiers ?? null,
       },
       {
         accounts: {
           namespace: namespaceId,
           updateAuthority: provider.wallet.publicKey,
+          payer: payer,
+          systemProgram: SystemProgram.programId,
         },
       }
     )
@@ -195,6 +265,81 @@ export function withUpdateNamespace(
   return transaction;
 }
 
+export function withInitNamespaceCollection(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  collectionMintKeypair: Keypair,
+  payer = wallet.publicKey
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+
+  const namespaceId = findNamespaceId(namespaceName);
+  const collectionMint = collectionMintKeypair.publicKey;
+  transaction.add(
+    namespacesProgram.instruction.initNamespaceCollection({
+      accounts: {
+        namespace: namespaceId,
+        updateAuthority: provider.wallet.publicKey,
+        payer: payer,
+        namespaceTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint,
+          namespaceId,
+          true
+        ),
+        collectionMint: collectionMint,
+        collectionMetadata: findMintMetadataId(collectionMint),
+        collectionMasterEdition: findMintEditionId(collectionMint),
+        tokenMetadataProgram: METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedToken: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    })
+  );
+  return transaction;
+}
+
+export function withSetEmojiAllowlist(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  sequences: string[],
+  payer = wallet.publicKey
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+
+  const namespaceId = findNamespaceId(namespaceName);
+  transaction.add(
+    namespacesProgram.instruction.setEmojiAllowlist(sequences, {
+      accounts: {
+        namespace: namespaceId,
+        emojiAllowlist: findEmojiAllowlistId(namespaceId),
+        updateAuthority: provider.wallet.publicKey,
+        payer: payer,
+        systemProgram: SystemProgram.programId,
+      },
+    })
+  );
+  return transaction;
+}
+
+/**
+ * @deprecated use withClaimNameEntryV2, referrals are only paid on v2 claims
+ */
 export async function withClaimNameEntry(
   transaction: Transaction,
   connection: Connection,
@@ -216,6 +361,13 @@ export async function withClaimNameEntry(
   const entryId = findNameEntryId(namespaceId, entryName);
   const claimRequestId = findClaimRequestId(namespaceId, entryName, requestor);
   const tokenManagerId = findTokenManagerAddress(mintId);
+  await withMigrateClaimRequest(
+    transaction,
+    connection,
+    wallet,
+    claimRequestId,
+    payer
+  );
 
   const namespaceTokenAccountId = getAssociatedTokenAddressSync(
     mintId,
@@ -245,6 +397,7 @@ export async function withClaimNameEntry(
     transaction,
     wallet,
     namespaceId,
+    entryName,
     tokenManagerId,
     mintId,
     duration
@@ -282,6 +435,180 @@ export async function withClaimNameEntry(
   return transaction;
 }
 
+export async function withClaimNameEntryV2(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string,
+  mintId: PublicKey,
+  duration?: number,
+  params: {
+    requestor?: PublicKey;
+    payer?: PublicKey;
+    referrer?: PublicKey;
+    gateTokenAccount?: PublicKey;
+    gateMetadata?: PublicKey;
+  } = {}
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const requestor = params.requestor ?? wallet.publicKey;
+  const payer = params.payer ?? wallet.publicKey;
+  const namespaceId = findNamespaceId(namespaceName);
+  const namespace = await getNamespace(connection, namespaceId);
+  const entryId = findNameEntryId(namespaceId, entryName);
+  const claimRequestId = findClaimRequestId(namespaceId, entryName, requestor);
+  await withMigrateClaimRequest(
+    transaction,
+    connection,
+    wallet,
+    claimRequestId,
+    payer
+  );
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const paymentManagerId = findPaymentManagerAddress(DEFAULT_PAYMENT_MANAGER);
+  const paymentMintId = namespace.parsed.paymentMint;
+  const dailyPaymentAmount = paymentAmountDaily(namespace.parsed, entryName);
+
+  const recipientTokenAccount = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    provider.connection,
+    mintId,
+    wallet.publicKey,
+    payer,
+    true
+  );
+
+  // optional accounts default to the namespaces program id
+  const hasTimeInvalidator =
+    dailyPaymentAmount.gt(new BN(0)) || !!namespace.parsed.maxExpiration;
+  const isPaid = dailyPaymentAmount.gt(new BN(0)) && !!duration && duration > 0;
+  let paymentTokenAccountId = NAMESPACES_PROGRAM_ID;
+  let feeCollectorTokenAccountId = NAMESPACES_PROGRAM_ID;
+  let remainingAccountsForPayment: AccountMeta[] = [];
+  if (isPaid) {
+    [
+      paymentTokenAccountId,
+      feeCollectorTokenAccountId,
+      remainingAccountsForPayment,
+    ] = await withRemainingAccountsForPayment(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paymentMintId,
+      namespaceId,
+      paymentManagerId
+    );
+  }
+
+  const writableAccounts = [
+    ...(hasTimeInvalidator
+      ? [paymentManagerId, findTimeInvalidatorAddress(tokenManagerId)]
+      : []),
+    ...(isPaid
+      ? [
+          getAssociatedTokenAddressSync(paymentMintId, payer, true),
+          paymentTokenAccountId,
+          feeCollectorTokenAccountId,
+        ]
+      : []),
+    ...(isPaid && params.referrer
+      ? [
+          findReferrerId(params.referrer),
+          getAssociatedTokenAddressSync(paymentMintId, params.referrer, true),
+        ]
+      : []),
+    ...(namespace.parsed.maxEntriesPerWallet
+      ? [findWalletEntryCounterId(namespaceId, requestor)]
+      : []),
+  ];
+
+  transaction.add(
+    withWritableAccounts(
+      namespacesProgram.instruction.claimNameEntryV2(
+        {
+          duration: duration && duration > 0 ? new anchor.BN(duration) : null,
+        },
+        {
+          accounts: {
+            namespace: namespaceId,
+            nameEntry: entryId,
+            requestor: requestor,
+            recipient: wallet.publicKey,
+            payer: payer,
+            claimRequest: claimRequestId,
+            mint: mintId,
+            namespaceTokenAccount: getAssociatedTokenAddressSync(
+              mintId,
+              namespaceId,
+              true
+            ),
+            tokenManager: tokenManagerId,
+            tokenManagerTokenAccount: getAssociatedTokenAddressSync(
+              mintId,
+              tokenManagerId,
+              true
+            ),
+            mintCounter: findMintCounterId(mintId),
+            recipientTokenAccount: recipientTokenAccount,
+            mintEdition: findMintEditionId(mintId),
+            paymentMint: hasTimeInvalidator
+              ? paymentMintId
+              : NAMESPACES_PROGRAM_ID,
+            paymentManager: hasTimeInvalidator
+              ? paymentManagerId
+              : NAMESPACES_PROGRAM_ID,
+            timeInvalidator: hasTimeInvalidator
+              ? findTimeInvalidatorAddress(tokenManagerId)
+              : NAMESPACES_PROGRAM_ID,
+            payerTokenAccount: isPaid
+              ? getAssociatedTokenAddressSync(paymentMintId, payer, true)
+              : NAMESPACES_PROGRAM_ID,
+            paymentTokenAccount: paymentTokenAccountId,
+            feeCollectorTokenAccount: feeCollectorTokenAccountId,
+            globalContext: findGlobalContextId(),
+            namespaceFeeOverride: findNamespaceFeeOverrideId(namespaceId),
+            referrer:
+              isPaid && params.referrer
+                ? findReferrerId(params.referrer)
+                : NAMESPACES_PROGRAM_ID,
+            referrerTokenAccount:
+              isPaid && params.referrer
+                ? getAssociatedTokenAddressSync(
+                    paymentMintId,
+                    params.referrer,
+                    true
+                  )
+                : NAMESPACES_PROGRAM_ID,
+            gateTokenAccount: params.gateTokenAccount ?? NAMESPACES_PROGRAM_ID,
+            gateMetadata: params.gateMetadata ?? NAMESPACES_PROGRAM_ID,
+            walletEntryCounter: namespace.parsed.maxEntriesPerWallet
+              ? findWalletEntryCounterId(namespaceId, requestor)
+              : NAMESPACES_PROGRAM_ID,
+            tokenManagerProgram: TOKEN_MANAGER_ADDRESS,
+            timeInvalidatorProgram: TIME_INVALIDATOR_ADDRESS,
+            paymentManagerProgram: PAYMENT_MANAGER_ADDRESS,
+            tokenMetadataProgram: METADATA_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedToken: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          },
+          remainingAccounts: remainingAccountsForPayment,
+        }
+      ),
+      writableAccounts
+    )
+  );
+  return transaction;
+}
+
 export function withInitNameEntry(
   transaction: Transaction,
   connection: Connection,
@@ -311,6 +638,14 @@ export function withInitNameEntry(
           payer: provider.wallet.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         },
+        // only read for emoji names in namespaces with a name policy
+        remainingAccounts: [
+          {
+            pubkey: findEmojiAllowlistId(namespaceId),
+            isSigner: false,
+            isWritable: false,
+          },
+        ],
       }
     )
   );
@@ -323,7 +658,8 @@ export function withInitNameEntryMint(
   wallet: Wallet,
   namespaceName: string,
   entryName: string,
-  mintKeypair: Keypair
+  mintKeypair: Keypair,
+  collectionMint?: PublicKey
 ): Transaction {
   const provider = new anchor.AnchorProvider(connection, wallet, {});
   const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
@@ -359,6 +695,38 @@ export function withInitNameEntryMint(
         rent: SYSVAR_RENT_PUBKEY,
         systemProgram: SystemProgram.programId,
       },
+      remainingAccounts: collectionMint
+        ? getRemainingAccountsForCollection(collectionMint)
+        : [],
+    })
+  );
+  return transaction;
+}
+
+export async function withSyncNameEntryMintMetadata(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+
+  const namespaceId = findNamespaceId(namespaceName);
+  const nameEntry = await getNameEntry(connection, namespaceName, entryName);
+  transaction.add(
+    namespacesProgram.instruction.syncNameEntryMintMetadata({
+      accounts: {
+        namespace: namespaceId,
+        nameEntry: nameEntry.pubkey,
+        mintMetadata: findMintMetadataId(nameEntry.parsed.mint),
+        tokenMetadataProgram: METADATA_PROGRAM_ID,
+      },
     })
   );
   return transaction;
@@ -430,6 +798,10 @@ export async function withRevokeNameEntry(
         rent: SYSVAR_RENT_PUBKEY,
       },
       remainingAccounts: [
+        ...getRemainingAccountsForWalletEntryCounter(
+          namespaceId,
+          nameEntry.parsed.claimer
+        ),
         ...remainingAccountsForKind,
         ...remainingAccountsForReturn,
       ],
@@ -477,15 +849,21 @@ export async function withInvalidateExpiredNameEntry(
         namespaceTokenAccount: namespaceTokenAccountId,
         invalidator: params.invalidator || namespaceId,
       },
-      remainingAccounts: checkNameEntry.parsed.reverseEntry
-        ? [
-            {
-              pubkey: checkNameEntry.parsed.reverseEntry,
-              isSigner: false,
-              isWritable: true,
-            },
-          ]
-        : [],
+      remainingAccounts: [
+        ...getRemainingAccountsForWalletEntryCounter(
+          namespaceId,
+          checkNameEntry.parsed.claimer
+        ),
+        ...(checkNameEntry.parsed.reverseEntry
+          ? [
+              {
+                pubkey: checkNameEntry.parsed.reverseEntry,
+                isSigner: false,
+                isWritable: true,
+              },
+            ]
+          : []),
+      ],
     })
   );
   return transaction;
@@ -500,6 +878,7 @@ export function withInvalidateTransferableNameEntry(
     mintId: PublicKey;
     entryName: string;
     invalidator?: PublicKey;
+    claimer?: PublicKey | null;
   }
 ): Transaction {
   const provider = new anchor.AnchorProvider(connection, wallet, {});
@@ -519,6 +898,10 @@ export function withInvalidateTransferableNameEntry(
         tokenManager: tokenManagerId,
         invalidator: params.invalidator || namespaceId,
       },
+      remainingAccounts: getRemainingAccountsForWalletEntryCounter(
+        namespaceId,
+        params.claimer ?? null
+      ),
     })
   );
   return transaction;
@@ -537,11 +920,14 @@ export async function withInvalidateNameEntry(
   }
 ): Promise<Transaction> {
   if (params.transferable) {
+    const nameEntry = await tryGetAccount(() =>
+      getNameEntry(connection, params.namespaceName, params.entryName)
+    );
     return withInvalidateTransferableNameEntry(
       transaction,
       connection,
       wallet,
-      params
+      { ...params, claimer: nameEntry?.parsed.claimer }
     );
   } else {
     return withInvalidateExpiredNameEntry(
@@ -648,7 +1034,11 @@ export function withCreateClaimRequest(
   namespaceName: string,
   entryName: string,
   user: PublicKey,
-  transaction: Transaction
+  transaction: Transaction,
+  params: {
+    gateTokenAccount?: PublicKey;
+    gateMetadata?: PublicKey;
+  } = {}
 ): Transaction {
   const provider = new anchor.AnchorProvider(connection, wallet, {});
   const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
@@ -659,6 +1049,11 @@ export function withCreateClaimRequest(
   const namespaceId = findNamespaceId(namespaceName);
   const claimRequestId = findClaimRequestId(namespaceId, entryName, user);
 
+  // gated namespaces check the user holds the gate token,
+  // collection gates also read the token metadata
+  const gateAccounts = [params.gateTokenAccount, params.gateMetadata].filter(
+    (pubkey): pubkey is PublicKey => !!pubkey
+  );
   transaction.add(
     // bump not used anymore, defaulting to placeholder zero
     namespacesProgram.instruction.createClaimRequest(entryName, 0, user, {
@@ -668,6 +1063,11 @@ export function withCreateClaimRequest(
         claimRequest: claimRequestId,
         systemProgram: anchor.web3.SystemProgram.programId,
       },
+      remainingAccounts: gateAccounts.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: false,
+      })),
     })
   );
   return transaction;
@@ -703,15 +1103,50 @@ export function withUpdateClaimRequest(
   return transaction;
 }
 
-export function withRevokeReverseEntry(
+/**
+ * Grows a claim request created before the payer and expiration were stored
+ * so the program can read it, current claim requests are left untouched
+ */
+export async function withMigrateClaimRequest(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  claimRequestId: PublicKey,
+  payer = wallet.publicKey
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const claimRequestInfo = await connection.getAccountInfo(claimRequestId);
+  if (
+    !claimRequestInfo ||
+    claimRequestInfo.data.length > LEGACY_CLAIM_REQUEST_SIZE
+  ) {
+    return transaction;
+  }
+  transaction.add(
+    namespacesProgram.instruction.migrateClaimRequest({
+      accounts: {
+        claimRequest: claimRequestId,
+        payer: payer,
+        systemProgram: SystemProgram.programId,
+      },
+    })
+  );
+  return transaction;
+}
+
+export async function withRejectClaimRequest(
   transaction: Transaction,
   connection: Connection,
   wallet: Wallet,
   namespaceName: string,
   entryName: string,
-  reverseEntryId: PublicKey,
-  claimRequestId: PublicKey
-): Transaction {
+  requestor: PublicKey
+): Promise<Transaction> {
   const provider = new anchor.AnchorProvider(connection, wallet, {});
   const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
     NAMESPACES_IDL,
@@ -719,32 +1154,36 @@ export function withRevokeReverseEntry(
     provider
   );
   const namespaceId = findNamespaceId(namespaceName);
-  const nameEntryId = findNameEntryId(namespaceId, entryName);
+  const claimRequestId = findClaimRequestId(namespaceId, entryName, requestor);
+  await withMigrateClaimRequest(
+    transaction,
+    connection,
+    wallet,
+    claimRequestId
+  );
+  const claimRequest = await tryGetAccount(() =>
+    getClaimRequest(connection, namespaceName, entryName, requestor)
+  );
   transaction.add(
-    namespacesProgram.instruction.revokeReverseEntry({
+    namespacesProgram.instruction.rejectClaimRequest({
       accounts: {
         namespace: namespaceId,
-        nameEntry: nameEntryId,
-        reverseEntry: reverseEntryId,
+        approveAuthority: provider.wallet.publicKey,
         claimRequest: claimRequestId,
-        invalidator: wallet.publicKey,
+        payer: claimRequestPayer(claimRequest?.parsed, requestor),
       },
     })
   );
   return transaction;
 }
 
-export async function withInvalidateExpiredReverseEntry(
+export async function withWithdrawClaimRequest(
   transaction: Transaction,
   connection: Connection,
   wallet: Wallet,
-  params: {
-    namespaceName: string;
-    mintId: PublicKey;
-    entryName: string;
-    reverseEntryId: PublicKey;
-    invalidator?: PublicKey;
-  }
+  namespaceName: string,
+  entryName: string,
+  requestor: PublicKey
 ): Promise<Transaction> {
   const provider = new anchor.AnchorProvider(connection, wallet, {});
   const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
@@ -752,70 +1191,178 @@ export async function withInvalidateExpiredReverseEntry(
     NAMESPACES_PROGRAM_ID,
     provider
   );
-  const namespaceId = findNamespaceId(params.namespaceName);
-  const nameEntryId = findNameEntryId(namespaceId, params.entryName);
-  const namespaceTokenAccountId = await findAta(
-    params.mintId,
-    namespaceId,
-    true
+  const namespaceId = findNamespaceId(namespaceName);
+  const claimRequestId = findClaimRequestId(namespaceId, entryName, requestor);
+  await withMigrateClaimRequest(
+    transaction,
+    connection,
+    wallet,
+    claimRequestId
+  );
+  const claimRequest = await tryGetAccount(() =>
+    getClaimRequest(connection, namespaceName, entryName, requestor)
   );
   transaction.add(
-    namespacesProgram.instruction.invalidateExpiredReverseEntry({
+    namespacesProgram.instruction.withdrawClaimRequest({
       accounts: {
-        namespace: namespaceId,
-        nameEntry: nameEntryId,
-        namespaceTokenAccount: namespaceTokenAccountId,
-        reverseNameEntry: params.reverseEntryId,
-        invalidator: params.invalidator || namespaceId,
+        claimRequest: claimRequestId,
+        requestor: requestor,
+        payer: claimRequestPayer(claimRequest?.parsed, requestor),
       },
     })
   );
   return transaction;
 }
 
-export function withInvalidateTransferableReverseEntry(
+export async function withCloseExpiredClaimRequest(
   transaction: Transaction,
   connection: Connection,
   wallet: Wallet,
-  params: {
-    namespaceName: string;
-    mintId: PublicKey;
-    entryName: string;
-    reverseEntryId: PublicKey;
-    invalidator?: PublicKey;
-  }
-): Transaction {
+  namespaceName: string,
+  entryName: string,
+  requestor: PublicKey
+): Promise<Transaction> {
   const provider = new anchor.AnchorProvider(connection, wallet, {});
   const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
     NAMESPACES_IDL,
     NAMESPACES_PROGRAM_ID,
     provider
   );
-  const namespaceId = findNamespaceId(params.namespaceName);
-  const nameEntryId = findNameEntryId(namespaceId, params.entryName);
-  const tokenManagerId = findTokenManagerAddress(params.mintId);
+  const namespaceId = findNamespaceId(namespaceName);
+  const claimRequestId = findClaimRequestId(namespaceId, entryName, requestor);
+  await withMigrateClaimRequest(
+    transaction,
+    connection,
+    wallet,
+    claimRequestId
+  );
+  const claimRequest = await tryGetAccount(() =>
+    getClaimRequest(connection, namespaceName, entryName, requestor)
+  );
   transaction.add(
-    namespacesProgram.instruction.invalidateTransferableReverseEntry({
+    namespacesProgram.instruction.closeExpiredClaimRequest({
       accounts: {
-        namespace: namespaceId,
-        nameEntry: nameEntryId,
-        tokenManager: tokenManagerId,
-        reverseNameEntry: params.reverseEntryId,
-        invalidator: params.invalidator || namespaceId,
+        claimRequest: claimRequestId,
+        payer: claimRequestPayer(claimRequest?.parsed, requestor),
       },
     })
   );
   return transaction;
 }
 
-export async function withInvalidateReverseEntry(
+export function withRevokeReverseEntry(
   transaction: Transaction,
   connection: Connection,
   wallet: Wallet,
-  params: {
-    namespaceName: string;
-    mintId: PublicKey;
-    entryName: string;
+  namespaceName: string,
+  entryName: string,
+  reverseEntryId: PublicKey,
+  claimRequestId: PublicKey
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const nameEntryId = findNameEntryId(namespaceId, entryName);
+  transaction.add(
+    namespacesProgram.instruction.revokeReverseEntry({
+      accounts: {
+        namespace: namespaceId,
+        nameEntry: nameEntryId,
+        reverseEntry: reverseEntryId,
+        claimRequest: claimRequestId,
+        invalidator: wallet.publicKey,
+      },
+    })
+  );
+  return transaction;
+}
+
+export async function withInvalidateExpiredReverseEntry(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  params: {
+    namespaceName: string;
+    mintId: PublicKey;
+    entryName: string;
+    reverseEntryId: PublicKey;
+    invalidator?: PublicKey;
+  }
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(params.namespaceName);
+  const nameEntryId = findNameEntryId(namespaceId, params.entryName);
+  const namespaceTokenAccountId = await findAta(
+    params.mintId,
+    namespaceId,
+    true
+  );
+  transaction.add(
+    namespacesProgram.instruction.invalidateExpiredReverseEntry({
+      accounts: {
+        namespace: namespaceId,
+        nameEntry: nameEntryId,
+        namespaceTokenAccount: namespaceTokenAccountId,
+        reverseNameEntry: params.reverseEntryId,
+        invalidator: params.invalidator || namespaceId,
+      },
+    })
+  );
+  return transaction;
+}
+
+export function withInvalidateTransferableReverseEntry(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  params: {
+    namespaceName: string;
+    mintId: PublicKey;
+    entryName: string;
+    reverseEntryId: PublicKey;
+    invalidator?: PublicKey;
+  }
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(params.namespaceName);
+  const nameEntryId = findNameEntryId(namespaceId, params.entryName);
+  const tokenManagerId = findTokenManagerAddress(params.mintId);
+  transaction.add(
+    namespacesProgram.instruction.invalidateTransferableReverseEntry({
+      accounts: {
+        namespace: namespaceId,
+        nameEntry: nameEntryId,
+        tokenManager: tokenManagerId,
+        reverseNameEntry: params.reverseEntryId,
+        invalidator: params.invalidator || namespaceId,
+      },
+    })
+  );
+  return transaction;
+}
+
+export async function withInvalidateReverseEntry(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  params: {
+    namespaceName: string;
+    mintId: PublicKey;
+    entryName: string;
     reverseEntryId: PublicKey;
     invalidator?: PublicKey;
     transferable: boolean;
@@ -900,6 +1447,7 @@ export function withApproveClaimRequest(
     entryName: string;
     user: PublicKey;
     approveAuthority?: PublicKey;
+    expiresAt?: anchor.BN;
   }
 ): Transaction {
   const provider = new anchor.AnchorProvider(connection, wallet, {});
@@ -920,6 +1468,7 @@ export function withApproveClaimRequest(
     namespacesProgram.instruction.approveClaimRequest(
       params.entryName,
       params.user,
+      params.expiresAt ?? null,
       {
         accounts: {
           namespace: namespaceId,
@@ -936,6 +1485,166 @@ export function withApproveClaimRequest(
   return transaction;
 }
 
+export async function withApproveClaimRequestWithSignature(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  params: {
+    namespaceName: string;
+    entryName: string;
+    user: PublicKey;
+    expiresAt: anchor.BN;
+    approveAuthority: Keypair;
+  },
+  payer = wallet.publicKey
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(params.namespaceName);
+  const nameEntryId = findNameEntryId(namespaceId, params.entryName);
+  const nameEntry = await tryGetAccount(() =>
+    getNameEntry(connection, params.namespaceName, params.entryName)
+  );
+
+  // signed by the approve authority in the preceding ed25519 instruction
+  const message = namespacesProgram.coder.types.encode("ClaimApproval", {
+    namespace: namespaceId,
+    entryName: params.entryName,
+    requestor: params.user,
+    counter: nameEntry?.parsed.claimRequestCounter ?? 0,
+    expiresAt: params.expiresAt,
+  });
+  transaction.add(
+    Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: params.approveAuthority.secretKey,
+      message,
+    })
+  );
+  transaction.add(
+    namespacesProgram.instruction.approveClaimRequestWithSignature(
+      params.entryName,
+      params.user,
+      params.expiresAt,
+      {
+        accounts: {
+          namespace: namespaceId,
+          payer: payer,
+          claimRequest: findClaimRequestId(
+            namespaceId,
+            params.entryName,
+            params.user
+          ),
+          nameEntry: nameEntryId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    )
+  );
+  return transaction;
+}
+
+export function withApproveClaimRequestWithProof(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  params: {
+    namespaceName: string;
+    entryName: string;
+    user: PublicKey;
+    proof: Buffer[];
+  },
+  payer = wallet.publicKey
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(params.namespaceName);
+  transaction.add(
+    namespacesProgram.instruction.approveClaimRequestWithProof(
+      params.entryName,
+      params.user,
+      params.proof.map((node) => [...node]),
+      {
+        accounts: {
+          namespace: namespaceId,
+          payer: payer,
+          claimRequest: findClaimRequestId(
+            namespaceId,
+            params.entryName,
+            params.user
+          ),
+          allowlistClaim: findAllowlistClaimId(
+            namespaceId,
+            allowlistLeaf(params.entryName, params.user)
+          ),
+          nameEntry: findNameEntryId(namespaceId, params.entryName),
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    )
+  );
+  return transaction;
+}
+
+export function withApproveClaimRequests(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  params: {
+    namespaceName: string;
+    approvals: { entryName: string; user: PublicKey }[];
+    expiresAt?: anchor.BN;
+    approveAuthority?: PublicKey;
+  },
+  payer = wallet.publicKey
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(params.namespaceName);
+  transaction.add(
+    namespacesProgram.instruction.approveClaimRequests(
+      {
+        approvals: params.approvals,
+        expiresAt: params.expiresAt ?? null,
+      },
+      {
+        accounts: {
+          namespace: namespaceId,
+          payer: payer,
+          approveAuthority:
+            params.approveAuthority ?? provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        },
+        remainingAccounts: params.approvals.flatMap(({ entryName, user }) => [
+          {
+            pubkey: findClaimRequestId(namespaceId, entryName, user),
+            isSigner: false,
+            isWritable: true,
+          },
+          {
+            pubkey: findNameEntryId(namespaceId, entryName),
+            isSigner: false,
+            isWritable: false,
+          },
+        ]),
+      }
+    )
+  );
+  return transaction;
+}
+
 export async function withSetGlobalReverseEntry(
   transaction: Transaction,
   connection: Connection,
@@ -1044,18 +1753,12 @@ export async function withMigrateNameEntryMint(
     transaction,
     wallet,
     namespaceId,
+    params.entryName,
     tokenManagerId,
     params.mintKeypair.publicKey,
     params.duration && params.duration > 0 ? params.duration : undefined
   );
 
-  const remainingAccountsForKind = getRemainingAccountsForKind(
-    params.mintKeypair.publicKey,
-    namespace.parsed.transferableEntries
-      ? TokenManagerKind.Unmanaged
-      : TokenManagerKind.Edition
-  );
-
   const mintMetadataId = findMintMetadataId(params.mintKeypair.publicKey);
   const mintMasterEditionId = findMintEditionId(params.mintKeypair.publicKey);
   transaction.add(
@@ -1089,11 +1792,859 @@ export async function withMigrateNameEntryMint(
           systemProgram: SystemProgram.programId,
         },
         remainingAccounts: [
+          ...(namespace.parsed.collectionMint
+            ? getRemainingAccountsForCollection(namespace.parsed.collectionMint)
+            : []),
           ...remainingAccountsForClaim,
-          ...remainingAccountsForKind,
         ],
       }
     )
   );
   return transaction;
 }
+
+export function withInitReferrer(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  authority = wallet.publicKey
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  transaction.add(
+    namespacesProgram.instruction.initReferrer({
+      accounts: {
+        referrer: findReferrerId(authority),
+        authority: authority,
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      },
+    })
+  );
+  return transaction;
+}
+
+export function withSetNamespaceFeeOverride(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  feeBasisPoints: number
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  transaction.add(
+    namespacesProgram.instruction.setNamespaceFeeOverride(
+      new anchor.BN(feeBasisPoints),
+      {
+        accounts: {
+          globalContext: findGlobalContextId(),
+          namespace: namespaceId,
+          namespaceFeeOverride: findNamespaceFeeOverrideId(namespaceId),
+          updateAuthority: provider.wallet.publicKey,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    )
+  );
+  return transaction;
+}
+
+export function withRemoveNamespaceFeeOverride(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  transaction.add(
+    namespacesProgram.instruction.removeNamespaceFeeOverride({
+      accounts: {
+        globalContext: findGlobalContextId(),
+        namespaceFeeOverride: findNamespaceFeeOverrideId(namespaceId),
+        updateAuthority: provider.wallet.publicKey,
+      },
+    })
+  );
+  return transaction;
+}
+
+export async function withCollectNamespaceFunds(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  amount?: BN
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const globalContextId = findGlobalContextId();
+  const namespace = await getNamespace(connection, namespaceId);
+  const globalContextTokenAccount = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    namespace.parsed.paymentMint,
+    globalContextId,
+    wallet.publicKey,
+    true
+  );
+  const rentAuthorityTokenAccount = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    namespace.parsed.paymentMint,
+    wallet.publicKey,
+    wallet.publicKey
+  );
+  const accounts = {
+    globalContext: globalContextId,
+    globalContextPaymentAccount: globalContextTokenAccount,
+    namespaceFeeOverride: findNamespaceFeeOverrideId(namespaceId),
+    namespace: namespaceId,
+    namespacePaymentAccount: getAssociatedTokenAddressSync(
+      namespace.parsed.paymentMint,
+      namespaceId,
+      true
+    ),
+    rentAuthority: wallet.publicKey,
+    rentAuthorityTokenAccount: rentAuthorityTokenAccount,
+    tokenProgram: TOKEN_PROGRAM_ID,
+  };
+  transaction.add(
+    amount
+      ? namespacesProgram.instruction.collectNamespaceFunds(amount, {
+          accounts,
+        })
+      : namespacesProgram.instruction.collectAllNamespaceFunds({ accounts })
+  );
+  return transaction;
+}
+
+export async function withDistributeNamespaceFunds(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const globalContextId = findGlobalContextId();
+  const namespace = await getNamespace(connection, namespaceId);
+  const globalContextTokenAccount = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    namespace.parsed.paymentMint,
+    globalContextId,
+    wallet.publicKey,
+    true
+  );
+  const beneficiaryTokenAccounts: AccountMeta[] = [];
+  for (const beneficiary of namespace.parsed.beneficiaries ?? []) {
+    beneficiaryTokenAccounts.push({
+      pubkey: await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        namespace.parsed.paymentMint,
+        beneficiary.address,
+        wallet.publicKey,
+        true
+      ),
+      isSigner: false,
+      isWritable: true,
+    });
+  }
+  transaction.add(
+    namespacesProgram.instruction.distributeNamespaceFunds({
+      accounts: {
+        globalContext: globalContextId,
+        globalContextPaymentAccount: globalContextTokenAccount,
+        namespaceFeeOverride: findNamespaceFeeOverrideId(namespaceId),
+        namespace: namespaceId,
+        namespacePaymentAccount: getAssociatedTokenAddressSync(
+          namespace.parsed.paymentMint,
+          namespaceId,
+          true
+        ),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+      remainingAccounts: beneficiaryTokenAccounts,
+    })
+  );
+  return transaction;
+}
+
+export async function withListNameEntry(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string,
+  price: BN
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const nameEntry = await getNameEntry(connection, namespaceName, entryName);
+  const mintId = nameEntry.parsed.mint;
+  const listingId = findListingId(mintId);
+  transaction.add(
+    namespacesProgram.instruction.listNameEntry(price, {
+      accounts: {
+        namespace: namespaceId,
+        nameEntry: nameEntry.pubkey,
+        listing: listingId,
+        listingTokenAccount: getAssociatedTokenAddressSync(
+          mintId,
+          listingId,
+          true
+        ),
+        mint: mintId,
+        seller: wallet.publicKey,
+        sellerTokenAccount: getAssociatedTokenAddressSync(
+          mintId,
+          wallet.publicKey,
+          true
+        ),
+        globalContext: findGlobalContextId(),
+        namespaceFeeOverride: findNamespaceFeeOverrideId(namespaceId),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    })
+  );
+  return transaction;
+}
+
+export function withDelistNameEntry(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const listingId = findListingId(mintId);
+  transaction.add(
+    namespacesProgram.instruction.delistNameEntry({
+      accounts: {
+        listing: listingId,
+        listingTokenAccount: getAssociatedTokenAddressSync(
+          mintId,
+          listingId,
+          true
+        ),
+        seller: wallet.publicKey,
+        sellerTokenAccount: getAssociatedTokenAddressSync(
+          mintId,
+          wallet.publicKey,
+          true
+        ),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+    })
+  );
+  return transaction;
+}
+
+export async function withBuyNameEntry(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const namespace = await getNamespace(connection, namespaceId);
+  const nameEntry = await getNameEntry(connection, namespaceName, entryName);
+  const listing = await getListing(connection, nameEntry.parsed.mint);
+  const mintId = listing.parsed.mint;
+  const paymentMintId = listing.parsed.paymentMint;
+  const namespacePaymentAccount = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    paymentMintId,
+    namespaceId,
+    wallet.publicKey,
+    true
+  );
+  const sellerPaymentTokenAccount = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    paymentMintId,
+    listing.parsed.seller,
+    wallet.publicKey,
+    true
+  );
+  const reverseEntryId = nameEntry.parsed.reverseEntry;
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  transaction.add(
+    withWritableAccounts(
+      namespacesProgram.instruction.buyNameEntry({
+        accounts: {
+          namespace: namespaceId,
+          nameEntry: nameEntry.pubkey,
+          listing: listing.pubkey,
+          listingTokenAccount: getAssociatedTokenAddressSync(
+            mintId,
+            listing.pubkey,
+            true
+          ),
+          mint: mintId,
+          seller: listing.parsed.seller,
+          sellerPaymentTokenAccount: sellerPaymentTokenAccount,
+          reverseEntry: reverseEntryId ?? NAMESPACES_PROGRAM_ID,
+          tokenManager: tokenManagerId,
+          timeInvalidator: findTimeInvalidatorAddress(tokenManagerId),
+          buyer: wallet.publicKey,
+          buyerTokenAccount: getAssociatedTokenAddressSync(
+            mintId,
+            wallet.publicKey,
+            true
+          ),
+          buyerPaymentTokenAccount: getAssociatedTokenAddressSync(
+            paymentMintId,
+            wallet.publicKey,
+            true
+          ),
+          namespacePaymentTokenAccount: namespacePaymentAccount,
+          globalContext: findGlobalContextId(),
+          namespaceFeeOverride: findNamespaceFeeOverrideId(namespaceId),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        remainingAccounts: getRemainingAccountsForRoyalties(
+          namespaceId,
+          namespace.parsed
+        ),
+      }),
+      reverseEntryId ? [reverseEntryId] : []
+    )
+  );
+  return transaction;
+}
+
+export async function withMakeNameEntryOffer(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string,
+  amount: BN,
+  expiresAt: number
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const namespace = await getNamespace(connection, namespaceId);
+  const nameEntryId = findNameEntryId(namespaceId, entryName);
+  const offerId = findOfferId(nameEntryId, wallet.publicKey);
+  const paymentMintId = namespace.parsed.paymentMint;
+  transaction.add(
+    namespacesProgram.instruction.makeNameEntryOffer(
+      {
+        amount: amount,
+        expiresAt: new anchor.BN(expiresAt),
+      },
+      {
+        accounts: {
+          namespace: namespaceId,
+          nameEntry: nameEntryId,
+          offer: offerId,
+          offerTokenAccount: getAssociatedTokenAddressSync(
+            paymentMintId,
+            offerId,
+            true
+          ),
+          paymentMint: paymentMintId,
+          buyer: wallet.publicKey,
+          buyerPaymentTokenAccount: getAssociatedTokenAddressSync(
+            paymentMintId,
+            wallet.publicKey,
+            true
+          ),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    )
+  );
+  return transaction;
+}
+
+export async function withCancelNameEntryOffer(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string,
+  buyer = wallet.publicKey
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const nameEntryId = findNameEntryId(namespaceId, entryName);
+  const offer = await getOffer(connection, nameEntryId, buyer);
+  transaction.add(
+    namespacesProgram.instruction.cancelNameEntryOffer({
+      accounts: {
+        offer: offer.pubkey,
+        offerTokenAccount: getAssociatedTokenAddressSync(
+          offer.parsed.paymentMint,
+          offer.pubkey,
+          true
+        ),
+        buyer: buyer,
+        buyerPaymentTokenAccount: getAssociatedTokenAddressSync(
+          offer.parsed.paymentMint,
+          buyer,
+          true
+        ),
+        user: wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+    })
+  );
+  return transaction;
+}
+
+export async function withAcceptNameEntryOffer(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string,
+  buyer: PublicKey
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const namespace = await getNamespace(connection, namespaceId);
+  const nameEntry = await getNameEntry(connection, namespaceName, entryName);
+  const offer = await getOffer(connection, nameEntry.pubkey, buyer);
+  const mintId = nameEntry.parsed.mint;
+  const paymentMintId = offer.parsed.paymentMint;
+  const holderPaymentTokenAccount = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    paymentMintId,
+    wallet.publicKey,
+    wallet.publicKey,
+    true
+  );
+  const namespacePaymentAccount = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    paymentMintId,
+    namespaceId,
+    wallet.publicKey,
+    true
+  );
+  const reverseEntryId = nameEntry.parsed.reverseEntry;
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  transaction.add(
+    withWritableAccounts(
+      namespacesProgram.instruction.acceptNameEntryOffer({
+        accounts: {
+          namespace: namespaceId,
+          nameEntry: nameEntry.pubkey,
+          offer: offer.pubkey,
+          offerTokenAccount: getAssociatedTokenAddressSync(
+            paymentMintId,
+            offer.pubkey,
+            true
+          ),
+          mint: mintId,
+          buyer: buyer,
+          buyerTokenAccount: getAssociatedTokenAddressSync(
+            mintId,
+            buyer,
+            true
+          ),
+          holderTokenAccount: getAssociatedTokenAddressSync(
+            mintId,
+            wallet.publicKey,
+            true
+          ),
+          tokenManager: tokenManagerId,
+          timeInvalidator: findTimeInvalidatorAddress(tokenManagerId),
+          holder: wallet.publicKey,
+          holderPaymentTokenAccount: holderPaymentTokenAccount,
+          reverseEntry: reverseEntryId ?? NAMESPACES_PROGRAM_ID,
+          namespacePaymentTokenAccount: namespacePaymentAccount,
+          globalContext: findGlobalContextId(),
+          namespaceFeeOverride: findNamespaceFeeOverrideId(namespaceId),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        remainingAccounts: getRemainingAccountsForRoyalties(
+          namespaceId,
+          namespace.parsed
+        ),
+      }),
+      reverseEntryId ? [reverseEntryId] : []
+    )
+  );
+  return transaction;
+}
+
+export async function withInitSublease(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string,
+  price: BN,
+  duration: number
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const nameEntry = await getNameEntry(connection, namespaceName, entryName);
+  const mintId = nameEntry.parsed.mint;
+  const subleaseId = findSubleaseId(mintId);
+  transaction.add(
+    namespacesProgram.instruction.initSublease(
+      {
+        price: price,
+        duration: new anchor.BN(duration),
+      },
+      {
+        accounts: {
+          namespace: namespaceId,
+          nameEntry: nameEntry.pubkey,
+          sublease: subleaseId,
+          subleaseTokenAccount: getAssociatedTokenAddressSync(
+            mintId,
+            subleaseId,
+            true
+          ),
+          mint: mintId,
+          holderTokenAccount: getAssociatedTokenAddressSync(
+            mintId,
+            wallet.publicKey,
+            true
+          ),
+          tokenManager: findTokenManagerAddress(mintId),
+          holder: wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    )
+  );
+  return transaction;
+}
+
+export async function withRentSublease(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const nameEntry = await getNameEntry(connection, namespaceName, entryName);
+  const mintId = nameEntry.parsed.mint;
+  const sublease = await getSublease(connection, mintId);
+  const holderPaymentTokenAccount = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    sublease.parsed.paymentMint,
+    sublease.parsed.holder,
+    wallet.publicKey,
+    true
+  );
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const reverseEntryId = nameEntry.parsed.reverseEntry;
+  transaction.add(
+    withWritableAccounts(
+      namespacesProgram.instruction.rentSublease({
+        accounts: {
+          nameEntry: nameEntry.pubkey,
+          sublease: sublease.pubkey,
+          holder: sublease.parsed.holder,
+          holderPaymentTokenAccount: holderPaymentTokenAccount,
+          reverseEntry: reverseEntryId ?? NAMESPACES_PROGRAM_ID,
+          tokenManager: tokenManagerId,
+          timeInvalidator: findTimeInvalidatorAddress(tokenManagerId),
+          lessee: wallet.publicKey,
+          lesseePaymentTokenAccount: getAssociatedTokenAddressSync(
+            sublease.parsed.paymentMint,
+            wallet.publicKey,
+            true
+          ),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        },
+      }),
+      reverseEntryId ? [reverseEntryId] : []
+    )
+  );
+  return transaction;
+}
+
+export async function withSetSubleaseReverseNameEntry(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const nameEntry = await getNameEntry(connection, namespaceName, entryName);
+  transaction.add(
+    namespacesProgram.instruction.setSubleaseReverseNameEntry({
+      accounts: {
+        namespace: namespaceId,
+        nameEntry: nameEntry.pubkey,
+        reverseEntry: findReverseNameEntryForNamespaceId(
+          namespaceId,
+          wallet.publicKey
+        ),
+        sublease: findSubleaseId(nameEntry.parsed.mint),
+        user: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      },
+    })
+  );
+  return transaction;
+}
+
+export async function withEndSublease(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const nameEntry = await getNameEntry(connection, namespaceName, entryName);
+  const sublease = await getSublease(connection, nameEntry.parsed.mint);
+  if (!sublease.parsed.lessee) {
+    throw new Error("Sublease is not rented");
+  }
+  const reverseEntryId = nameEntry.parsed.reverseEntry;
+  transaction.add(
+    withWritableAccounts(
+      namespacesProgram.instruction.endSublease({
+        accounts: {
+          nameEntry: nameEntry.pubkey,
+          sublease: sublease.pubkey,
+          lessee: sublease.parsed.lessee,
+          reverseEntry: reverseEntryId ?? NAMESPACES_PROGRAM_ID,
+          user: wallet.publicKey,
+        },
+      }),
+      reverseEntryId ? [reverseEntryId] : []
+    )
+  );
+  return transaction;
+}
+
+export async function withCloseSublease(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const subleaseId = findSubleaseId(mintId);
+  transaction.add(
+    namespacesProgram.instruction.closeSublease({
+      accounts: {
+        sublease: subleaseId,
+        subleaseTokenAccount: getAssociatedTokenAddressSync(
+          mintId,
+          subleaseId,
+          true
+        ),
+        holder: wallet.publicKey,
+        holderTokenAccount: getAssociatedTokenAddressSync(
+          mintId,
+          wallet.publicKey,
+          true
+        ),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+    })
+  );
+  return transaction;
+}
+
+export async function withSetPrimaryName(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  namespaceName: string,
+  entryName: string,
+  payer = wallet.publicKey
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const namespaceId = findNamespaceId(namespaceName);
+  const nameEntry = await getNameEntry(connection, namespaceName, entryName);
+  const tokenManagerId = findTokenManagerAddress(nameEntry.parsed.mint);
+  transaction.add(
+    namespacesProgram.instruction.setPrimaryName({
+      accounts: {
+        namespace: namespaceId,
+        nameEntry: nameEntry.pubkey,
+        reverseEntry: findReverseNameEntryForNamespaceId(
+          namespaceId,
+          wallet.publicKey
+        ),
+        primaryName: findPrimaryNameId(wallet.publicKey),
+        tokenManager: tokenManagerId,
+        timeInvalidator: findTimeInvalidatorAddress(tokenManagerId),
+        user: wallet.publicKey,
+        payer: payer,
+        systemProgram: SystemProgram.programId,
+      },
+    })
+  );
+  return transaction;
+}
+
+export function withClosePrimaryName(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet
+): Transaction {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  transaction.add(
+    namespacesProgram.instruction.closePrimaryName({
+      accounts: {
+        primaryName: findPrimaryNameId(wallet.publicKey),
+        user: wallet.publicKey,
+      },
+    })
+  );
+  return transaction;
+}
+
+export async function withResolvePrimaryName(
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  primaryNameWallet: PublicKey
+): Promise<Transaction> {
+  const provider = new anchor.AnchorProvider(connection, wallet, {});
+  const namespacesProgram = new anchor.Program<NAMESPACES_PROGRAM>(
+    NAMESPACES_IDL,
+    NAMESPACES_PROGRAM_ID,
+    provider
+  );
+  const primaryName = await getPrimaryName(connection, primaryNameWallet);
+  const nameEntry = await namespacesProgram.account.entry.fetch(
+    primaryName.parsed.nameEntry
+  );
+  const tokenManagerId = findTokenManagerAddress(nameEntry.mint);
+  transaction.add(
+    namespacesProgram.instruction.resolvePrimaryName({
+      accounts: {
+        primaryName: primaryName.pubkey,
+        namespace: primaryName.parsed.namespace,
+        nameEntry: primaryName.parsed.nameEntry,
+        reverseEntry: primaryName.parsed.reverseEntry,
+        tokenManager: tokenManagerId,
+        timeInvalidator: findTimeInvalidatorAddress(tokenManagerId),
+      },
+    })
+  );
+  return transaction;
+}
@@ -2,12 +2,23 @@ import { utils } from "@project-serum/anchor";
 import { PublicKey } from "@solana/web3.js";
 
 import {
+  ALLOWLIST_CLAIM_SEED,
   CLAIM_REQUEST_SEED,
+  EMOJI_ALLOWLIST_SEED,
   ENTRY_SEED,
+  FEE_OVERRIDE_SEED,
   GLOBAL_CONTEXT_SEED,
+  LISTING_SEED,
+  LONG_NAME_SEED_PREFIX,
+  MAX_NAME_SEED_LENGTH,
   NAMESPACE_SEED,
   NAMESPACES_PROGRAM_ID,
+  OFFER_SEED,
+  PRIMARY_NAME_SEED,
+  REFERRER_SEED,
   REVERSE_ENTRY_SEED,
+  SUBLEASE_SEED,
+  WALLET_ENTRY_COUNTER_SEED,
 } from "./constants";
 
 /**
@@ -23,6 +34,17 @@ export const findNamespaceId = (namespaceName: string): PublicKey =>
     NAMESPACES_PROGRAM_ID
   )[0];
 
+/**
+ * Seed for an entry name, names longer than the max seed length are seeded on their prefixed sha256 hash.
+ * @returns
+ */
+export const entryNameSeed = (entryName: string): Uint8Array => {
+  const entryNameBytes = utils.bytes.utf8.encode(entryName);
+  return entryNameBytes.length > MAX_NAME_SEED_LENGTH
+    ? Buffer.from(utils.sha256.hash(LONG_NAME_SEED_PREFIX + entryName), "hex")
+    : entryNameBytes;
+};
+
 /**
  * Finds the entry id in a given namespace.
  * @returns
@@ -35,7 +57,7 @@ export const findNameEntryId = (
     [
       utils.bytes.utf8.encode(ENTRY_SEED),
       namespaceId.toBytes(),
-      utils.bytes.utf8.encode(entryName),
+      entryNameSeed(entryName),
     ],
     NAMESPACES_PROGRAM_ID
   )[0];
@@ -53,7 +75,7 @@ export const findClaimRequestId = (
     [
       utils.bytes.utf8.encode(CLAIM_REQUEST_SEED),
       namespaceId.toBytes(),
-      utils.bytes.utf8.encode(entryName),
+      entryNameSeed(entryName),
       requestor.toBytes(),
     ],
     NAMESPACES_PROGRAM_ID
@@ -126,3 +148,116 @@ export const findGlobalContextId = (): PublicKey =>
     [utils.bytes.utf8.encode(GLOBAL_CONTEXT_SEED)],
     NAMESPACES_PROGRAM_ID
   )[0];
+
+/**
+ * Finds the referrer ID for a given authority.
+ * @returns
+ */
+export const findReferrerId = (authority: PublicKey): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(REFERRER_SEED), authority.toBytes()],
+    NAMESPACES_PROGRAM_ID
+  )[0];
+
+/**
+ * Finds the fee override ID for a given namespace.
+ * @returns
+ */
+export const findNamespaceFeeOverrideId = (
+  namespaceId: PublicKey
+): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(FEE_OVERRIDE_SEED), namespaceId.toBytes()],
+    NAMESPACES_PROGRAM_ID
+  )[0];
+
+/**
+ * Finds the entry counter ID for a wallet in a given namespace.
+ * @returns
+ */
+export const findWalletEntryCounterId = (
+  namespaceId: PublicKey,
+  wallet: PublicKey
+): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(WALLET_ENTRY_COUNTER_SEED),
+      namespaceId.toBytes(),
+      wallet.toBytes(),
+    ],
+    NAMESPACES_PROGRAM_ID
+  )[0];
+
+/**
+ * Finds the primary name ID for a given publickey.
+ * @returns
+ */
+export const findPrimaryNameId = (pubkey: PublicKey): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(PRIMARY_NAME_SEED), pubkey.toBytes()],
+    NAMESPACES_PROGRAM_ID
+  )[0];
+
+/**
+ * Finds the listing ID for a given name entry mint.
+ * @returns
+ */
+export const findListingId = (mintId: PublicKey): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(LISTING_SEED), mintId.toBytes()],
+    NAMESPACES_PROGRAM_ID
+  )[0];
+
+/**
+ * Finds the offer ID for a buyer on a given name entry.
+ * @returns
+ */
+export const findOfferId = (
+  nameEntryId: PublicKey,
+  buyer: PublicKey
+): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(OFFER_SEED),
+      nameEntryId.toBytes(),
+      buyer.toBytes(),
+    ],
+    NAMESPACES_PROGRAM_ID
+  )[0];
+
+/**
+ * Finds the sublease ID for a given name entry mint.
+ * @returns
+ */
+export const findSubleaseId = (mintId: PublicKey): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(SUBLEASE_SEED), mintId.toBytes()],
+    NAMESPACES_PROGRAM_ID
+  )[0];
+
+/**
+ * Finds the emoji allowlist ID for a given namespace.
+ * @returns
+ */
+export const findEmojiAllowlistId = (namespaceId: PublicKey): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(EMOJI_ALLOWLIST_SEED), namespaceId.toBytes()],
+    NAMESPACES_PROGRAM_ID
+  )[0];
+
+/**
+ * Finds the allowlist claim id marking an allowlist leaf as used.
+ * @returns
+ */
+export const findAllowlistClaimId = (
+  namespaceId: PublicKey,
+  leaf: Buffer
+): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(ALLOWLIST_CLAIM_SEED),
+      namespaceId.toBytes(),
+      leaf,
+    ],
+    NAMESPACES_PROGRAM_ID
+  )[0];
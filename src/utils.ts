@@ -1,4 +1,8 @@
-import { findAta } from "@cardinal/common";
+import {
+  findAta,
+  findMintEditionId,
+  findMintMetadataId,
+} from "@cardinal/common";
 import { PAYMENT_MANAGER_ADDRESS } from "@cardinal/payment-manager";
 import { findPaymentManagerAddress } from "@cardinal/payment-manager/dist/cjs/pda";
 import { withRemainingAccountsForPayment } from "@cardinal/payment-manager/dist/cjs/utils";
@@ -9,16 +13,21 @@ import {
   TokenManagerKind,
 } from "@cardinal/token-manager/dist/cjs/programs/tokenManager";
 import type { Wallet } from "@project-serum/anchor/dist/cjs/provider";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import type {
   AccountMeta,
   Connection,
-  PublicKey,
   Transaction,
+  TransactionInstruction,
 } from "@solana/web3.js";
+import { PublicKey } from "@solana/web3.js";
 import { BN } from "bn.js";
+import { keccak_256 } from "js-sha3";
 
 import { getNamespace, getReverseEntry } from "./accounts";
+import type { ClaimRequestData, NamespaceData } from "./constants";
 import { DEFAULT_PAYMENT_MANAGER, IDENTITIES } from "./constants";
+import { findWalletEntryCounterId } from "./pda";
 
 export function formatName(namespace: string, name: string): string {
   return IDENTITIES.includes(namespace) ? `@${name}` : `${name}.${namespace}`;
@@ -94,21 +103,160 @@ export async function nameForDisplay(
   return (name && name[0]) || displayAddress(pubkey.toString());
 }
 
+/**
+ * Length of an entry name in grapheme clusters, matching how the program counts name length
+ * @param entryName
+ * @returns
+ */
+export const entryNameLength = (entryName: string): number => {
+  // Intl.Segmenter is not in the ES2019 lib, runtimes without it count code points
+  const Segmenter = (
+    Intl as unknown as {
+      Segmenter?: new () => { segment: (input: string) => Iterable<unknown> };
+    }
+  ).Segmenter;
+  return Segmenter
+    ? Array.from(new Segmenter().segment(entryName)).length
+    : Array.from(entryName).length;
+};
+
+/**
+ * Daily payment for an entry name, the first tier that fits the name length applies
+ * and longer names pay the namespace default
+ * @param namespace
+ * @param entryName
+ * @returns
+ */
+export const paymentAmountDaily = (
+  namespace: NamespaceData,
+  entryName: string
+): BN => {
+  const length = entryNameLength(entryName);
+  const tier = namespace.paymentTiers?.find(
+    (tier) => length <= tier.maxNameLength
+  );
+  return tier?.paymentAmountDaily ?? namespace.paymentAmountDaily;
+};
+
+/**
+ * Account refunded when a claim request is closed,
+ * requests without a recorded payer refund the requestor
+ * @param claimRequest
+ * @param requestor
+ * @returns
+ */
+export const claimRequestPayer = (
+  claimRequest: ClaimRequestData | undefined | null,
+  requestor: PublicKey
+): PublicKey =>
+  claimRequest && !claimRequest.payer.equals(PublicKey.default)
+    ? claimRequest.payer
+    : requestor;
+
+const MERKLE_LEAF_PREFIX = 0;
+const MERKLE_NODE_PREFIX = 1;
+
+const hashMerkleNodes = (a: Buffer, b: Buffer): Buffer => {
+  const [first, second] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+  return Buffer.from(
+    keccak_256.arrayBuffer(
+      Buffer.concat([Buffer.from([MERKLE_NODE_PREFIX]), first, second])
+    )
+  );
+};
+
+/**
+ * Allowlist leaf for an entry name and requestor, matching the program
+ * @param entryName
+ * @param requestor
+ * @returns
+ */
+export const allowlistLeaf = (
+  entryName: string,
+  requestor: PublicKey
+): Buffer =>
+  Buffer.from(
+    keccak_256.arrayBuffer(
+      Buffer.concat([
+        Buffer.from([MERKLE_LEAF_PREFIX]),
+        Buffer.from(entryName, "utf8"),
+        requestor.toBuffer(),
+      ])
+    )
+  );
+
+/**
+ * Levels of an allowlist merkle tree from the leaves up to the root,
+ * nodes without a sibling move up unchanged
+ * @param leaves
+ * @returns
+ */
+const allowlistTree = (leaves: Buffer[]): Buffer[][] => {
+  const levels = [leaves];
+  while (levels[levels.length - 1]!.length > 1) {
+    const level = levels[levels.length - 1]!;
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) {
+      next.push(
+        i + 1 < level.length
+          ? hashMerkleNodes(level[i]!, level[i + 1]!)
+          : level[i]!
+      );
+    }
+    levels.push(next);
+  }
+  return levels;
+};
+
+/**
+ * Allowlist root to set on the namespace for the given leaves
+ * @param leaves
+ * @returns
+ */
+export const allowlistRoot = (leaves: Buffer[]): Buffer => {
+  const levels = allowlistTree(leaves);
+  return levels[levels.length - 1]![0]!;
+};
+
+/**
+ * Proof that a leaf is in the allowlist built from the given leaves
+ * @param leaves
+ * @param leaf
+ * @returns
+ */
+export const allowlistProof = (leaves: Buffer[], leaf: Buffer): Buffer[] => {
+  let index = leaves.findIndex((l) => l.equals(leaf));
+  if (index < 0) {
+    throw new Error("Leaf not in allowlist");
+  }
+  const proof: Buffer[] = [];
+  for (const level of allowlistTree(leaves).slice(0, -1)) {
+    const sibling = level[index % 2 === 0 ? index + 1 : index - 1];
+    if (sibling) {
+      proof.push(sibling);
+    }
+    index = Math.floor(index / 2);
+  }
+  return proof;
+};
+
 export const withRemainingAccountsForClaim = async (
   connection: Connection,
   transaction: Transaction,
   wallet: Wallet,
   namespaceId: PublicKey,
+  entryName: string,
   tokenManagerId: PublicKey,
   mintId: PublicKey,
   duration?: number
 ): Promise<AccountMeta[]> => {
   const namespace = await getNamespace(connection, namespaceId);
   const paymentManagerId = findPaymentManagerAddress(DEFAULT_PAYMENT_MANAGER);
+  const dailyPaymentAmount = paymentAmountDaily(namespace.parsed, entryName);
 
   const accounts: AccountMeta[] = [];
   if (
-    namespace.parsed.paymentAmountDaily.gt(new BN(0)) ||
+    dailyPaymentAmount.gt(new BN(0)) ||
     namespace.parsed.maxExpiration
   ) {
     const timeInvalidatorId = findTimeInvalidatorAddress(tokenManagerId);
@@ -137,13 +285,16 @@ export const withRemainingAccountsForClaim = async (
       ]
     );
   }
-  const remainingAccountsForKind = getRemainingAccountsForKind(
-    mintId,
-    TokenManagerKind.Edition
-  );
-  accounts.push(...remainingAccountsForKind);
+  // transferable entries are unmanaged and need no edition accounts
+  if (!namespace.parsed.transferableEntries) {
+    const remainingAccountsForKind = getRemainingAccountsForKind(
+      mintId,
+      TokenManagerKind.Edition
+    );
+    accounts.push(...remainingAccountsForKind);
+  }
   if (
-    namespace.parsed.paymentAmountDaily.gt(new BN(0)) &&
+    dailyPaymentAmount.gt(new BN(0)) &&
     duration &&
     duration > 0
   ) {
@@ -192,3 +343,97 @@ export const withRemainingAccountsForClaim = async (
   }
   return accounts;
 };
+
+/**
+ * Remaining accounts used to verify a name mint in the namespace collection
+ * @param collectionMint
+ * @returns
+ */
+export const getRemainingAccountsForCollection = (
+  collectionMint: PublicKey
+): AccountMeta[] => [
+  {
+    pubkey: collectionMint,
+    isSigner: false,
+    isWritable: false,
+  },
+  {
+    pubkey: findMintMetadataId(collectionMint),
+    isSigner: false,
+    isWritable: false,
+  },
+  {
+    pubkey: findMintEditionId(collectionMint),
+    isSigner: false,
+    isWritable: false,
+  },
+];
+
+/**
+ * Remaining accounts that release an entry from its claimer's wallet limit,
+ * passed first when revoking or invalidating an entry
+ * @param namespaceId
+ * @param claimer
+ * @returns
+ */
+export const getRemainingAccountsForWalletEntryCounter = (
+  namespaceId: PublicKey,
+  claimer: PublicKey | null
+): AccountMeta[] =>
+  claimer
+    ? [
+        {
+          pubkey: findWalletEntryCounterId(namespaceId, claimer),
+          isSigner: false,
+          isWritable: true,
+        },
+      ]
+    : [];
+
+/**
+ * Marks accounts writable on an instruction, optional accounts are not declared mut
+ * so the program checks their writability only when they are used
+ * @param instruction
+ * @param writableAccounts
+ * @returns
+ */
+export const withWritableAccounts = (
+  instruction: TransactionInstruction,
+  writableAccounts: PublicKey[]
+): TransactionInstruction => {
+  instruction.keys = instruction.keys.map((key) =>
+    writableAccounts.some((account) => account.equals(key.pubkey))
+      ? { ...key, isWritable: true }
+      : key
+  );
+  return instruction;
+};
+
+/**
+ * Remaining accounts that receive royalties on a secondary sale, one payment
+ * token account for each namespace metadata creator with a share
+ * @param namespaceId
+ * @param namespace
+ * @returns
+ */
+export const getRemainingAccountsForRoyalties = (
+  namespaceId: PublicKey,
+  namespace: NamespaceData
+): AccountMeta[] => {
+  if (namespace.sellerFeeBasisPoints === 0) return [];
+  const creators =
+    namespace.creators && namespace.creators.length > 0
+      ? namespace.creators
+      : [{ address: namespaceId, share: 100 }];
+  return creators
+    .filter((creator) => creator.share > 0)
+    .map((creator) => ({
+      pubkey: getAssociatedTokenAddressSync(
+        namespace.paymentMint,
+        creator.address,
+        true
+      ),
+      isSigner: false,
+      isWritable: true,
+    }));
+};
@@ -0,0 +1,185 @@
+import type { CardinalProvider } from "@cardinal/common";
+import { executeTransaction, getTestProvider } from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  allowlistLeaf,
+  allowlistProof,
+  allowlistRoot,
+  getClaimRequest,
+  getNamespaceByName,
+  withApproveClaimRequest,
+  withApproveClaimRequestWithProof,
+  withCreateNamespace,
+  withUpdateNamespace,
+} from "../src";
+
+describe("approve-claim-request-with-proof", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const otherEntryName = `testname-${Math.random()}`;
+  const requestor = web3.Keypair.generate();
+  const otherRequestor = web3.Keypair.generate();
+  const leaves = [
+    allowlistLeaf(entryName, requestor.publicKey),
+    allowlistLeaf(otherEntryName, otherRequestor.publicKey),
+    allowlistLeaf(`testname-${Math.random()}`, web3.Keypair.generate().publicKey),
+  ];
+
+  // global
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+  });
+
+  it("Creates a namespace with an allowlist", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const namespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    const updateTransaction = new web3.Transaction();
+    withUpdateNamespace(
+      updateTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      {
+        updateAuthority: provider.wallet.publicKey,
+        rentAuthority: provider.wallet.publicKey,
+        approveAuthority: provider.wallet.publicKey,
+        schema: namespace.parsed.schema,
+        paymentAmountDaily: namespace.parsed.paymentAmountDaily,
+        paymentMint: namespace.parsed.paymentMint,
+        minRentalSeconds: namespace.parsed.minRentalSeconds,
+        transferableEntries: namespace.parsed.transferableEntries,
+        invalidationType: namespace.parsed.invalidationType,
+        allowlistRoot: [...allowlistRoot(leaves)],
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      updateTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Fails to approve with a proof for another leaf", async () => {
+    const transaction = new web3.Transaction();
+    withApproveClaimRequestWithProof(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      {
+        namespaceName,
+        entryName,
+        user: otherRequestor.publicKey,
+        proof: allowlistProof(leaves, leaves[0]!),
+      }
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Approves an allowlisted requestor", async () => {
+    const transaction = new web3.Transaction();
+    withApproveClaimRequestWithProof(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      {
+        namespaceName,
+        entryName,
+        user: requestor.publicKey,
+        proof: allowlistProof(leaves, leaves[0]!),
+      }
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      requestor.publicKey
+    );
+    assert.equal(claimRequest.parsed.isApproved, true);
+    assert.equal(claimRequest.parsed.expiresAt, null);
+  });
+
+  it("Fails to replay an allowlist leaf", async () => {
+    const transaction = new web3.Transaction();
+    withApproveClaimRequestWithProof(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      {
+        namespaceName,
+        entryName,
+        user: requestor.publicKey,
+        proof: allowlistProof(leaves, leaves[0]!),
+      }
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Keeps the expiration of an existing request", async () => {
+    const expiresAt = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const transaction = new web3.Transaction();
+    withApproveClaimRequest(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      entryName: otherEntryName,
+      user: otherRequestor.publicKey,
+      expiresAt,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const proofTransaction = new web3.Transaction();
+    withApproveClaimRequestWithProof(
+      proofTransaction,
+      provider.connection,
+      provider.wallet,
+      {
+        namespaceName,
+        entryName: otherEntryName,
+        user: otherRequestor.publicKey,
+        proof: allowlistProof(leaves, leaves[1]!),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      proofTransaction,
+      provider.wallet
+    );
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      otherEntryName,
+      otherRequestor.publicKey
+    );
+    assert.equal(claimRequest.parsed.isApproved, true);
+    assert.equal(
+      claimRequest.parsed.expiresAt?.toNumber(),
+      expiresAt.toNumber()
+    );
+  });
+});
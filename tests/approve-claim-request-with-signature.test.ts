@@ -0,0 +1,114 @@
+import type { CardinalProvider } from "@cardinal/common";
+import { executeTransaction, getTestProvider } from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  getClaimRequest,
+  withApproveClaimRequestWithSignature,
+  withCreateNamespace,
+} from "../src";
+
+describe("approve-claim-request-with-signature", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const approveAuthority = web3.Keypair.generate();
+  const requestor = web3.Keypair.generate();
+
+  // global
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+  });
+
+  it("Creates a namespace", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: approveAuthority.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Fails to approve with a signature from another key", async () => {
+    const transaction = new web3.Transaction();
+    await withApproveClaimRequestWithSignature(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      {
+        namespaceName,
+        entryName,
+        user: requestor.publicKey,
+        expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+        approveAuthority: web3.Keypair.generate(),
+      }
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Fails to approve with an expired signature", async () => {
+    const transaction = new web3.Transaction();
+    await withApproveClaimRequestWithSignature(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      {
+        namespaceName,
+        entryName,
+        user: requestor.publicKey,
+        expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) - 3600),
+        approveAuthority,
+      }
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Approves with a signature from the approve authority", async () => {
+    const expiresAt = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const transaction = new web3.Transaction();
+    await withApproveClaimRequestWithSignature(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      {
+        namespaceName,
+        entryName,
+        user: requestor.publicKey,
+        expiresAt,
+        approveAuthority,
+      }
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      requestor.publicKey
+    );
+    assert.equal(claimRequest.parsed.isApproved, true);
+    assert.equal(claimRequest.parsed.counter, 0);
+    assert.equal(
+      claimRequest.parsed.expiresAt?.toNumber(),
+      expiresAt.toNumber()
+    );
+    assert.equal(
+      claimRequest.parsed.payer.toString(),
+      provider.wallet.publicKey.toString()
+    );
+  });
+});
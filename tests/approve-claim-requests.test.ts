@@ -0,0 +1,119 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  getTestProvider,
+  newAccountWithLamports,
+} from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  getClaimRequest,
+  withApproveClaimRequests,
+  withCreateClaimRequest,
+  withCreateNamespace,
+} from "../src";
+
+describe("approve-claim-requests", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const approvals = [...Array(3)].map(() => ({
+    entryName: `testname-${Math.random()}`,
+    user: web3.Keypair.generate().publicKey,
+  }));
+
+  // global
+  let provider: CardinalProvider;
+  let requestor: web3.Keypair;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    requestor = await newAccountWithLamports(provider.connection);
+    approvals.push({
+      entryName: `testname-${Math.random()}`,
+      user: requestor.publicKey,
+    });
+  });
+
+  it("Creates a namespace and a pending request", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const requestorWallet = new anchor.Wallet(requestor);
+    const requestTransaction = new web3.Transaction();
+    withCreateClaimRequest(
+      provider.connection,
+      requestorWallet,
+      namespaceName,
+      approvals[3]!.entryName,
+      requestor.publicKey,
+      requestTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      requestTransaction,
+      requestorWallet
+    );
+  });
+
+  it("Fails to approve without the approve authority", async () => {
+    const otherWallet = new anchor.Wallet(
+      await newAccountWithLamports(provider.connection)
+    );
+    const transaction = new web3.Transaction();
+    withApproveClaimRequests(transaction, provider.connection, otherWallet, {
+      namespaceName,
+      approvals,
+    });
+    await expect(
+      executeTransaction(provider.connection, transaction, otherWallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Approves new and pending requests in one instruction", async () => {
+    const expiresAt = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const transaction = new web3.Transaction();
+    withApproveClaimRequests(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      {
+        namespaceName,
+        approvals,
+        expiresAt,
+      }
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    for (const { entryName, user } of approvals) {
+      const claimRequest = await getClaimRequest(
+        provider.connection,
+        namespaceName,
+        entryName,
+        user
+      );
+      assert.equal(claimRequest.parsed.isApproved, true);
+      assert.equal(
+        claimRequest.parsed.expiresAt?.toNumber(),
+        expiresAt.toNumber()
+      );
+      // pending requests keep the payer that created them
+      assert.equal(
+        claimRequest.parsed.payer.toString(),
+        (user.equals(requestor.publicKey)
+          ? requestor.publicKey
+          : provider.wallet.publicKey
+        ).toString()
+      );
+    }
+  });
+});
@@ -0,0 +1,175 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  tryGetAccount,
+} from "@cardinal/common";
+import { getAccount } from "@solana/spl-token";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  getClaimRequest,
+  getNameEntry,
+  getNamespaceByName,
+  withClaimNameEntryV2,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withUpdateClaimRequest,
+} from "../src";
+import { initGlobalContextIfMissing } from "./utils";
+
+describe("claim-name-entry-v2", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+  const nameEntryMint = mintKeypair.publicKey;
+
+  // global
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    await initGlobalContextIfMissing(provider);
+  });
+
+  it("Creates a namespace", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkNamespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    assert.equal(checkNamespace.parsed.name, namespaceName);
+  });
+
+  it("Init entry and mint", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [mintKeypair],
+      }
+    );
+  });
+
+  it("Create claim request", async () => {
+    const transaction = new web3.Transaction();
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Fails to claim before approval", async () => {
+    const transaction = new web3.Transaction();
+    await withClaimNameEntryV2(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      nameEntryMint
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Approve claim request", async () => {
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    const transaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      transaction
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Claim with unused optional accounts", async () => {
+    // a free namespace passes the program id for every payment, referral, gate and counter account
+    const transaction = new web3.Transaction();
+    await withClaimNameEntryV2(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      nameEntryMint
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkClaimRequest = await tryGetAccount(async () =>
+      getClaimRequest(
+        provider.connection,
+        namespaceName,
+        entryName,
+        provider.wallet.publicKey
+      )
+    );
+    expect(checkClaimRequest).toEqual(null);
+
+    const checkNameEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      entryName
+    );
+    assert.equal(checkNameEntry.parsed.isClaimed, true);
+    assert.equal(
+      checkNameEntry.parsed.data?.toString(),
+      provider.wallet.publicKey.toString()
+    );
+
+    const checkRecipientTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(nameEntryMint, provider.wallet.publicKey)
+    );
+    expect(Number(checkRecipientTokenAccount.amount.toString())).toEqual(1);
+    expect(checkRecipientTokenAccount.isFrozen).toEqual(true);
+  });
+});
@@ -0,0 +1,260 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  tryGetAccount,
+  withFindOrInitAssociatedTokenAccount,
+} from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import { getAccount } from "@solana/spl-token";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  getClaimRequest,
+  getNamespaceByName,
+  getReferrer,
+  withClaimNameEntryV2,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withInitReferrer,
+  withUpdateClaimRequest,
+  withUpdateNamespace,
+} from "../src";
+import {
+  createMint,
+  initGlobalContextIfMissing,
+  initPaymentManagerIfMissing,
+} from "./utils";
+
+describe("claim-referral", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+  const nameEntryMint = mintKeypair.publicKey;
+  const referrer = web3.Keypair.generate();
+  const paymentAmountDaily = new anchor.BN(10000000);
+  const referralBasisPoints = 1000;
+  const PAYMENT_MINT_START = 100000000;
+  const duration = 86400;
+
+  // global
+  let paymentMintId: web3.PublicKey;
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    await initGlobalContextIfMissing(provider);
+    await initPaymentManagerIfMissing(provider);
+    [, paymentMintId] = await createMint(provider.connection, provider.wallet, {
+      target: provider.wallet.publicKey,
+      amount: PAYMENT_MINT_START,
+    });
+  });
+
+  it("Creates a namespace with referrals", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      paymentAmountDaily,
+      paymentMint: paymentMintId,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const namespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    const updateTransaction = new web3.Transaction();
+    withUpdateNamespace(
+      updateTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      {
+        updateAuthority: provider.wallet.publicKey,
+        rentAuthority: provider.wallet.publicKey,
+        approveAuthority: provider.wallet.publicKey,
+        schema: namespace.parsed.schema,
+        paymentAmountDaily: namespace.parsed.paymentAmountDaily,
+        paymentMint: namespace.parsed.paymentMint,
+        minRentalSeconds: namespace.parsed.minRentalSeconds,
+        transferableEntries: false,
+        invalidationType: namespace.parsed.invalidationType,
+        referralBasisPoints,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      updateTransaction,
+      provider.wallet
+    );
+
+    const checkNamespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    assert.equal(
+      checkNamespace.parsed.referralBasisPoints,
+      referralBasisPoints
+    );
+  });
+
+  it("Init referrer", async () => {
+    const transaction = new web3.Transaction();
+    withInitReferrer(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      referrer.publicKey
+    );
+    await withFindOrInitAssociatedTokenAccount(
+      transaction,
+      provider.connection,
+      paymentMintId,
+      referrer.publicKey,
+      provider.wallet.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [referrer],
+      }
+    );
+
+    const checkReferrer = await getReferrer(
+      provider.connection,
+      referrer.publicKey
+    );
+    assert.equal(checkReferrer.parsed.referralCount.toNumber(), 0);
+  });
+
+  it("Init entry, mint and approved claim request", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [mintKeypair],
+      }
+    );
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    const approveTransaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      approveTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      approveTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Fails to refer yourself", async () => {
+    const walletReferrer = await tryGetAccount(() =>
+      getReferrer(provider.connection, provider.wallet.publicKey)
+    );
+    if (!walletReferrer) {
+      const transaction = new web3.Transaction();
+      withInitReferrer(transaction, provider.connection, provider.wallet);
+      await executeTransaction(
+        provider.connection,
+        transaction,
+        provider.wallet
+      );
+    }
+
+    const claimTransaction = new web3.Transaction();
+    await withClaimNameEntryV2(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      nameEntryMint,
+      duration,
+      { referrer: provider.wallet.publicKey }
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        claimTransaction,
+        provider.wallet,
+        { silent: true }
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Claim pays the referrer", async () => {
+    const transaction = new web3.Transaction();
+    await withClaimNameEntryV2(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      nameEntryMint,
+      duration,
+      { referrer: referrer.publicKey }
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkReferrerTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMintId, referrer.publicKey)
+    );
+    expect(Number(checkReferrerTokenAccount.amount.toString())).toBeGreaterThan(
+      0
+    );
+    const checkReferrer = await getReferrer(
+      provider.connection,
+      referrer.publicKey
+    );
+    assert.equal(checkReferrer.parsed.referralCount.toNumber(), 1);
+  });
+});
@@ -0,0 +1,198 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  getTestProvider,
+  newAccountWithLamports,
+  tryGetAccount,
+} from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  findClaimRequestId,
+  findNamespaceId,
+  getClaimRequest,
+  withApproveClaimRequest,
+  withCloseExpiredClaimRequest,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withRejectClaimRequest,
+  withWithdrawClaimRequest,
+} from "../src";
+
+describe("claim-request-refunds", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+
+  // global
+  let provider: CardinalProvider;
+  let requestor: web3.Keypair;
+
+  const claimRequestLamports = async (user: web3.PublicKey) =>
+    (await provider.connection.getAccountInfo(
+      findClaimRequestId(findNamespaceId(namespaceName), entryName, user)
+    ))!.lamports;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    requestor = await newAccountWithLamports(provider.connection);
+  });
+
+  it("Creates a namespace", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Withdraw refunds the payer of the request", async () => {
+    const transaction = new web3.Transaction();
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      requestor.publicKey,
+      transaction
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const lamports = await claimRequestLamports(requestor.publicKey);
+    const payerBefore = await provider.connection.getBalance(
+      provider.wallet.publicKey
+    );
+    const withdrawTransaction = new web3.Transaction();
+    await withWithdrawClaimRequest(
+      withdrawTransaction,
+      provider.connection,
+      new anchor.Wallet(requestor),
+      namespaceName,
+      entryName,
+      requestor.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      withdrawTransaction,
+      new anchor.Wallet(requestor)
+    );
+
+    assert.equal(
+      (await provider.connection.getBalance(provider.wallet.publicKey)) -
+        payerBefore,
+      lamports
+    );
+    const checkClaimRequest = await tryGetAccount(() =>
+      getClaimRequest(
+        provider.connection,
+        namespaceName,
+        entryName,
+        requestor.publicKey
+      )
+    );
+    assert.equal(checkClaimRequest, null);
+  });
+
+  it("Reject refunds a requestor that paid for the request", async () => {
+    const requestorWallet = new anchor.Wallet(requestor);
+    const transaction = new web3.Transaction();
+    withCreateClaimRequest(
+      provider.connection,
+      requestorWallet,
+      namespaceName,
+      entryName,
+      requestor.publicKey,
+      transaction
+    );
+    await executeTransaction(provider.connection, transaction, requestorWallet);
+
+    const lamports = await claimRequestLamports(requestor.publicKey);
+    const requestorBefore = await provider.connection.getBalance(
+      requestor.publicKey
+    );
+    const rejectTransaction = new web3.Transaction();
+    await withRejectClaimRequest(
+      rejectTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      requestor.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      rejectTransaction,
+      provider.wallet
+    );
+
+    assert.equal(
+      (await provider.connection.getBalance(requestor.publicKey)) -
+        requestorBefore,
+      lamports
+    );
+  });
+
+  it("Fails to close a claim request before it expires", async () => {
+    const transaction = new web3.Transaction();
+    withApproveClaimRequest(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      entryName,
+      user: requestor.publicKey,
+      expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) + 2),
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const closeTransaction = new web3.Transaction();
+    await withCloseExpiredClaimRequest(
+      closeTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      requestor.publicKey
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        closeTransaction,
+        provider.wallet,
+        { silent: true }
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Closes an expired claim request", async () => {
+    await new Promise((r) => setTimeout(r, 4000));
+    const lamports = await claimRequestLamports(requestor.publicKey);
+    const payerBefore = await provider.connection.getBalance(
+      provider.wallet.publicKey
+    );
+
+    // anyone can close an expired request, the refund goes to its payer
+    const closer = new anchor.Wallet(
+      await newAccountWithLamports(provider.connection)
+    );
+    const transaction = new web3.Transaction();
+    await withCloseExpiredClaimRequest(
+      transaction,
+      provider.connection,
+      closer,
+      namespaceName,
+      entryName,
+      requestor.publicKey
+    );
+    await executeTransaction(provider.connection, transaction, closer);
+
+    assert.equal(
+      (await provider.connection.getBalance(provider.wallet.publicKey)) -
+        payerBefore,
+      lamports
+    );
+  });
+});
@@ -0,0 +1,237 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  tryGetAccount,
+} from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import { getAccount } from "@solana/spl-token";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  findGlobalContextId,
+  findNamespaceId,
+  getClaimRequest,
+  getNamespaceByName,
+  withClaimNameEntryV2,
+  withCollectNamespaceFunds,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withSetNamespaceFeeOverride,
+  withUpdateClaimRequest,
+} from "../src";
+import {
+  createMint,
+  initGlobalContextIfMissing,
+  initPaymentManagerIfMissing,
+} from "./utils";
+
+describe("collect-namespace-funds", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+  const nameEntryMint = mintKeypair.publicKey;
+  const paymentAmountDaily = new anchor.BN(10000000);
+  const feeBasisPoints = 1000;
+  const PAYMENT_MINT_START = 100000000;
+
+  // global
+  let paymentMintId: web3.PublicKey;
+  let provider: CardinalProvider;
+
+  const tokenAmount = async (owner: web3.PublicKey) => {
+    const tokenAccount = await tryGetAccount(async () =>
+      getAccount(provider.connection, await findAta(paymentMintId, owner, true))
+    );
+    return Number(tokenAccount?.amount.toString() ?? 0);
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    await initGlobalContextIfMissing(provider);
+    await initPaymentManagerIfMissing(provider);
+    [, paymentMintId] = await createMint(provider.connection, provider.wallet, {
+      target: provider.wallet.publicKey,
+      amount: PAYMENT_MINT_START,
+    });
+  });
+
+  it("Creates a namespace with a fee override", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      paymentAmountDaily,
+      paymentMint: paymentMintId,
+      transferableEntries: false,
+    });
+    withSetNamespaceFeeOverride(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      feeBasisPoints
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Claim accrues protocol fees", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [mintKeypair],
+      }
+    );
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    const approveTransaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      approveTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      approveTransaction,
+      provider.wallet
+    );
+
+    const claimTransaction = new web3.Transaction();
+    await withClaimNameEntryV2(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      nameEntryMint,
+      86400
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      provider.wallet
+    );
+
+    const checkNamespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    assert.equal(
+      checkNamespace.parsed.accruedProtocolFees.toNumber(),
+      paymentAmountDaily.toNumber() / 10
+    );
+    assert.equal(
+      await tokenAmount(findNamespaceId(namespaceName)),
+      paymentAmountDaily.toNumber()
+    );
+  });
+
+  it("Fails to collect more than the namespace holds", async () => {
+    const transaction = new web3.Transaction();
+    await withCollectNamespaceFunds(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      paymentAmountDaily.muln(2)
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Collect pays accrued fees out of the amount", async () => {
+    const amount = paymentAmountDaily.toNumber() * 0.4;
+    const globalContextBefore = await tokenAmount(findGlobalContextId());
+    const authorityBefore = await tokenAmount(provider.wallet.publicKey);
+
+    const transaction = new web3.Transaction();
+    await withCollectNamespaceFunds(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      new anchor.BN(amount)
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const fee = paymentAmountDaily.toNumber() / 10;
+    assert.equal(
+      (await tokenAmount(findGlobalContextId())) - globalContextBefore,
+      fee
+    );
+    assert.equal(
+      (await tokenAmount(provider.wallet.publicKey)) - authorityBefore,
+      amount - fee
+    );
+    const checkNamespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    assert.equal(checkNamespace.parsed.accruedProtocolFees.toNumber(), 0);
+  });
+
+  it("Collect all empties the namespace", async () => {
+    const authorityBefore = await tokenAmount(provider.wallet.publicKey);
+    const namespaceBefore = await tokenAmount(findNamespaceId(namespaceName));
+
+    const transaction = new web3.Transaction();
+    await withCollectNamespaceFunds(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    assert.equal(await tokenAmount(findNamespaceId(namespaceName)), 0);
+    assert.equal(
+      (await tokenAmount(provider.wallet.publicKey)) - authorityBefore,
+      namespaceBefore
+    );
+  });
+});
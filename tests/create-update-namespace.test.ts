@@ -68,6 +68,9 @@ describe("create-update-namespace", () => {
         paymentMint: namespace.parsed.paymentMint,
         minRentalSeconds: namespace.parsed.minRentalSeconds,
         invalidationType: namespace.parsed.invalidationType,
+        maxEntriesPerWallet: 3,
+        minNameLength: 2,
+        maxNameLength: 20,
       }
     );
     await executeTransaction(provider.connection, transaction, provider.wallet);
@@ -83,5 +86,8 @@ describe("create-update-namespace", () => {
       paymentAmountDaily.toNumber()
     );
     assert.equal(checkNamespace.parsed.maxExpiration?.toNumber(), 10000);
+    assert.equal(checkNamespace.parsed.maxEntriesPerWallet, 3);
+    assert.equal(checkNamespace.parsed.minNameLength, 2);
+    assert.equal(checkNamespace.parsed.maxNameLength, 20);
   });
 });
@@ -0,0 +1,249 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  findMintEditionId,
+  findMintMetadataId,
+  getTestProvider,
+  METADATA_PROGRAM_ID,
+} from "@cardinal/common";
+import { findTokenManagerAddress } from "@cardinal/token-manager/dist/cjs/programs/tokenManager/pda";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  findNameEntryId,
+  findNamespaceId,
+  findReverseNameEntryForNamespaceId,
+  getClaimRequest,
+  getNameEntry,
+  namespacesProgram,
+  withClaimNameEntry,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withUpdateClaimRequest,
+} from "../src";
+
+describe("cross-namespace-entry-substitution", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const otherNamespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+  const nameEntryMint = mintKeypair.publicKey;
+
+  // global
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+  });
+
+  it("Creates two namespaces", async () => {
+    const transaction = new web3.Transaction();
+    for (const name of [namespaceName, otherNamespaceName]) {
+      withCreateNamespace(transaction, provider.connection, provider.wallet, {
+        namespaceName: name,
+        updateAuthority: provider.wallet.publicKey,
+        rentAuthority: provider.wallet.publicKey,
+        approveAuthority: provider.wallet.publicKey,
+        transferableEntries: false,
+      });
+    }
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Init entry", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Fails to init mint for entry under another namespace", async () => {
+    const otherNamespaceId = findNamespaceId(otherNamespaceName);
+    const entryId = findNameEntryId(findNamespaceId(namespaceName), entryName);
+    const transaction = new web3.Transaction().add(
+      namespacesProgram(
+        provider.connection,
+        provider.wallet
+      ).instruction.initNameEntryMint({
+        accounts: {
+          namespace: otherNamespaceId,
+          nameEntry: entryId,
+          payer: provider.wallet.publicKey,
+          namespaceTokenAccount: getAssociatedTokenAddressSync(
+            nameEntryMint,
+            otherNamespaceId,
+            true
+          ),
+          mint: nameEntryMint,
+          mintMetadata: findMintMetadataId(nameEntryMint),
+          masterEdition: findMintEditionId(nameEntryMint),
+          tokenMetadataProgram: METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedToken: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: web3.SYSVAR_RENT_PUBKEY,
+          systemProgram: web3.SystemProgram.programId,
+        },
+      })
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        signers: [mintKeypair],
+        silent: true,
+      })
+    ).rejects.toThrow();
+
+    const checkEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      entryName
+    );
+    assert.equal(
+      checkEntry.parsed.namespace.toString(),
+      findNamespaceId(namespaceName).toString()
+    );
+  });
+
+  it("Init mint and claim", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    const approveTransaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      approveTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      approveTransaction,
+      provider.wallet
+    );
+
+    const claimTransaction = new web3.Transaction();
+    await withClaimNameEntry(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      nameEntryMint
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Fails to set entry data under another namespace", async () => {
+    const entryId = findNameEntryId(findNamespaceId(namespaceName), entryName);
+    const transaction = new web3.Transaction().add(
+      namespacesProgram(
+        provider.connection,
+        provider.wallet
+      ).instruction.setNameEntryData({
+        accounts: {
+          namespace: findNamespaceId(otherNamespaceName),
+          nameEntry: entryId,
+          userTokenAccount: getAssociatedTokenAddressSync(
+            nameEntryMint,
+            provider.wallet.publicKey,
+            true
+          ),
+          tokenManager: findTokenManagerAddress(nameEntryMint),
+          user: provider.wallet.publicKey,
+        },
+      })
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Fails to set reverse entry under another namespace", async () => {
+    const otherNamespaceId = findNamespaceId(otherNamespaceName);
+    const entryId = findNameEntryId(findNamespaceId(namespaceName), entryName);
+    const transaction = new web3.Transaction().add(
+      namespacesProgram(
+        provider.connection,
+        provider.wallet
+      ).instruction.setNamespaceReverseEntry({
+        accounts: {
+          namespace: otherNamespaceId,
+          nameEntry: entryId,
+          reverseEntry: findReverseNameEntryForNamespaceId(
+            otherNamespaceId,
+            provider.wallet.publicKey
+          ),
+          userTokenAccount: getAssociatedTokenAddressSync(
+            nameEntryMint,
+            provider.wallet.publicKey,
+            true
+          ),
+          tokenManager: findTokenManagerAddress(nameEntryMint),
+          user: provider.wallet.publicKey,
+          payer: provider.wallet.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        },
+      })
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+
+    const checkEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      entryName
+    );
+    assert.equal(checkEntry.parsed.reverseEntry, null);
+  });
+});
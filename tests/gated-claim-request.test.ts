@@ -0,0 +1,166 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  getTestProvider,
+  newAccountWithLamports,
+} from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  getClaimRequest,
+  getNamespaceByName,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withUpdateNamespace,
+} from "../src";
+import { createMint } from "./utils";
+
+describe("gated-claim-request", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const GATE_TYPE_MINT = 2;
+
+  // global
+  let provider: CardinalProvider;
+  let gateMintId: web3.PublicKey;
+  let gateTokenAccountId: web3.PublicKey;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    [gateTokenAccountId, gateMintId] = await createMint(
+      provider.connection,
+      provider.wallet,
+      { target: provider.wallet.publicKey, amount: 1 }
+    );
+  });
+
+  it("Creates a mint gated namespace", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const namespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    const updateTransaction = new web3.Transaction();
+    withUpdateNamespace(
+      updateTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      {
+        updateAuthority: provider.wallet.publicKey,
+        rentAuthority: provider.wallet.publicKey,
+        schema: namespace.parsed.schema,
+        paymentAmountDaily: namespace.parsed.paymentAmountDaily,
+        paymentMint: namespace.parsed.paymentMint,
+        minRentalSeconds: namespace.parsed.minRentalSeconds,
+        transferableEntries: namespace.parsed.transferableEntries,
+        invalidationType: namespace.parsed.invalidationType,
+        gate: {
+          gateType: GATE_TYPE_MINT,
+          mint: gateMintId,
+          minAmount: new anchor.BN(1),
+        },
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      updateTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Fails to request without a gate token account", async () => {
+    const transaction = new web3.Transaction();
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Fails to request when the gate is not met", async () => {
+    const user = new anchor.Wallet(
+      await newAccountWithLamports(provider.connection)
+    );
+    const [otherMintTokenAccountId] = await createMint(
+      provider.connection,
+      user,
+      { target: user.publicKey, amount: 1 }
+    );
+    const transaction = new web3.Transaction();
+    withCreateClaimRequest(
+      provider.connection,
+      user,
+      namespaceName,
+      entryName,
+      user.publicKey,
+      transaction,
+      { gateTokenAccount: otherMintTokenAccountId }
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, user, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+
+    // a holder's token account does not satisfy the gate for another user
+    const otherTransaction = new web3.Transaction();
+    withCreateClaimRequest(
+      provider.connection,
+      user,
+      namespaceName,
+      entryName,
+      user.publicKey,
+      otherTransaction,
+      { gateTokenAccount: gateTokenAccountId }
+    );
+    await expect(
+      executeTransaction(provider.connection, otherTransaction, user, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Requests while holding the gate token", async () => {
+    const transaction = new web3.Transaction();
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction,
+      { gateTokenAccount: gateTokenAccountId }
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    assert.equal(
+      claimRequest.parsed.requestor.toString(),
+      provider.wallet.publicKey.toString()
+    );
+  });
+});
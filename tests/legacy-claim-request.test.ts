@@ -0,0 +1,128 @@
+import type { CardinalProvider } from "@cardinal/common";
+import { executeTransaction, getTestProvider } from "@cardinal/common";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  findNamespaceId,
+  LEGACY_CLAIM_REQUEST_SIZE,
+  namespacesProgram,
+  withCreateNamespace,
+  withMigrateClaimRequest,
+} from "../src";
+
+// preloaded by the test validator from tests/fixtures/legacy-claim-request.json
+const LEGACY_CLAIM_REQUEST_ID = new web3.PublicKey(
+  "D5V7nDW32XrfHeCH9NMCEm8wWHaAPGoHiL2XKu9FZkT8"
+);
+const LEGACY_ENTRY_NAME = "legacy-claim-name";
+
+describe("legacy-claim-request", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+
+  // global
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+  });
+
+  it("Cannot read a legacy claim request with a long name", async () => {
+    const claimRequestInfo = await provider.connection.getAccountInfo(
+      LEGACY_CLAIM_REQUEST_ID
+    );
+    assert.equal(claimRequestInfo?.data.length, LEGACY_CLAIM_REQUEST_SIZE);
+    await expect(
+      namespacesProgram(provider.connection).account.claimRequest.fetch(
+        LEGACY_CLAIM_REQUEST_ID
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Fails to migrate an account that is not a claim request", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const migrateTransaction = new web3.Transaction();
+    migrateTransaction.add(
+      namespacesProgram(provider.connection).instruction.migrateClaimRequest({
+        accounts: {
+          claimRequest: findNamespaceId(namespaceName),
+          payer: provider.wallet.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        },
+      })
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        migrateTransaction,
+        provider.wallet,
+        { silent: true }
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Migrates a legacy claim request", async () => {
+    const transaction = new web3.Transaction();
+    await withMigrateClaimRequest(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      LEGACY_CLAIM_REQUEST_ID
+    );
+    assert.equal(transaction.instructions.length, 1);
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const claimRequest = await namespacesProgram(
+      provider.connection
+    ).account.claimRequest.fetch(LEGACY_CLAIM_REQUEST_ID);
+    assert.equal(claimRequest.entryName, LEGACY_ENTRY_NAME);
+    assert.equal(
+      claimRequest.requestor.toString(),
+      provider.wallet.publicKey.toString()
+    );
+    assert.equal(claimRequest.isApproved, true);
+    assert.equal(
+      claimRequest.payer.toString(),
+      web3.PublicKey.default.toString()
+    );
+    assert.equal(claimRequest.expiresAt, null);
+
+    const checkTransaction = new web3.Transaction();
+    await withMigrateClaimRequest(
+      checkTransaction,
+      provider.connection,
+      provider.wallet,
+      LEGACY_CLAIM_REQUEST_ID
+    );
+    assert.equal(checkTransaction.instructions.length, 0);
+  });
+
+  it("Withdraws a migrated legacy claim request", async () => {
+    const transaction = new web3.Transaction();
+    transaction.add(
+      namespacesProgram(provider.connection).instruction.withdrawClaimRequest({
+        accounts: {
+          claimRequest: LEGACY_CLAIM_REQUEST_ID,
+          requestor: provider.wallet.publicKey,
+          // legacy requests did not record a payer and refund the requestor
+          payer: provider.wallet.publicKey,
+        },
+      })
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const claimRequestInfo = await provider.connection.getAccountInfo(
+      LEGACY_CLAIM_REQUEST_ID
+    );
+    assert.equal(claimRequestInfo, null);
+  });
+});
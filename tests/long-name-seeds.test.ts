@@ -0,0 +1,200 @@
+import type { CardinalProvider } from "@cardinal/common";
+import { executeTransaction, findAta, getTestProvider } from "@cardinal/common";
+import { utils } from "@project-serum/anchor";
+import { getAccount } from "@solana/spl-token";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  entryNameSeed,
+  findNameEntryId,
+  findNamespaceId,
+  getClaimRequest,
+  getNameEntry,
+  getReverseNameEntryForNamespace,
+  withClaimNameEntry,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withSetNamespaceReverseEntry,
+  withUpdateClaimRequest,
+} from "../src";
+
+describe("long-name-seeds", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `long-name-${"x".repeat(40)}-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+  });
+
+  it("Creates a namespace", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Seeds long names on their hash", () => {
+    const shortName = "x".repeat(32);
+    assert.equal(
+      Buffer.from(entryNameSeed(shortName)).toString(),
+      Buffer.from(shortName).toString()
+    );
+    const seed = entryNameSeed(entryName);
+    assert.equal(seed.length, 32);
+    assert.notEqual(
+      Buffer.from(seed).toString("hex"),
+      Buffer.from(entryName).subarray(0, 32).toString("hex")
+    );
+    // the hash is prefixed so it never equals the seed of a 32 byte name
+    assert.equal(
+      Buffer.from(seed).toString("hex"),
+      utils.sha256.hash(`long:${entryName}`)
+    );
+    assert.notEqual(
+      Buffer.from(seed).toString("hex"),
+      utils.sha256.hash(entryName)
+    );
+  });
+
+  it("Fails to init a name past the max length", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      "x".repeat(129)
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Init entry and mint", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [mintKeypair],
+      }
+    );
+
+    const checkEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      entryName
+    );
+    assert.equal(checkEntry.parsed.name, entryName);
+    assert.equal(
+      checkEntry.pubkey.toString(),
+      findNameEntryId(findNamespaceId(namespaceName), entryName).toString()
+    );
+  });
+
+  it("Claim", async () => {
+    const transaction = new web3.Transaction();
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    assert.equal(claimRequest.parsed.entryName, entryName);
+    const approveTransaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      approveTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      approveTransaction,
+      provider.wallet
+    );
+
+    const claimTransaction = new web3.Transaction();
+    await withClaimNameEntry(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      provider.wallet
+    );
+
+    const checkRecipientTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(mintKeypair.publicKey, provider.wallet.publicKey)
+    );
+    expect(Number(checkRecipientTokenAccount.amount.toString())).toEqual(1);
+  });
+
+  it("Set reverse entry", async () => {
+    const transaction = new web3.Transaction();
+    withSetNamespaceReverseEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair.publicKey
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkReverseEntry = await getReverseNameEntryForNamespace(
+      provider.connection,
+      provider.wallet.publicKey,
+      findNamespaceId(namespaceName)
+    );
+    assert.equal(checkReverseEntry.parsed.entryName, entryName);
+  });
+});
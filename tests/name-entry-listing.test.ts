@@ -0,0 +1,232 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  newAccountWithLamports,
+} from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import { getAccount } from "@solana/spl-token";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  getClaimRequest,
+  getListing,
+  getNameEntry,
+  getNamespaceByName,
+  withBuyNameEntry,
+  withClaimNameEntry,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withListNameEntry,
+  withSetNamespaceFeeOverride,
+  withUpdateClaimRequest,
+} from "../src";
+import { createMint, initGlobalContextIfMissing } from "./utils";
+
+describe("name-entry-listing", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+  const nameEntryMint = mintKeypair.publicKey;
+  const price = new anchor.BN(1000000);
+  const feeBasisPoints = 500;
+  const PAYMENT_MINT_START = 10000000;
+
+  // global
+  let paymentMintId: web3.PublicKey;
+  let buyer: web3.Keypair;
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    await initGlobalContextIfMissing(provider);
+    buyer = await newAccountWithLamports(provider.connection);
+    [, paymentMintId] = await createMint(provider.connection, provider.wallet, {
+      target: buyer.publicKey,
+      amount: PAYMENT_MINT_START,
+    });
+  });
+
+  it("Creates a transferable namespace with a fee override", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      paymentMint: paymentMintId,
+      transferableEntries: true,
+    });
+    withSetNamespaceFeeOverride(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      feeBasisPoints
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Init and claim entry", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [mintKeypair],
+      }
+    );
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    const approveTransaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      approveTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      approveTransaction,
+      provider.wallet
+    );
+
+    const claimTransaction = new web3.Transaction();
+    await withClaimNameEntry(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      nameEntryMint
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Fails to list without a price", async () => {
+    const transaction = new web3.Transaction();
+    await withListNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      new anchor.BN(0)
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("List", async () => {
+    const transaction = new web3.Transaction();
+    await withListNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      price
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkListing = await getListing(provider.connection, nameEntryMint);
+    assert.equal(checkListing.parsed.price.toNumber(), price.toNumber());
+    assert.equal(
+      checkListing.parsed.seller.toString(),
+      provider.wallet.publicKey.toString()
+    );
+    const checkSellerTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(nameEntryMint, provider.wallet.publicKey)
+    );
+    expect(Number(checkSellerTokenAccount.amount.toString())).toEqual(0);
+  });
+
+  it("Buy charges the namespace fee", async () => {
+    const transaction = new web3.Transaction();
+    await withBuyNameEntry(
+      transaction,
+      provider.connection,
+      new anchor.Wallet(buyer),
+      namespaceName,
+      entryName
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new anchor.Wallet(buyer)
+    );
+
+    const fee = (price.toNumber() * feeBasisPoints) / 10000;
+    const checkBuyerTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(nameEntryMint, buyer.publicKey)
+    );
+    expect(Number(checkBuyerTokenAccount.amount.toString())).toEqual(1);
+    const checkSellerPaymentAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMintId, provider.wallet.publicKey)
+    );
+    expect(Number(checkSellerPaymentAccount.amount.toString())).toEqual(
+      price.toNumber() - fee
+    );
+
+    const checkNamespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    assert.equal(checkNamespace.parsed.accruedProtocolFees.toNumber(), fee);
+    const checkNameEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      entryName
+    );
+    assert.equal(
+      checkNameEntry.parsed.data?.toString(),
+      buyer.publicKey.toString()
+    );
+  });
+});
@@ -0,0 +1,151 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  findMintMetadataId,
+  getTestProvider,
+} from "@cardinal/common";
+import { Metadata } from "@metaplex-foundation/mpl-token-metadata";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  findNamespaceId,
+  getNamespaceByName,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withSyncNameEntryMintMetadata,
+  withUpdateNamespace,
+} from "../src";
+
+describe("name-entry-metadata-sync", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+  const creator = web3.Keypair.generate().publicKey;
+  const otherCreator = web3.Keypair.generate().publicKey;
+  const sellerFeeBasisPoints = 500;
+
+  // global
+  let provider: CardinalProvider;
+
+  const updateCreators = async (
+    creators: { address: web3.PublicKey; verified: boolean; share: number }[]
+  ) => {
+    const namespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    const transaction = new web3.Transaction();
+    withUpdateNamespace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      {
+        updateAuthority: provider.wallet.publicKey,
+        rentAuthority: provider.wallet.publicKey,
+        approveAuthority: provider.wallet.publicKey,
+        schema: namespace.parsed.schema,
+        paymentAmountDaily: namespace.parsed.paymentAmountDaily,
+        paymentMint: namespace.parsed.paymentMint,
+        minRentalSeconds: namespace.parsed.minRentalSeconds,
+        transferableEntries: namespace.parsed.transferableEntries,
+        invalidationType: namespace.parsed.invalidationType,
+        sellerFeeBasisPoints,
+        creators,
+      }
+    );
+    return transaction;
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+  });
+
+  it("Creates a namespace and name entry mint", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+  });
+
+  it("Fails to set duplicate creators", async () => {
+    const transaction = await updateCreators([
+      { address: creator, verified: false, share: 50 },
+      { address: creator, verified: false, share: 50 },
+    ]);
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Fails to set the namespace as a creator", async () => {
+    const transaction = await updateCreators([
+      { address: creator, verified: false, share: 50 },
+      { address: findNamespaceId(namespaceName), verified: false, share: 50 },
+    ]);
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Syncs updated royalties to the mint metadata", async () => {
+    const transaction = await updateCreators([
+      { address: creator, verified: false, share: 70 },
+      { address: otherCreator, verified: false, share: 30 },
+    ]);
+    await withSyncNameEntryMintMetadata(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const mintMetadata = await Metadata.fromAccountAddress(
+      provider.connection,
+      findMintMetadataId(mintKeypair.publicKey)
+    );
+    assert.equal(mintMetadata.data.sellerFeeBasisPoints, sellerFeeBasisPoints);
+    assert.deepEqual(
+      mintMetadata.data.creators?.map((c) => [c.address.toString(), c.share]),
+      [
+        [findNamespaceId(namespaceName).toString(), 0],
+        [creator.toString(), 70],
+        [otherCreator.toString(), 30],
+      ]
+    );
+  });
+});
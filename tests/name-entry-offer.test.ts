@@ -0,0 +1,288 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  newAccountWithLamports,
+} from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import { getAccount } from "@solana/spl-token";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  findNameEntryId,
+  findNamespaceId,
+  getClaimRequest,
+  getNameEntry,
+  getNamespaceByName,
+  getOffer,
+  withAcceptNameEntryOffer,
+  withCancelNameEntryOffer,
+  withClaimNameEntry,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withMakeNameEntryOffer,
+  withSetNamespaceFeeOverride,
+  withUpdateClaimRequest,
+} from "../src";
+import { createMint, initGlobalContextIfMissing } from "./utils";
+
+describe("name-entry-offer", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+  const nameEntryMint = mintKeypair.publicKey;
+  const amount = new anchor.BN(1000000);
+  const feeBasisPoints = 500;
+  const PAYMENT_MINT_START = 10000000;
+
+  // global
+  let paymentMintId: web3.PublicKey;
+  let buyer: web3.Keypair;
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    await initGlobalContextIfMissing(provider);
+    buyer = await newAccountWithLamports(provider.connection);
+    [, paymentMintId] = await createMint(provider.connection, provider.wallet, {
+      target: buyer.publicKey,
+      amount: PAYMENT_MINT_START,
+    });
+  });
+
+  it("Creates a transferable namespace with a fee override", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      paymentMint: paymentMintId,
+      transferableEntries: true,
+    });
+    withSetNamespaceFeeOverride(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      feeBasisPoints
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Init and claim entry", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [mintKeypair],
+      }
+    );
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    const approveTransaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      approveTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      approveTransaction,
+      provider.wallet
+    );
+
+    const claimTransaction = new web3.Transaction();
+    await withClaimNameEntry(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      nameEntryMint
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Fails to make an expired offer", async () => {
+    const transaction = new web3.Transaction();
+    await withMakeNameEntryOffer(
+      transaction,
+      provider.connection,
+      new anchor.Wallet(buyer),
+      namespaceName,
+      entryName,
+      amount,
+      Math.floor(Date.now() / 1000) - 60
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new anchor.Wallet(buyer),
+        { silent: true }
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Make and cancel offer", async () => {
+    const transaction = new web3.Transaction();
+    await withMakeNameEntryOffer(
+      transaction,
+      provider.connection,
+      new anchor.Wallet(buyer),
+      namespaceName,
+      entryName,
+      amount,
+      Math.floor(Date.now() / 1000) + 3600
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new anchor.Wallet(buyer)
+    );
+
+    const nameEntryId = findNameEntryId(
+      findNamespaceId(namespaceName),
+      entryName
+    );
+    const checkOffer = await getOffer(
+      provider.connection,
+      nameEntryId,
+      buyer.publicKey
+    );
+    assert.equal(checkOffer.parsed.amount.toNumber(), amount.toNumber());
+    const checkBuyerPaymentAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMintId, buyer.publicKey)
+    );
+    expect(Number(checkBuyerPaymentAccount.amount.toString())).toEqual(
+      PAYMENT_MINT_START - amount.toNumber()
+    );
+
+    const cancelTransaction = new web3.Transaction();
+    await withCancelNameEntryOffer(
+      cancelTransaction,
+      provider.connection,
+      new anchor.Wallet(buyer),
+      namespaceName,
+      entryName
+    );
+    await executeTransaction(
+      provider.connection,
+      cancelTransaction,
+      new anchor.Wallet(buyer)
+    );
+    const checkRefundedPaymentAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMintId, buyer.publicKey)
+    );
+    expect(Number(checkRefundedPaymentAccount.amount.toString())).toEqual(
+      PAYMENT_MINT_START
+    );
+  });
+
+  it("Accept offer charges the namespace fee", async () => {
+    const transaction = new web3.Transaction();
+    await withMakeNameEntryOffer(
+      transaction,
+      provider.connection,
+      new anchor.Wallet(buyer),
+      namespaceName,
+      entryName,
+      amount,
+      Math.floor(Date.now() / 1000) + 3600
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new anchor.Wallet(buyer)
+    );
+
+    const acceptTransaction = new web3.Transaction();
+    await withAcceptNameEntryOffer(
+      acceptTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      buyer.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      acceptTransaction,
+      provider.wallet
+    );
+
+    const fee = (amount.toNumber() * feeBasisPoints) / 10000;
+    const checkBuyerTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(nameEntryMint, buyer.publicKey)
+    );
+    expect(Number(checkBuyerTokenAccount.amount.toString())).toEqual(1);
+    const checkHolderPaymentAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMintId, provider.wallet.publicKey)
+    );
+    expect(Number(checkHolderPaymentAccount.amount.toString())).toEqual(
+      amount.toNumber() - fee
+    );
+
+    const checkNamespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    assert.equal(checkNamespace.parsed.accruedProtocolFees.toNumber(), fee);
+    const checkNameEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      entryName
+    );
+    assert.equal(
+      checkNameEntry.parsed.data?.toString(),
+      buyer.publicKey.toString()
+    );
+  });
+});
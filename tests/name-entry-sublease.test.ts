@@ -0,0 +1,258 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  newAccountWithLamports,
+} from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import { getAccount } from "@solana/spl-token";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  getClaimRequest,
+  getNameEntry,
+  getSublease,
+  withClaimNameEntry,
+  withCloseSublease,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withEndSublease,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withInitSublease,
+  withRentSublease,
+  withUpdateClaimRequest,
+} from "../src";
+import { createMint, initGlobalContextIfMissing } from "./utils";
+
+describe("name-entry-sublease", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+  const nameEntryMint = mintKeypair.publicKey;
+  const price = new anchor.BN(1000000);
+  const duration = 3600;
+  const PAYMENT_MINT_START = 10000000;
+
+  // global
+  let paymentMintId: web3.PublicKey;
+  let lessee: web3.Keypair;
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    await initGlobalContextIfMissing(provider);
+    lessee = await newAccountWithLamports(provider.connection);
+    [, paymentMintId] = await createMint(provider.connection, provider.wallet, {
+      target: lessee.publicKey,
+      amount: PAYMENT_MINT_START,
+    });
+  });
+
+  it("Creates a transferable namespace", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      paymentMint: paymentMintId,
+      transferableEntries: true,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Init and claim entry", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [mintKeypair],
+      }
+    );
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    const approveTransaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      approveTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      approveTransaction,
+      provider.wallet
+    );
+
+    const claimTransaction = new web3.Transaction();
+    await withClaimNameEntry(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      nameEntryMint
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Init sublease", async () => {
+    const transaction = new web3.Transaction();
+    await withInitSublease(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      price,
+      duration
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkSublease = await getSublease(provider.connection, nameEntryMint);
+    assert.equal(checkSublease.parsed.price.toNumber(), price.toNumber());
+    assert.equal(checkSublease.parsed.lessee, null);
+    const checkHolderTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(nameEntryMint, provider.wallet.publicKey)
+    );
+    expect(Number(checkHolderTokenAccount.amount.toString())).toEqual(0);
+  });
+
+  it("Rent sublease", async () => {
+    const transaction = new web3.Transaction();
+    await withRentSublease(
+      transaction,
+      provider.connection,
+      new anchor.Wallet(lessee),
+      namespaceName,
+      entryName
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new anchor.Wallet(lessee)
+    );
+
+    const checkSublease = await getSublease(provider.connection, nameEntryMint);
+    assert.equal(
+      checkSublease.parsed.lessee?.toString(),
+      lessee.publicKey.toString()
+    );
+    const checkHolderPaymentAccount = await getAccount(
+      provider.connection,
+      await findAta(paymentMintId, provider.wallet.publicKey)
+    );
+    expect(Number(checkHolderPaymentAccount.amount.toString())).toEqual(
+      price.toNumber()
+    );
+    const checkNameEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      entryName
+    );
+    assert.equal(
+      checkNameEntry.parsed.data?.toString(),
+      lessee.publicKey.toString()
+    );
+  });
+
+  it("Fails to end an active sublease as the holder", async () => {
+    const transaction = new web3.Transaction();
+    await withEndSublease(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("End and close sublease", async () => {
+    const transaction = new web3.Transaction();
+    await withEndSublease(
+      transaction,
+      provider.connection,
+      new anchor.Wallet(lessee),
+      namespaceName,
+      entryName
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new anchor.Wallet(lessee)
+    );
+    const checkNameEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      entryName
+    );
+    assert.equal(
+      checkNameEntry.parsed.data?.toString(),
+      provider.wallet.publicKey.toString()
+    );
+
+    const closeTransaction = new web3.Transaction();
+    await withCloseSublease(
+      closeTransaction,
+      provider.connection,
+      provider.wallet,
+      nameEntryMint
+    );
+    await executeTransaction(
+      provider.connection,
+      closeTransaction,
+      provider.wallet
+    );
+    const checkHolderTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(nameEntryMint, provider.wallet.publicKey)
+    );
+    expect(Number(checkHolderTokenAccount.amount.toString())).toEqual(1);
+  });
+});
@@ -0,0 +1,142 @@
+import type { CardinalProvider } from "@cardinal/common";
+import { executeTransaction, getTestProvider } from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  getNameEntry,
+  getNamespaceByName,
+  withCreateNamespace,
+  withInitNameEntry,
+  withUpdateNamespace,
+} from "../src";
+
+describe("name-policy", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const canonicalName = "café";
+  const rejectedNames = [
+    // decomposed accent
+    "cafe\u0301",
+    // compatibility ligature
+    "ﬁsh",
+    // fullwidth letters
+    "ａｂｃ",
+    // uppercase
+    "Cafe",
+    // zero width space
+    "ca\u200bfe",
+    // latin mixed with a cyrillic lookalike
+    "pаypal",
+    // cyrillic spelling a latin word
+    "рос",
+  ];
+
+  // global
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+  });
+
+  it("Creates a namespace with a strict name policy", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const namespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    const updateTransaction = new web3.Transaction();
+    withUpdateNamespace(
+      updateTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      {
+        updateAuthority: provider.wallet.publicKey,
+        rentAuthority: provider.wallet.publicKey,
+        approveAuthority: provider.wallet.publicKey,
+        transferableEntries: false,
+        schema: namespace.parsed.schema,
+        paymentAmountDaily: namespace.parsed.paymentAmountDaily,
+        paymentMint: namespace.parsed.paymentMint,
+        minRentalSeconds: namespace.parsed.minRentalSeconds,
+        invalidationType: namespace.parsed.invalidationType,
+        namePolicy: 2,
+        minNameLength: 3,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      updateTransaction,
+      provider.wallet
+    );
+
+    const checkNamespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    assert.equal(checkNamespace.parsed.namePolicy, 2);
+  });
+
+  it("Init canonical name", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      canonicalName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkNameEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      canonicalName
+    );
+    assert.equal(checkNameEntry.parsed.name, canonicalName);
+  });
+
+  it("Fails to init a name below the minimum length", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      "ab"
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Fails to init non canonical or confusable names", async () => {
+    for (const name of rejectedNames) {
+      const transaction = new web3.Transaction();
+      withInitNameEntry(
+        transaction,
+        provider.connection,
+        provider.wallet,
+        namespaceName,
+        name
+      );
+      await expect(
+        executeTransaction(provider.connection, transaction, provider.wallet, {
+          silent: true,
+        })
+      ).rejects.toThrow();
+    }
+  });
+});
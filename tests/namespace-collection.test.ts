@@ -0,0 +1,132 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  findMintMetadataId,
+  getTestProvider,
+} from "@cardinal/common";
+import { Metadata } from "@metaplex-foundation/mpl-token-metadata";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  findNamespaceId,
+  getNamespaceByName,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withInitNamespaceCollection,
+} from "../src";
+
+describe("namespace-collection", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const collectionMintKeypair = web3.Keypair.generate();
+  const mintKeypair = web3.Keypair.generate();
+
+  // global
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+  });
+
+  it("Creates a namespace", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Inits the namespace collection", async () => {
+    const transaction = new web3.Transaction();
+    withInitNamespaceCollection(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      collectionMintKeypair
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      { signers: [collectionMintKeypair] }
+    );
+
+    const checkNamespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    assert.equal(
+      checkNamespace.parsed.collectionMint?.toString(),
+      collectionMintKeypair.publicKey.toString()
+    );
+    const collectionMetadata = await Metadata.fromAccountAddress(
+      provider.connection,
+      findMintMetadataId(collectionMintKeypair.publicKey)
+    );
+    assert.equal(
+      collectionMetadata.updateAuthority.toString(),
+      findNamespaceId(namespaceName).toString()
+    );
+  });
+
+  it("Fails to init the collection twice", async () => {
+    const otherCollectionMintKeypair = web3.Keypair.generate();
+    const transaction = new web3.Transaction();
+    withInitNamespaceCollection(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      otherCollectionMintKeypair
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        signers: [otherCollectionMintKeypair],
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Inits a name entry mint in the collection", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair,
+      collectionMintKeypair.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+
+    const mintMetadata = await Metadata.fromAccountAddress(
+      provider.connection,
+      findMintMetadataId(mintKeypair.publicKey)
+    );
+    assert.equal(
+      mintMetadata.collection?.key.toString(),
+      collectionMintKeypair.publicKey.toString()
+    );
+    assert.equal(mintMetadata.collection?.verified, true);
+  });
+});
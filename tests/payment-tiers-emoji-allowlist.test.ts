@@ -0,0 +1,238 @@
+import type { CardinalProvider } from "@cardinal/common";
+import { executeTransaction, getTestProvider } from "@cardinal/common";
+import { getTimeInvalidator } from "@cardinal/token-manager/dist/cjs/programs/timeInvalidator/accounts";
+import { findTimeInvalidatorAddress } from "@cardinal/token-manager/dist/cjs/programs/timeInvalidator/pda";
+import { findTokenManagerAddress } from "@cardinal/token-manager/dist/cjs/programs/tokenManager/pda";
+import * as anchor from "@project-serum/anchor";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  getClaimRequest,
+  getNameEntry,
+  getNamespaceByName,
+  paymentAmountDaily,
+  withClaimNameEntry,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withSetEmojiAllowlist,
+  withUpdateClaimRequest,
+  withUpdateNamespace,
+} from "../src";
+import { createMint, initPaymentManagerIfMissing } from "./utils";
+
+describe("payment-tiers-emoji-allowlist", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = "🔥ab";
+  const mintKeypair = web3.Keypair.generate();
+  const defaultPaymentAmountDaily = new anchor.BN(1000);
+  const tierPaymentAmountDaily = new anchor.BN(10000);
+  const PAYMENT_MINT_START = 10000000;
+  const duration = 86400;
+
+  // global
+  let paymentMintId: web3.PublicKey;
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    await initPaymentManagerIfMissing(provider);
+    [, paymentMintId] = await createMint(provider.connection, provider.wallet, {
+      target: provider.wallet.publicKey,
+      amount: PAYMENT_MINT_START,
+    });
+  });
+
+  it("Creates a namespace with a short name tier", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      paymentAmountDaily: defaultPaymentAmountDaily,
+      paymentMint: paymentMintId,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const namespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    const updateTransaction = new web3.Transaction();
+    withUpdateNamespace(
+      updateTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      {
+        updateAuthority: provider.wallet.publicKey,
+        rentAuthority: provider.wallet.publicKey,
+        approveAuthority: provider.wallet.publicKey,
+        transferableEntries: false,
+        schema: namespace.parsed.schema,
+        paymentAmountDaily: defaultPaymentAmountDaily,
+        paymentMint: paymentMintId,
+        minRentalSeconds: namespace.parsed.minRentalSeconds,
+        invalidationType: namespace.parsed.invalidationType,
+        namePolicy: 1,
+        paymentTiers: [
+          { maxNameLength: 3, paymentAmountDaily: tierPaymentAmountDaily },
+        ],
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      updateTransaction,
+      provider.wallet
+    );
+
+    const checkNamespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    assert.equal(
+      paymentAmountDaily(checkNamespace.parsed, entryName).toNumber(),
+      tierPaymentAmountDaily.toNumber()
+    );
+    assert.equal(
+      paymentAmountDaily(checkNamespace.parsed, "abcd").toNumber(),
+      defaultPaymentAmountDaily.toNumber()
+    );
+  });
+
+  it("Fails to allow a sequence that is not an emoji", async () => {
+    const transaction = new web3.Transaction();
+    withSetEmojiAllowlist(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      ["ab"]
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Set emoji allowlist", async () => {
+    const transaction = new web3.Transaction();
+    withSetEmojiAllowlist(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      ["🔥"]
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Fails to init a name with an emoji outside the allowlist", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      "💀ab"
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+  });
+
+  it("Init and claim an allowlisted emoji name at the tier price", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [mintKeypair],
+      }
+    );
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    const approveTransaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      approveTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      approveTransaction,
+      provider.wallet
+    );
+
+    const claimTransaction = new web3.Transaction();
+    await withClaimNameEntry(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair.publicKey,
+      duration
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      provider.wallet
+    );
+
+    const checkNameEntry = await getNameEntry(
+      provider.connection,
+      namespaceName,
+      entryName
+    );
+    assert.equal(checkNameEntry.parsed.name, entryName);
+    const checkTimeInvalidator = await getTimeInvalidator(
+      provider.connection,
+      findTimeInvalidatorAddress(findTokenManagerAddress(mintKeypair.publicKey))
+    );
+    assert.equal(
+      checkTimeInvalidator.parsed.extensionPaymentAmount?.toNumber(),
+      tierPaymentAmountDaily.toNumber()
+    );
+  });
+});
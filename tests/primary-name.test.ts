@@ -0,0 +1,200 @@
+import type { CardinalProvider } from "@cardinal/common";
+import {
+  executeTransaction,
+  getTestProvider,
+  newAccountWithLamports,
+  tryGetAccount,
+} from "@cardinal/common";
+import * as anchor from "@project-serum/anchor";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  findNamespaceId,
+  getClaimRequest,
+  getPrimaryName,
+  withClaimNameEntry,
+  withClosePrimaryName,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withResolvePrimaryName,
+  withSetNamespaceReverseEntry,
+  withSetPrimaryName,
+  withUpdateClaimRequest,
+} from "../src";
+
+describe("primary-name", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entryName = `testname-${Math.random()}`;
+  const mintKeypair = web3.Keypair.generate();
+
+  let otherUser: web3.Keypair;
+  let provider: CardinalProvider;
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    otherUser = await newAccountWithLamports(provider.connection);
+  });
+
+  it("Creates a namespace", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      approveAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Init, claim and set reverse entry", async () => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      {
+        signers: [mintKeypair],
+      }
+    );
+
+    const claimRequest = await getClaimRequest(
+      provider.connection,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey
+    );
+    const approveTransaction = new web3.Transaction();
+    withUpdateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      claimRequest.pubkey,
+      true,
+      approveTransaction
+    );
+    await executeTransaction(
+      provider.connection,
+      approveTransaction,
+      provider.wallet
+    );
+
+    const claimTransaction = new web3.Transaction();
+    await withClaimNameEntry(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair.publicKey
+    );
+    withSetNamespaceReverseEntry(
+      claimTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Fails to set another wallet's entry as primary name", async () => {
+    const transaction = new web3.Transaction();
+    await withSetPrimaryName(
+      transaction,
+      provider.connection,
+      new anchor.Wallet(otherUser),
+      namespaceName,
+      entryName
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new anchor.Wallet(otherUser),
+        { silent: true }
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Set and resolve primary name", async () => {
+    const transaction = new web3.Transaction();
+    await withSetPrimaryName(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkPrimaryName = await getPrimaryName(
+      provider.connection,
+      provider.wallet.publicKey
+    );
+    assert.equal(
+      checkPrimaryName.parsed.wallet.toString(),
+      provider.wallet.publicKey.toString()
+    );
+    assert.equal(
+      checkPrimaryName.parsed.namespace.toString(),
+      findNamespaceId(namespaceName).toString()
+    );
+
+    const resolveTransaction = new web3.Transaction();
+    await withResolvePrimaryName(
+      resolveTransaction,
+      provider.connection,
+      provider.wallet,
+      provider.wallet.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      resolveTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Close primary name", async () => {
+    const transaction = new web3.Transaction();
+    withClosePrimaryName(transaction, provider.connection, provider.wallet);
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkPrimaryName = await tryGetAccount(() =>
+      getPrimaryName(provider.connection, provider.wallet.publicKey)
+    );
+    expect(checkPrimaryName).toEqual(null);
+  });
+});
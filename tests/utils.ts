@@ -1,4 +1,9 @@
-import { executeTransaction } from "@cardinal/common";
+import type { CardinalProvider } from "@cardinal/common";
+import { executeTransaction, tryGetAccount } from "@cardinal/common";
+import { DEFAULT_PAYMENT_MANAGER_NAME } from "@cardinal/payment-manager";
+import { getPaymentManager } from "@cardinal/payment-manager/dist/cjs/accounts";
+import { findPaymentManagerAddress } from "@cardinal/payment-manager/dist/cjs/pda";
+import { withInit as withInitPaymentManager } from "@cardinal/payment-manager/dist/cjs/transaction";
 import type { Wallet } from "@project-serum/anchor/dist/cjs/provider";
 import {
   createAssociatedTokenAccountInstruction,
@@ -12,6 +17,8 @@ import {
 import type { Connection, PublicKey } from "@solana/web3.js";
 import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
 
+import { getGlobalContext, withInit } from "../src";
+
 export const NAMESPACE_SEED = "namespace";
 export const ENTRY_SEED = "entry";
 export const REVERSE_ENTRY_SEED = "reverse-entry";
@@ -67,3 +74,49 @@ export const createMintTx = async (
     ata,
   ];
 };
+
+/**
+ * Creates the global context unless an earlier test file on the validator did
+ */
+export const initGlobalContextIfMissing = async (
+  provider: CardinalProvider
+): Promise<void> => {
+  const globalContext = await tryGetAccount(() =>
+    getGlobalContext(provider.connection)
+  );
+  if (!globalContext) {
+    const transaction = new Transaction();
+    withInit(provider.connection, provider.wallet, 0, transaction);
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  }
+};
+
+/**
+ * Creates the default payment manager unless an earlier test file on the validator did
+ */
+export const initPaymentManagerIfMissing = async (
+  provider: CardinalProvider
+): Promise<void> => {
+  const paymentManager = await tryGetAccount(() =>
+    getPaymentManager(
+      provider.connection,
+      findPaymentManagerAddress(DEFAULT_PAYMENT_MANAGER_NAME)
+    )
+  );
+  if (!paymentManager) {
+    const transaction = new Transaction();
+    await withInitPaymentManager(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      {
+        paymentManagerName: DEFAULT_PAYMENT_MANAGER_NAME,
+        feeCollectorId: Keypair.generate().publicKey,
+        makerFeeBasisPoints: 0,
+        takerFeeBasisPoints: 0,
+        includeSellerFeeBasisPoints: false,
+      }
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  }
+};
@@ -0,0 +1,159 @@
+import type { CardinalProvider } from "@cardinal/common";
+import { executeTransaction, getTestProvider } from "@cardinal/common";
+import * as web3 from "@solana/web3.js";
+import assert from "assert";
+
+import {
+  findNamespaceId,
+  getNamespaceByName,
+  getWalletEntryCounter,
+  withClaimNameEntryV2,
+  withCreateClaimRequest,
+  withCreateNamespace,
+  withInitNameEntry,
+  withInitNameEntryMint,
+  withUpdateNamespace,
+} from "../src";
+import { initGlobalContextIfMissing } from "./utils";
+
+describe("wallet-entry-limit", () => {
+  // test params
+  const namespaceName = `ns-${Math.random()}`;
+  const entries = [...Array(2)].map(() => ({
+    entryName: `testname-${Math.random()}`,
+    mintKeypair: web3.Keypair.generate(),
+  }));
+
+  // global
+  let provider: CardinalProvider;
+
+  const requestEntry = async (
+    entryName: string,
+    mintKeypair: web3.Keypair
+  ) => {
+    const transaction = new web3.Transaction();
+    withInitNameEntry(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName
+    );
+    withInitNameEntryMint(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair
+    );
+    withCreateClaimRequest(
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      provider.wallet.publicKey,
+      transaction
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    await initGlobalContextIfMissing(provider);
+  });
+
+  it("Creates a namespace with one entry per wallet", async () => {
+    const transaction = new web3.Transaction();
+    withCreateNamespace(transaction, provider.connection, provider.wallet, {
+      namespaceName,
+      updateAuthority: provider.wallet.publicKey,
+      rentAuthority: provider.wallet.publicKey,
+      transferableEntries: false,
+    });
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const namespace = await getNamespaceByName(
+      provider.connection,
+      namespaceName
+    );
+    const updateTransaction = new web3.Transaction();
+    withUpdateNamespace(
+      updateTransaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      {
+        updateAuthority: provider.wallet.publicKey,
+        rentAuthority: provider.wallet.publicKey,
+        schema: namespace.parsed.schema,
+        paymentAmountDaily: namespace.parsed.paymentAmountDaily,
+        paymentMint: namespace.parsed.paymentMint,
+        minRentalSeconds: namespace.parsed.minRentalSeconds,
+        transferableEntries: namespace.parsed.transferableEntries,
+        invalidationType: namespace.parsed.invalidationType,
+        maxEntriesPerWallet: 1,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      updateTransaction,
+      provider.wallet
+    );
+  });
+
+  it("Claim creates the wallet entry counter", async () => {
+    const { entryName, mintKeypair } = entries[0]!;
+    await requestEntry(entryName, mintKeypair);
+
+    const transaction = new web3.Transaction();
+    await withClaimNameEntryV2(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair.publicKey
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const walletEntryCounter = await getWalletEntryCounter(
+      provider.connection,
+      findNamespaceId(namespaceName),
+      provider.wallet.publicKey
+    );
+    assert.equal(walletEntryCounter.parsed.count, 1);
+  });
+
+  it("Fails to claim past the wallet limit", async () => {
+    const { entryName, mintKeypair } = entries[1]!;
+    await requestEntry(entryName, mintKeypair);
+
+    const transaction = new web3.Transaction();
+    await withClaimNameEntryV2(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      namespaceName,
+      entryName,
+      mintKeypair.publicKey
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        silent: true,
+      })
+    ).rejects.toThrow();
+
+    const walletEntryCounter = await getWalletEntryCounter(
+      provider.connection,
+      findNamespaceId(namespaceName),
+      provider.wallet.publicKey
+    );
+    assert.equal(walletEntryCounter.parsed.count, 1);
+  });
+});
@@ -40,6 +40,20 @@ export const updateNamespace = async (
       maxExpiration: namespace.parsed.maxExpiration ?? undefined,
       schema: namespace.parsed.schema,
       invalidationType: namespace.parsed.invalidationType,
+      metadataUriTemplate: namespace.parsed.metadataUriTemplate ?? undefined,
+      metadataSymbol: namespace.parsed.metadataSymbol ?? undefined,
+      metadataNameFormat: namespace.parsed.metadataNameFormat ?? undefined,
+      sellerFeeBasisPoints: namespace.parsed.sellerFeeBasisPoints,
+      creators: namespace.parsed.creators ?? undefined,
+      referralBasisPoints: namespace.parsed.referralBasisPoints,
+      beneficiaries: namespace.parsed.beneficiaries ?? undefined,
+      allowlistRoot: namespace.parsed.allowlistRoot ?? undefined,
+      gate: namespace.parsed.gate ?? undefined,
+      maxEntriesPerWallet: namespace.parsed.maxEntriesPerWallet ?? undefined,
+      namePolicy: namespace.parsed.namePolicy,
+      minNameLength: namespace.parsed.minNameLength ?? undefined,
+      maxNameLength: namespace.parsed.maxNameLength ?? undefined,
+      paymentTiers: namespace.parsed.paymentTiers ?? undefined,
     }
   );
   transaction.feePayer = wallet.publicKey;